@@ -47,14 +47,15 @@
 
 use anyhow::Result;
 use argh::FromArgs;
-use rune::termcolor::{ColorChoice, StandardStream};
+use rune::termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor as _};
 use rune::EmitDiagnostics as _;
 use std::fs;
-use std::io;
+use std::io::{self, Write as _};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
-use runestick::{Item, Unit, Value, VmExecution};
+use runestick::{Inst, Item, Unit, Value, VmError, VmErrorKind, VmExecution};
 
 /// Rune Programming Language.
 /// CLI Arguments
@@ -63,9 +64,21 @@ struct Args {
     /// rune Entry File
     #[argh(positional)]
     path: PathBuf,
-    /// provide detailed tracing for each instruction executed.
+    /// provide detailed tracing for each instruction executed, marking where execution suspends on and resumes from futures.
     #[argh(switch)]
     trace: bool,
+    /// only trace instructions executed inside of the function with the given item, like `foo::bar`. Implies `--trace`.
+    #[argh(option)]
+    trace_filter: Option<String>,
+    /// pause at the given `file:line` whenever it starts executing, printing the location and dumping the stack before continuing. Can be given multiple times. Doesn't support async functions.
+    #[argh(option, long = "break")]
+    breakpoints: Vec<String>,
+    /// run all functions marked with `#[test]` instead of `main`, reporting which of them failed.
+    #[argh(switch)]
+    test: bool,
+    /// run all functions marked with `#[bench]` instead of `main`, reporting how long each iteration takes.
+    #[argh(switch)]
+    bench: bool,
     /// dump everything.
     #[argh(switch, short = 'd')]
     dump: bool,
@@ -78,6 +91,9 @@ struct Args {
     /// dump the state of the stack after completion. If compiled with `--trace` will dump it after each instruction.
     #[argh(switch)]
     dump_stack: bool,
+    /// pretty-print values over multiple lines when dumping the stack, and in the output of `dbg`.
+    #[argh(switch)]
+    pretty: bool,
     /// dump dynamic functions.
     #[argh(switch)]
     dump_functions: bool,
@@ -90,18 +106,58 @@ struct Args {
     /// dump native types.
     #[argh(switch)]
     dump_native_types: bool,
+    /// compare the instructions of the given compiled unit (`.rnc`) against the ones of the entry file, printing the functions which differ instead of running `main`.
+    #[argh(option)]
+    diff_unit: Option<PathBuf>,
+    /// write dumps to the given file instead of stdout.
+    #[argh(option)]
+    dump_output: Option<PathBuf>,
     /// include source code references where appropriate (only available if -O debug-info=true).
     #[argh(switch)]
     with_source: bool,
+    /// disable colored output. Colors are also disabled if the `NO_COLOR` environment variable is set.
+    #[argh(switch)]
+    no_color: bool,
     /// enabled experimental features.
     #[argh(switch)]
     experimental: bool,
+    /// limit the memory in bytes that the script is allowed to allocate.
+    #[argh(option)]
+    max_memory: Option<usize>,
+    /// limit the amount of gas the script is allowed to consume, where every executed instruction costs one unit of gas.
+    #[argh(option)]
+    max_gas: Option<u64>,
+    /// how integer arithmetic behaves on overflow, one of `checked` (the default), `wrapping`, or `saturating`.
+    #[argh(option)]
+    arithmetic: Option<String>,
+    /// deny scripts access to the given comma-separated packages, like `fs,process`.
+    /// Available packages are: http, json, toml, time, process, fs, signal, env.
+    #[argh(option)]
+    deny: Vec<String>,
+    /// allow scripts to run external commands through the `process` package, which is denied by default.
+    #[argh(switch)]
+    allow_process: bool,
+    /// set the optimization level, one of 0, 1, 2, or 3.
+    /// 0: disables memoize-instance-fn and peephole,
+    /// 1: enables memoize-instance-fn (the default),
+    /// 2: enables memoize-instance-fn and peephole,
+    /// 3: enables memoize-instance-fn and peephole, and disables debug-info.
+    /// Individual options given with `-O` take precedence.
+    #[argh(option)]
+    opt_level: Option<u8>,
     /// update the given compiler option.
     /// link-checks: Perform link-time checks,
     /// memoize_instance_fn: Memoize the instance function in a loop,
     /// debug_info: Include debug information when compiling,
     /// macros: Support (experimental) macros,
     /// bytecode: Support (experimental) bytecode caching,
+    /// peephole: Perform peephole optimizations on generated instructions,
+    /// negative-index: Interpret negative indices as offsets from the end,
+    /// const-propagation: Propagate constants through `let` bindings,
+    /// dead-code-elimination: Only build functions reachable from entry points,
+    /// tail-calls: Turn self-recursive calls in tail position into jumps,
+    /// embed-sources: Embed the sources in the unit, to render diagnostics without them,
+    /// opt-level: Set the optimization level, like `--opt-level`,
     #[argh(option, short = 'O')]
     compiler_options: Vec<String>,
 }
@@ -110,8 +166,25 @@ struct Args {
 async fn main() -> Result<()> {
     env_logger::init();
     let args: Args = argh::from_env();
-    let mut context = rune::default_context()?;
-    let options = args.compiler_options.join(",").parse()?;
+    let mut builder = rune::ContextBuilder::new();
+
+    for packages in &args.deny {
+        builder.deny_list(packages)?;
+    }
+
+    let mut context = builder.build()?;
+    context.allow_host_access(runestick::HostAccess::Process, args.allow_process);
+    let mut options = rune::Options::default();
+
+    if let Some(level) = args.opt_level {
+        options.opt_level(level)?;
+    }
+
+    for option in args.compiler_options.iter().flat_map(|o| o.split(',')) {
+        if !option.is_empty() {
+            options.parse_option(option)?;
+        }
+    }
 
     if args.experimental {
         context.install(&rune_macros::module()?)?;
@@ -127,42 +200,91 @@ async fn main() -> Result<()> {
     )?;
 
     if !warnings.is_empty() {
-        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        let mut writer = StandardStream::stderr(color_choice(&args));
         warnings.emit_diagnostics(&mut writer, &sources)?;
     }
 
-    let vm = runestick::Vm::new(context.clone(), unit.clone());
+    let mut vm = runestick::Vm::new(context.clone(), unit.clone());
+
+    if let Some(max_memory) = args.max_memory {
+        vm.set_memory(Some(runestick::Memory::new(max_memory)));
+    }
+
+    if let Some(max_gas) = args.max_gas {
+        vm.set_gas(Some(runestick::Gas::new(max_gas)));
+    }
+
+    if let Some(arithmetic) = &args.arithmetic {
+        vm.set_arithmetic(arithmetic.parse().map_err(anyhow::Error::msg)?);
+    }
+
+    if args.pretty {
+        vm.set_output(Some(runestick::Output::process().with_pretty(true)));
+    }
+
+    if !args.breakpoints.is_empty() {
+        vm.set_breakpoints(Some(build_breakpoints(&args.breakpoints, &unit, &sources)?));
+    }
+
+    let mut dump_output: Box<dyn io::Write> = match &args.dump_output {
+        Some(path) => Box::new(io::BufWriter::new(fs::File::create(path)?)),
+        None => Box::new(io::stdout()),
+    };
 
     if args.dump_native_functions || args.dump {
-        println!("# functions");
+        writeln!(dump_output, "# functions")?;
 
         for (i, (hash, f)) in context.iter_functions().enumerate() {
-            println!("{:04} = {} ({})", i, f, hash);
+            writeln!(dump_output, "{:04} = {} ({})", i, f, hash)?;
         }
     }
 
     if args.dump_native_types || args.dump {
-        println!("# types");
+        writeln!(dump_output, "# types")?;
 
         for (i, (hash, ty)) in context.iter_types().enumerate() {
-            println!("{:04} = {} ({})", i, ty, hash);
+            writeln!(dump_output, "{:04} = {} ({})", i, ty, hash)?;
         }
     }
 
     if args.dump_unit || args.dump {
-        dump_unit(&args, &vm, &sources)?;
+        dump_unit(&mut *dump_output, &args, &vm, &sources)?;
+    }
+
+    if let Some(path) = &args.diff_unit {
+        let other = bincode::deserialize_from::<_, Unit>(fs::File::open(path)?)?;
+
+        for diff in other.diff(&unit) {
+            write!(dump_output, "{}", diff)?;
+        }
+
+        dump_output.flush()?;
+        return Ok(());
+    }
+
+    dump_output.flush()?;
+
+    if args.test {
+        return run_tests(&vm, &sources, color_choice(&args)).await;
+    }
+
+    if args.bench {
+        return run_benches(&vm, &sources, color_choice(&args)).await;
     }
 
     let last = std::time::Instant::now();
 
     let mut execution: runestick::VmExecution = vm.call(&Item::of(&["main"]), ())?;
 
-    let result = if args.trace {
+    let result = if args.trace || args.trace_filter.is_some() {
         match do_trace(
             &mut execution,
             &sources,
             args.dump_stack || args.dump,
+            args.pretty,
             args.with_source,
+            args.trace_filter.as_deref(),
+            color_choice(&args),
         )
         .await
         {
@@ -170,6 +292,8 @@ async fn main() -> Result<()> {
             Err(TraceError::Io(io)) => return Err(io.into()),
             Err(TraceError::VmError(vm)) => Err(vm),
         }
+    } else if !args.breakpoints.is_empty() {
+        do_break(&mut execution, &sources, &mut *dump_output, args.pretty)?
     } else {
         execution.async_complete().await
     };
@@ -177,7 +301,11 @@ async fn main() -> Result<()> {
     let errored = match result {
         Ok(result) => {
             let duration = std::time::Instant::now().duration_since(last);
-            println!("== {:?} ({:?})", result, duration);
+            if args.pretty {
+                println!("== {:#?} ({:?})", result, duration);
+            } else {
+                println!("== {:?} ({:?})", result, duration);
+            }
             None
         }
         Err(error) => {
@@ -188,11 +316,12 @@ async fn main() -> Result<()> {
     };
 
     if args.dump_stack || args.dump {
-        dump_stack(&execution)?;
+        dump_stack(&mut *dump_output, &execution, args.pretty)?;
+        dump_output.flush()?;
     }
 
     if let Some(error) = errored {
-        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        let mut writer = StandardStream::stderr(color_choice(&args));
         error.emit_diagnostics(&mut writer, &sources)?;
     }
 
@@ -213,10 +342,22 @@ fn get_or_build_unit(
     let maybe_unit = if use_cache {
         let f = fs::File::open(&bytecode_path)?;
         match bincode::deserialize_from::<_, Unit>(f) {
-            Ok(unit) => {
-                log::trace!("using cache: {}", bytecode_path.display());
-                Some(Arc::new(unit))
-            }
+            Ok(unit) => match unit.link(&*context) {
+                Ok(()) => match unit.verify(&*context) {
+                    Ok(()) => {
+                        log::trace!("using cache: {}", bytecode_path.display());
+                        Some(Arc::new(unit))
+                    }
+                    Err(e) => {
+                        log::error!("failed to verify: {}: {}", bytecode_path.display(), e);
+                        None
+                    }
+                },
+                Err(e) => {
+                    log::error!("failed to link: {}: {}", bytecode_path.display(), e);
+                    None
+                }
+            },
             Err(e) => {
                 log::error!("failed to deserialize: {}: {}", bytecode_path.display(), e);
                 None
@@ -234,7 +375,7 @@ fn get_or_build_unit(
             let unit = match rune::load_path(&*context, &options, sources, &args.path, warnings) {
                 Ok(unit) => unit,
                 Err(error) => {
-                    let mut writer = StandardStream::stderr(ColorChoice::Always);
+                    let mut writer = StandardStream::stderr(color_choice(args));
                     error.emit_diagnostics(&mut writer, sources)?;
                     anyhow::bail!("Load Error");
                 }
@@ -252,8 +393,12 @@ fn get_or_build_unit(
     Ok(unit)
 }
 
-fn dump_stack(execution: &runestick::VmExecution) -> Result<()> {
-    println!("# full stack dump after halting");
+fn dump_stack(
+    out: &mut dyn io::Write,
+    execution: &runestick::VmExecution,
+    pretty: bool,
+) -> Result<()> {
+    writeln!(out, "# full stack dump after halting")?;
 
     let vm = execution.vm()?;
 
@@ -272,61 +417,74 @@ fn dump_stack(execution: &runestick::VmExecution) -> Result<()> {
             .get(frame.stack_bottom()..stack_top)
             .expect("bad stack slice");
 
-        println!("  frame #{} (+{})", count, frame.stack_bottom());
+        writeln!(out, "  frame #{} (+{})", count, frame.stack_bottom())?;
 
         if values.is_empty() {
-            println!("    *empty*");
+            writeln!(out, "    *empty*")?;
         }
 
-        for (n, value) in stack.iter().enumerate() {
-            println!("{}+{} = {:?}", frame.stack_bottom(), n, value);
+        for (n, value) in values.iter().enumerate() {
+            write!(out, "    {}+{} = ", frame.stack_bottom(), n)?;
+            write_value(out, value, pretty)?;
+            writeln!(out)?;
         }
     }
 
     // NB: print final frame
-    println!("  frame #{} (+{})", frames.len(), stack.stack_bottom());
+    writeln!(out, "  frame #{} (+{})", frames.len(), stack.stack_bottom())?;
 
     let values = stack.get(stack.stack_bottom()..).expect("bad stack slice");
 
     if values.is_empty() {
-        println!("    *empty*");
+        writeln!(out, "    *empty*")?;
     }
 
     for (n, value) in values.iter().enumerate() {
-        println!("    {}+{} = {:?}", stack.stack_bottom(), n, value);
+        write!(out, "    {}+{} = ", stack.stack_bottom(), n)?;
+        write_value(out, value, pretty)?;
+        writeln!(out)?;
     }
     Ok(())
 }
 
-fn dump_unit(args: &Args, vm: &runestick::Vm, sources: &rune::Sources) -> Result<()> {
-    use std::io::Write as _;
+/// Write a value in a stack dump, pretty-printing it over multiple lines
+/// which are indented to line up with the dump if `pretty` is set.
+fn write_value(out: &mut dyn io::Write, value: &Value, pretty: bool) -> io::Result<()> {
+    if pretty {
+        let value = format!("{:#?}", value);
+        write!(out, "{}", value.replace('\n', "\n    "))
+    } else {
+        write!(out, "{:?}", value)
+    }
+}
 
+fn dump_unit(
+    out: &mut dyn io::Write,
+    args: &Args,
+    vm: &runestick::Vm,
+    sources: &rune::Sources,
+) -> Result<()> {
     let unit = vm.unit();
 
     if args.dump_instructions || args.dump {
-        println!("# instructions");
+        writeln!(out, "# instructions")?;
 
         let mut first_function = true;
 
-        for (n, inst) in unit.iter_instructions().enumerate() {
-            let out = std::io::stdout();
-            let mut out = out.lock();
-
-            let debug = unit.debug_info().and_then(|d| d.instruction_at(n));
-
-            if let Some((hash, signature)) = unit.debug_info().and_then(|d| d.function_at(n)) {
+        for inst in unit.disassemble() {
+            if let Some((hash, signature)) = inst.function {
                 if first_function {
                     first_function = false;
                 } else {
-                    println!();
+                    writeln!(out)?;
                 }
 
-                println!("fn {} ({}):", signature, hash);
+                writeln!(out, "fn {} ({}):", signature, hash)?;
             }
 
             if args.with_source {
-                if let Some((source, span)) =
-                    debug.and_then(|d| sources.get(d.source_id).map(|s| (s, d.span)))
+                if let (Some(source), Some(span)) =
+                    (inst.source_id.and_then(|id| sources.get(id)), inst.span)
                 {
                     if let Some((count, line)) = rune::diagnostics::line_for(source.as_str(), span)
                     {
@@ -341,17 +499,11 @@ fn dump_unit(args: &Args, vm: &runestick::Vm, sources: &rune::Sources) -> Result
                 }
             }
 
-            if let Some(label) = debug.and_then(|d| d.label.as_ref()) {
-                println!("{}:", label);
+            if let Some(label) = inst.label {
+                writeln!(out, "{}:", label)?;
             }
 
-            write!(out, "  {:04} = {}", n, inst)?;
-
-            if let Some(comment) = debug.and_then(|d| d.comment.as_ref()) {
-                write!(out, " // {}", comment)?;
-            }
-
-            println!();
+            writeln!(out, "  {}", inst)?;
         }
     }
 
@@ -361,43 +513,278 @@ fn dump_unit(args: &Args, vm: &runestick::Vm, sources: &rune::Sources) -> Result
     let mut keys = unit.iter_static_object_keys().peekable();
 
     if (args.dump_functions || args.dump) && functions.peek().is_some() {
-        println!("# dynamic functions");
+        writeln!(out, "# dynamic functions")?;
 
         for (hash, kind) in functions {
             if let Some(signature) = unit.debug_info().and_then(|d| d.functions.get(&hash)) {
-                println!("{} = {}", hash, signature);
+                writeln!(out, "{} = {}", hash, signature)?;
             } else {
-                println!("{} = {}", hash, kind);
+                writeln!(out, "{} = {}", hash, kind)?;
             }
         }
     }
 
     if (args.dump_types || args.dump) && types.peek().is_some() {
-        println!("# dynamic types");
+        writeln!(out, "# dynamic types")?;
 
         for (hash, ty) in types {
-            println!("{} = {}", hash, ty.value_type);
+            writeln!(out, "{} = {}", hash, ty.value_type)?;
         }
     }
 
     if strings.peek().is_some() {
-        println!("# strings");
+        writeln!(out, "# strings")?;
 
         for string in strings {
-            println!("{} = {:?}", string.hash(), string);
+            writeln!(out, "{} = {:?}", string.hash(), string)?;
         }
     }
 
     if keys.peek().is_some() {
-        println!("# object keys");
+        writeln!(out, "# object keys")?;
 
         for (hash, keys) in keys {
-            println!("{} = {:?}", hash, keys);
+            writeln!(out, "{} = {:?}", hash, keys)?;
+        }
+    }
+    Ok(())
+}
+
+/// Run all functions marked with `#[test]` in the unit and report the result
+/// of each one.
+async fn run_tests(vm: &runestick::Vm, sources: &rune::Sources, color: ColorChoice) -> Result<()> {
+    let tests = vm.unit().iter_tests().cloned().collect::<Vec<_>>();
+    println!("running {} tests", tests.len());
+
+    let mut failures = Vec::new();
+
+    for item in &tests {
+        let result = match vm.clone().call(item, ()) {
+            Ok(mut execution) => execution.async_complete().await,
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(..) => println!("test {} ... ok", item),
+            Err(error) => {
+                println!(
+                    "test {} ... FAILED: {}",
+                    item,
+                    describe_failure(&error, sources)
+                );
+                failures.push(error);
+            }
+        }
+    }
+
+    println!(
+        "test result: {}. {} passed; {} failed",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        tests.len() - failures.len(),
+        failures.len()
+    );
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    let failed = failures.len();
+    let mut writer = StandardStream::stderr(color);
+
+    for error in failures {
+        error.emit_diagnostics(&mut writer, sources)?;
+    }
+
+    anyhow::bail!("{} of {} tests failed", failed, tests.len());
+}
+
+/// The amount of time to spend sampling each benchmark.
+const BENCH_BUDGET: Duration = Duration::from_secs(1);
+
+/// The maximum number of iterations to run for each benchmark.
+const BENCH_MAX_ITERATIONS: usize = 10_000;
+
+/// Run all functions marked with `#[bench]` in the unit repeatedly and report
+/// how long each iteration takes.
+///
+/// Each benchmark is called repeatedly until either [BENCH_BUDGET] has been
+/// exceeded or [BENCH_MAX_ITERATIONS] have been run, but at least once.
+async fn run_benches(
+    vm: &runestick::Vm,
+    sources: &rune::Sources,
+    color: ColorChoice,
+) -> Result<()> {
+    let benches = vm.unit().iter_benches().cloned().collect::<Vec<_>>();
+    println!("running {} benches", benches.len());
+
+    for item in &benches {
+        let mut samples = Vec::new();
+        let start = std::time::Instant::now();
+
+        while samples.is_empty()
+            || (start.elapsed() < BENCH_BUDGET && samples.len() < BENCH_MAX_ITERATIONS)
+        {
+            let last = std::time::Instant::now();
+
+            let result = match vm.clone().call(item, ()) {
+                Ok(mut execution) => execution.async_complete().await,
+                Err(error) => Err(error),
+            };
+
+            if let Err(error) = result {
+                println!(
+                    "bench {} ... FAILED: {}",
+                    item,
+                    describe_failure(&error, sources)
+                );
+                let mut writer = StandardStream::stderr(color);
+                error.emit_diagnostics(&mut writer, sources)?;
+                anyhow::bail!("bench `{}` failed", item);
+            }
+
+            samples.push(std::time::Instant::now().duration_since(last));
         }
+
+        let stats = BenchStats::new(&samples);
+
+        println!(
+            "bench {} ... {:?}/iter (+/- {:?}), {:.0} iter/sec ({} iterations)",
+            item,
+            stats.mean,
+            stats.deviation,
+            stats.per_second(),
+            samples.len()
+        );
     }
+
     Ok(())
 }
 
+/// Statistics collected for a single benchmark.
+struct BenchStats {
+    /// The mean time spent per iteration.
+    mean: Duration,
+    /// The standard deviation of the time spent per iteration.
+    deviation: Duration,
+}
+
+impl BenchStats {
+    /// Calculate statistics from a non-empty collection of samples.
+    fn new(samples: &[Duration]) -> Self {
+        let count = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            deviation: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// The number of iterations which can be performed per second.
+    fn per_second(&self) -> f64 {
+        1.0 / self.mean.as_secs_f64()
+    }
+}
+
+/// Describe why a test failed, including where in the source it failed if
+/// debug information is available.
+///
+/// Panics, like failed assertions, are described by their reason alone.
+fn describe_failure(error: &VmError, sources: &rune::Sources) -> String {
+    let (kind, location) = match error.kind() {
+        VmErrorKind::Unwound { kind, unit, ip } => (&**kind, location_for(unit, *ip, sources)),
+        kind => (kind, None),
+    };
+
+    let reason = match kind {
+        VmErrorKind::Panic { reason } => reason.to_string(),
+        kind => kind.to_string(),
+    };
+
+    match location {
+        Some(location) => format!("{} at {}", reason, location),
+        None => reason,
+    }
+}
+
+/// Get the `file:line:column` location of the instruction at the given
+/// instruction pointer.
+fn location_for(unit: &Unit, ip: usize, sources: &rune::Sources) -> Option<String> {
+    let debug = unit.debug_info()?.instruction_at(ip)?;
+    let source = sources.get(debug.source_id)?;
+    let (line, column) = rune::diagnostics::line_column_for(source.as_str(), debug.span)?;
+    Some(format!("{}:{}:{}", source.name(), line + 1, column + 1))
+}
+
+/// Build breakpoints from `file:line` specifications, where `file` is
+/// matched against the end of the names of the loaded sources.
+fn build_breakpoints(
+    specs: &[String],
+    unit: &Unit,
+    sources: &rune::Sources,
+) -> Result<runestick::Breakpoints> {
+    let mut breakpoints =
+        runestick::Breakpoints::new(rune::diagnostics::instruction_lines(unit, sources));
+
+    for spec in specs {
+        let (file, line) = match spec.rfind(':') {
+            Some(n) => (&spec[..n], &spec[n + 1..]),
+            None => anyhow::bail!("bad breakpoint `{}`, expected `file:line`", spec),
+        };
+
+        let line = match line.parse::<usize>() {
+            Ok(line) if line > 0 => line,
+            _ => anyhow::bail!("bad line in breakpoint `{}`", spec),
+        };
+
+        let mut source_id = 0;
+
+        let source_id = loop {
+            match sources.get(source_id) {
+                Some(source) if Path::new(source.name()).ends_with(file) => break source_id,
+                Some(..) => source_id += 1,
+                None => anyhow::bail!("no source matching breakpoint `{}`", spec),
+            }
+        };
+
+        breakpoints.insert(source_id, line - 1);
+    }
+
+    Ok(breakpoints)
+}
+
+/// Run the program, printing the location and dumping the stack every time a
+/// breakpoint is hit.
+fn do_break(
+    execution: &mut VmExecution,
+    sources: &rune::Sources,
+    out: &mut dyn io::Write,
+    pretty: bool,
+) -> Result<Result<Value, VmError>> {
+    loop {
+        match execution.resume_until_breakpoint() {
+            Ok(Some(value)) => return Ok(Ok(value)),
+            Ok(None) => (),
+            Err(error) => return Ok(Err(error)),
+        }
+
+        let vm = execution.vm()?;
+
+        if let Some((source_id, line)) = vm.breakpoints().and_then(|b| b.hit()) {
+            let name = sources.get(source_id).map(|s| s.name()).unwrap_or("?");
+            writeln!(out, "== breakpoint {}:{}", name, line + 1)?;
+        }
+
+        dump_stack(out, execution, pretty)?;
+    }
+}
+
 enum TraceError {
     Io(std::io::Error),
     VmError(runestick::VmError),
@@ -414,26 +801,44 @@ async fn do_trace(
     execution: &mut VmExecution,
     sources: &rune::Sources,
     dump_stack: bool,
+    pretty: bool,
     with_source: bool,
+    filter: Option<&str>,
+    color: ColorChoice,
 ) -> Result<Value, TraceError> {
-    use std::io::Write as _;
-    let out = std::io::stdout();
+    let out = StandardStream::stdout(color);
+    let colors = TraceColors::new();
 
-    let mut current_frame_len = execution
-        .vm()
-        .map_err(TraceError::VmError)?
-        .call_frames()
-        .len();
+    let (mut current_frame_len, filter) = {
+        let vm = execution.vm().map_err(TraceError::VmError)?;
+        let filter = filter.map(|item| (vm.unit().clone(), function_range(vm.unit(), item)));
+        (vm.call_frames().len(), filter)
+    };
 
     loop {
-        {
+        let traced = match &filter {
+            Some((unit, range)) => {
+                let vm = execution.vm().map_err(TraceError::VmError)?;
+
+                match range {
+                    Some(range) => Arc::ptr_eq(unit, vm.unit()) && range.contains(&vm.ip()),
+                    None => false,
+                }
+            }
+            None => true,
+        };
+
+        if traced {
             let vm = execution.vm().map_err(TraceError::VmError)?;
             let mut out = out.lock();
 
             if let Some((hash, signature)) =
                 vm.unit().debug_info().and_then(|d| d.function_at(vm.ip()))
             {
-                writeln!(out, "fn {} ({}):", signature, hash)?;
+                out.set_color(&colors.function)?;
+                write!(out, "fn {}", signature)?;
+                out.reset()?;
+                writeln!(out, " ({}):", hash)?;
             }
 
             let debug = vm
@@ -447,38 +852,82 @@ async fn do_trace(
                 {
                     if let Some((count, line)) = rune::diagnostics::line_for(source.as_str(), span)
                     {
-                        writeln!(
+                        out.set_color(&colors.source)?;
+                        write!(
                             out,
                             "  {}:{: <3} - {}",
                             source.name(),
                             count + 1,
                             line.trim_end()
                         )?;
+                        out.reset()?;
+                        writeln!(out)?;
                     }
                 }
             }
 
             if let Some(inst) = debug {
                 if let Some(label) = &inst.label {
-                    writeln!(out, "{}:", label)?;
+                    out.set_color(&colors.label)?;
+                    write!(out, "{}:", label)?;
+                    out.reset()?;
+                    writeln!(out)?;
                 }
             }
 
+            write!(out, "  {:04} = ", vm.ip())?;
+
             if let Some(inst) = vm.unit().instruction_at(vm.ip()) {
-                write!(out, "  {:04} = {}", vm.ip(), inst)?;
+                let inst = inst.to_string();
+
+                // NB: the opcode is the first word of the formatted
+                // instruction, anything after it are operands.
+                let (opcode, operands) = match inst.find(' ') {
+                    Some(n) => inst.split_at(n),
+                    None => (inst.as_str(), ""),
+                };
+
+                out.set_color(&colors.opcode)?;
+                write!(out, "{}", opcode)?;
+                out.reset()?;
+                write!(out, "{}", operands)?;
             } else {
-                write!(out, "  {:04} = *out of bounds*", vm.ip())?;
+                write!(out, "*out of bounds*")?;
             }
 
             if let Some(inst) = debug {
                 if let Some(comment) = &inst.comment {
+                    out.set_color(&colors.comment)?;
                     write!(out, " // {}", comment)?;
+                    out.reset()?;
                 }
             }
 
             writeln!(out,)?;
         }
 
+        // NB: the virtual machine suspends while the futures it is waiting on
+        // are being polled, so mark where it happens and what it's waiting on.
+        let suspended = if traced {
+            suspension_point(execution.vm().map_err(TraceError::VmError)?)
+        } else {
+            None
+        };
+
+        if let Some(pending) = &suspended {
+            let mut out = out.lock();
+
+            for future in pending {
+                out.set_color(&colors.suspend)?;
+                write!(out, "  -- suspended on ")?;
+                out.reset()?;
+                out.set_color(&colors.value)?;
+                write_value(&mut out, future, pretty)?;
+                out.reset()?;
+                writeln!(out)?;
+            }
+        }
+
         let result = match execution.async_step().await {
             Ok(result) => result,
             Err(e) => return Err(TraceError::VmError(e)),
@@ -486,30 +935,54 @@ async fn do_trace(
 
         let mut out = out.lock();
 
-        if dump_stack {
+        if suspended.is_some() {
+            let vm = execution.vm().map_err(TraceError::VmError)?;
+
+            out.set_color(&colors.suspend)?;
+            write!(out, "  -- resumed with ")?;
+            out.reset()?;
+
+            if let Ok(value) = vm.stack().last() {
+                out.set_color(&colors.value)?;
+                write_value(&mut out, value, pretty)?;
+                out.reset()?;
+            }
+
+            writeln!(out)?;
+        }
+
+        if dump_stack && traced {
             let vm = execution.vm().map_err(TraceError::VmError)?;
             let frames = vm.call_frames();
 
             let stack = vm.stack();
 
             if current_frame_len != frames.len() {
+                out.set_color(&colors.frame)?;
+
                 if current_frame_len < frames.len() {
-                    println!("=> frame {} ({}):", frames.len(), stack.stack_bottom());
+                    write!(out, "=> frame {} ({}):", frames.len(), stack.stack_bottom())?;
                 } else {
-                    println!("<= frame {} ({}):", frames.len(), stack.stack_bottom());
+                    write!(out, "<= frame {} ({}):", frames.len(), stack.stack_bottom())?;
                 }
 
+                out.reset()?;
+                writeln!(out)?;
                 current_frame_len = frames.len();
             }
 
             let values = stack.get(stack.stack_bottom()..).expect("bad stack slice");
 
             if values.is_empty() {
-                println!("    *empty*");
+                writeln!(out, "    *empty*")?;
             }
 
             for (n, value) in values.iter().enumerate() {
-                writeln!(out, "    {}+{} = {:?}", stack.stack_bottom(), n, value)?;
+                write!(out, "    {}+{} = ", stack.stack_bottom(), n)?;
+                out.set_color(&colors.value)?;
+                write_value(&mut out, value, pretty)?;
+                out.reset()?;
+                writeln!(out)?;
             }
         }
 
@@ -519,6 +992,91 @@ async fn do_trace(
     }
 }
 
+/// Colors used when tracing.
+struct TraceColors {
+    function: ColorSpec,
+    source: ColorSpec,
+    label: ColorSpec,
+    opcode: ColorSpec,
+    comment: ColorSpec,
+    frame: ColorSpec,
+    value: ColorSpec,
+    suspend: ColorSpec,
+}
+
+impl TraceColors {
+    fn new() -> Self {
+        Self {
+            function: Self::spec(Color::Blue, true),
+            source: Self::spec(Color::White, false),
+            label: Self::spec(Color::Yellow, true),
+            opcode: Self::spec(Color::Green, true),
+            comment: Self::spec(Color::Cyan, false),
+            frame: Self::spec(Color::Magenta, true),
+            value: Self::spec(Color::Cyan, false),
+            suspend: Self::spec(Color::Red, true),
+        }
+    }
+
+    fn spec(color: Color, bold: bool) -> ColorSpec {
+        let mut spec = ColorSpec::new();
+        spec.set_fg(Some(color)).set_bold(bold);
+        spec
+    }
+}
+
+/// Get the futures the virtual machine will suspend on when executing the
+/// current instruction, if it is an `await` or a `select`.
+fn suspension_point(vm: &runestick::Vm) -> Option<Vec<Value>> {
+    let len = match vm.unit().instruction_at(vm.ip())? {
+        Inst::Await => 1,
+        Inst::Select { len, .. } => *len,
+        _ => return None,
+    };
+
+    let stack = vm.stack();
+    let start = stack.len().checked_sub(len)?;
+    Some(stack.get(start..)?.to_vec())
+}
+
+/// Get the range of instructions making up the function with the given item
+/// in the unit.
+///
+/// This requires debug information, without it nothing will match.
+fn function_range(unit: &Unit, item: &str) -> Option<std::ops::Range<usize>> {
+    let debug = unit.debug_info()?;
+
+    let start = debug
+        .functions_rev
+        .iter()
+        .filter_map(|(offset, hash)| Some((*offset, debug.functions.get(hash)?)))
+        .find(|(_, signature)| signature.path.to_string() == item)
+        .map(|(offset, _)| offset)?;
+
+    // NB: functions are laid out contiguously, so the function ends where the
+    // next one starts.
+    let end = debug
+        .functions_rev
+        .keys()
+        .copied()
+        .filter(|offset| *offset > start)
+        .min()
+        .unwrap_or_else(|| unit.iter_instructions().count());
+
+    Some(start..end)
+}
+
+/// Get the color choice to use for output.
+///
+/// Honors both the `--no-color` switch and the `NO_COLOR` convention.
+fn color_choice(args: &Args) -> ColorChoice {
+    if args.no_color || std::env::var_os("NO_COLOR").is_some() {
+        ColorChoice::Never
+    } else {
+        ColorChoice::Auto
+    }
+}
+
 /// Test if path `a` is newer than path `b`.
 fn should_cache_be_used(source: &Path, cached: &Path) -> io::Result<bool> {
     let source = fs::metadata(source)?;
@@ -531,3 +1089,67 @@ fn should_cache_be_used(source: &Path, cached: &Path) -> io::Result<bool> {
 
     Ok(source.modified()? < cached.modified()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{dump_stack, BenchStats};
+    use runestick::{Item, Source};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_dump_stack_two_frames() {
+        let context = Arc::new(rune::default_context().unwrap());
+        let mut sources = rune::Sources::new();
+        let mut warnings = rune::Warnings::new();
+
+        sources.insert_default(Source::new(
+            "main",
+            r#"
+            fn foo(a) { let b = 20; a + b }
+            fn main() { let x = 10; foo(x) }
+            "#,
+        ));
+
+        let unit = rune::load_sources(&*context, &Default::default(), &mut sources, &mut warnings)
+            .unwrap();
+
+        let vm = runestick::Vm::new(context, Arc::new(unit));
+        let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+
+        // Step until we're inside of `foo` and `b` has been assigned.
+        while execution.vm().unwrap().stack().len() < 3 {
+            execution.step().unwrap();
+        }
+
+        let mut out = Vec::new();
+        dump_stack(&mut out, &execution, false).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+# full stack dump after halting
+  frame #0 (+0)
+    0+0 = 10
+  frame #1 (+1)
+    1+0 = 10
+    1+1 = 20
+"
+        );
+    }
+
+    #[test]
+    fn test_bench_stats() {
+        let samples = [
+            Duration::from_millis(2),
+            Duration::from_millis(4),
+            Duration::from_millis(4),
+            Duration::from_millis(6),
+        ];
+
+        let stats = BenchStats::new(&samples);
+        assert_eq!(stats.mean, Duration::from_millis(4));
+        assert_eq!(stats.deviation.as_micros(), 1414);
+        assert_eq!(stats.per_second().round(), 250.0);
+    }
+}
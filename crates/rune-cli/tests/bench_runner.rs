@@ -0,0 +1,31 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_bench_is_reported() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("bench.rn");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rune"))
+        .arg(&path)
+        .arg("--bench")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+
+    assert_eq!(lines.next(), Some("running 1 benches"));
+
+    let line = lines.next().expect("missing bench report");
+    assert!(line.starts_with("bench fib10 ... "), "bad report: {}", line);
+    assert!(line.contains("/iter (+/- "), "bad report: {}", line);
+    assert!(line.contains(" iter/sec ("), "bad report: {}", line);
+
+    assert_eq!(lines.next(), None);
+}
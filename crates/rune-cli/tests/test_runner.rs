@@ -0,0 +1,87 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_failing_assertion_is_reported() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("failing_test.rn");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rune"))
+        .arg(&path)
+        .arg("--test")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "\
+running 2 tests
+test add_works ... ok
+test math::add ... FAILED: assertion failed `left == right` (left: 3, right: 4) at {}:13:9
+test result: FAILED. 1 passed; 1 failed
+",
+        path.display()
+    );
+
+    assert_eq!(stdout, expected);
+}
+
+#[test]
+fn test_trace_marks_suspension_points() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("async_trace.rn");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rune"))
+        .arg(&path)
+        .arg("--trace")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines = stdout.lines().collect::<Vec<_>>();
+
+    let suspended = lines
+        .iter()
+        .position(|line| line.starts_with("  -- suspended on Future {"))
+        .expect("missing suspend marker");
+
+    assert!(lines[suspended - 1].ends_with("await"));
+    assert_eq!(lines[suspended + 1], "  -- resumed with 42");
+}
+
+#[test]
+fn test_trace_filter_only_traces_function() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("trace_filter.rn");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rune"))
+        .arg(&path)
+        .arg("--trace-filter")
+        .arg("foo")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines = stdout.lines().collect::<Vec<_>>();
+
+    let functions = lines.iter().filter(|l| l.starts_with("fn ")).count();
+    assert_eq!(functions, 2);
+    assert!(lines.iter().all(|l| !l.starts_with("fn main")));
+    assert!(lines.iter().all(|l| !l.contains("call ")));
+}
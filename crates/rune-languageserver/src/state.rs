@@ -65,17 +65,19 @@ impl State {
                 continue;
             }
 
+            let text = source.to_string();
             let mut sources = rune::Sources::new();
-            sources.insert_default(runestick::Source::new(url.to_string(), source.to_string()));
+            sources.insert_default(runestick::Source::new(url.to_string(), text.clone()));
 
             let mut warnings = rune::Warnings::new();
             let mut diagnostics = Vec::new();
 
-            let error = rune::load_sources(
+            let error = rune::load_sources_cached(
                 &self.inner.context,
                 &self.inner.options,
                 &mut sources,
                 &mut warnings,
+                &mut source.cache,
             );
 
             if let Err(error) = error {
@@ -88,7 +90,17 @@ impl State {
                     }
                     // TODO: match source id with the document that has the error.
                     rune::LoadErrorKind::ParseError { error, .. } => {
-                        diagnostics.push(source.display_to_error(error.span(), error));
+                        // NB: reparse with recovery to report every syntax
+                        // error in the document, not only the first one.
+                        let (_, errors) = rune::parse_all_with_recovery(&text);
+
+                        if errors.is_empty() {
+                            diagnostics.push(source.display_to_error(error.span(), error));
+                        }
+
+                        for error in errors {
+                            diagnostics.push(source.display_to_error(error.span(), error));
+                        }
                     }
                     // TODO: match the source id with the document that has the error.
                     rune::LoadErrorKind::CompileError { error, .. } => {
@@ -97,12 +109,15 @@ impl State {
                     rune::LoadErrorKind::LinkError { errors } => {
                         for error in errors {
                             match error {
-                                rune::LinkerError::MissingFunction { hash, spans } => {
+                                rune::LinkerError::MissingFunction { hash, item, spans } => {
+                                    let message = match item {
+                                        Some(item) => format!("missing function `{}`", item),
+                                        None => format!("missing function with hash `{}`", hash),
+                                    };
+
                                     for (span, _) in spans {
-                                        diagnostics.push(source.display_to_error(
-                                            *span,
-                                            format!("missing function with hash `{}`", hash),
-                                        ));
+                                        diagnostics
+                                            .push(source.display_to_error(*span, message.clone()));
                                     }
                                 }
                             }
@@ -183,6 +198,7 @@ impl Sources {
         let source = Source {
             dirty: true,
             content: Rope::from(text),
+            cache: rune::CompileCache::new(),
         };
 
         self.sources.insert(url, source)
@@ -205,6 +221,8 @@ pub struct Source {
     dirty: bool,
     /// The content of the current source.
     content: Rope,
+    /// Cache used to avoid recompiling the source if it hasn't changed.
+    cache: rune::CompileCache,
 }
 
 impl Source {
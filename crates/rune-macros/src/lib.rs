@@ -39,7 +39,10 @@
 use rune::ast;
 use rune::{MacroContext, Parser, TokenStream};
 
+mod object_macro;
+mod stringify_macro;
 mod stringy_math_macro;
+mod vec_macro;
 
 /// Implementation for the `passthrough!` macro.
 fn passthrough_impl(_: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
@@ -58,6 +61,28 @@ fn make_function(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Res
     Ok(rune::quote!(ctx => fn #ident() { #output }))
 }
 
+/// Implementation for the `matches!` macro.
+///
+/// Expands `matches!(value, pattern)` into a `match` expression which tests if
+/// the value matches the pattern. The pattern may be followed by an `if` guard.
+fn matches(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+
+    let expr = parser.parse::<ast::Expr>()?;
+    let _ = parser.parse::<ast::Comma>()?;
+    let pat = parser.parse::<ast::Pat>()?;
+
+    let condition = if parser.peek::<ast::If>()? {
+        Some((parser.parse::<ast::If>()?, parser.parse::<ast::Expr>()?))
+    } else {
+        None
+    };
+
+    parser.parse_eof()?;
+
+    Ok(rune::quote!(ctx => match #expr { #pat #condition => true, _ => false }))
+}
+
 /// Construct the `std::experimental` module, which contains experimental
 /// macros.
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
@@ -65,5 +90,9 @@ pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     module.macro_(&["passthrough"], passthrough_impl)?;
     module.macro_(&["stringy_math"], stringy_math_macro::stringy_math)?;
     module.macro_(&["make_function"], make_function)?;
+    module.macro_(&["matches"], matches)?;
+    module.macro_(&["stringify"], stringify_macro::stringify)?;
+    module.macro_(&["vec"], vec_macro::vec)?;
+    module.macro_(&["object"], object_macro::object)?;
     Ok(module)
 }
@@ -0,0 +1,28 @@
+use rune::ast;
+use rune::{quote, MacroContext, Parser, TokenStream};
+
+/// Implementation for the `object!` macro.
+///
+/// Expands `object!{ key: value, ... }` into an anonymous object literal.
+/// Keys are either identifiers or string literals, and like in object literals
+/// the value can be omitted to use a variable with the same name as the key.
+pub(crate) fn object(
+    ctx: &mut MacroContext,
+    stream: &TokenStream,
+) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let mut assignments = Vec::new();
+
+    while !parser.is_eof()? {
+        assignments.push(parser.parse::<ast::LitObjectFieldAssign>()?);
+
+        if parser.peek::<ast::Comma>()? {
+            parser.parse::<ast::Comma>()?;
+        } else {
+            break;
+        }
+    }
+
+    parser.parse_eof()?;
+    Ok(quote!(ctx => #{ #(assignments),* }))
+}
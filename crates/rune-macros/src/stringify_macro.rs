@@ -0,0 +1,152 @@
+use rune::ast;
+use rune::ParseError;
+use rune::{quote, MacroContext, TokenStream};
+use std::fmt::Write as _;
+
+/// Implementation for the `stringify!` macro.
+///
+/// Expands into a string literal containing the source of the tokens passed
+/// to the macro. Whitespace between tokens is normalized, so
+/// `stringify!(1+2)` expands to `"1 + 2"`.
+pub(crate) fn stringify(
+    ctx: &mut MacroContext,
+    stream: &TokenStream,
+) -> runestick::Result<TokenStream> {
+    let mut output = String::new();
+    let mut previous = None;
+
+    for token in stream {
+        if let Some(previous) = previous {
+            if needs_space(previous, token.kind) {
+                output.push(' ');
+            }
+        }
+
+        write_token(ctx, token, &mut output)?;
+        previous = Some(token.kind);
+    }
+
+    let lit = ctx.lit(&output);
+    Ok(quote!(ctx => #lit))
+}
+
+/// Write the source of a single token to the output.
+fn write_token(
+    ctx: &MacroContext,
+    token: &ast::Token,
+    output: &mut String,
+) -> Result<(), ParseError> {
+    let span = token.span;
+
+    let bad_synthetic_id = |kind, id| ParseError::BadSyntheticId { kind, id, span };
+
+    match token.kind {
+        ast::Kind::Ident(ast::StringSource::Synthetic(id)) => {
+            let ident = ctx
+                .storage()
+                .get_string(id)
+                .ok_or_else(|| bad_synthetic_id("ident", id))?;
+            output.push_str(&ident);
+        }
+        ast::Kind::Label(ast::StringSource::Synthetic(id)) => {
+            let label = ctx
+                .storage()
+                .get_string(id)
+                .ok_or_else(|| bad_synthetic_id("label", id))?;
+            output.push('\'');
+            output.push_str(&label);
+        }
+        ast::Kind::LitStr(ast::LitStrSource::Synthetic(id)) => {
+            let string = ctx
+                .storage()
+                .get_string(id)
+                .ok_or_else(|| bad_synthetic_id("string", id))?;
+            write!(output, "{:?}", string).unwrap();
+        }
+        ast::Kind::LitTemplate(ast::LitStrSource::Synthetic(id)) => {
+            let string = ctx
+                .storage()
+                .get_string(id)
+                .ok_or_else(|| bad_synthetic_id("template", id))?;
+            write!(output, "`{}`", string).unwrap();
+        }
+        ast::Kind::LitByteStr(ast::LitByteStrSource::Synthetic(id)) => {
+            let bytes = ctx
+                .storage()
+                .get_byte_string(id)
+                .ok_or_else(|| bad_synthetic_id("byte string", id))?;
+            output.push_str("b\"");
+
+            for b in bytes {
+                output.extend(std::ascii::escape_default(b).map(char::from));
+            }
+
+            output.push('"');
+        }
+        ast::Kind::LitNumber(ast::NumberSource::Synthetic(id)) => {
+            let number = ctx
+                .storage()
+                .get_number(id)
+                .ok_or_else(|| bad_synthetic_id("number", id))?;
+
+            match number {
+                ast::Number::Integer(n) => write!(output, "{}", n).unwrap(),
+                ast::Number::Float(n) => write!(output, "{:?}", n).unwrap(),
+            }
+        }
+        ast::Kind::LitChar(ast::CopySource::Inline(c)) => {
+            write!(output, "{:?}", c).unwrap();
+        }
+        ast::Kind::LitByte(ast::CopySource::Inline(b)) => {
+            output.push_str("b'");
+            output.extend(std::ascii::escape_default(b).map(char::from));
+            output.push('\'');
+        }
+        ast::Kind::Ident(..)
+        | ast::Kind::Label(..)
+        | ast::Kind::LitStr(..)
+        | ast::Kind::LitTemplate(..)
+        | ast::Kind::LitByteStr(..)
+        | ast::Kind::LitNumber(..)
+        | ast::Kind::LitChar(..)
+        | ast::Kind::LitByte(..) => {
+            let source = ctx
+                .source()
+                .source(span)
+                .ok_or(ParseError::BadSlice { span })?;
+            output.push_str(source);
+        }
+        kind => {
+            write!(output, "{}", kind).unwrap();
+        }
+    }
+
+    Ok(())
+}
+
+/// Test if a space should be inserted between the two given tokens.
+fn needs_space(previous: ast::Kind, current: ast::Kind) -> bool {
+    match previous {
+        ast::Kind::Open(..) | ast::Kind::Dot | ast::Kind::ColonColon | ast::Kind::Pound => {
+            return false;
+        }
+        _ => (),
+    }
+
+    match current {
+        ast::Kind::Close(..)
+        | ast::Kind::Comma
+        | ast::Kind::SemiColon
+        | ast::Kind::Colon
+        | ast::Kind::ColonColon
+        | ast::Kind::Dot
+        | ast::Kind::QuestionMark => false,
+        ast::Kind::Open(ast::Delimiter::Parenthesis) | ast::Kind::Open(ast::Delimiter::Bracket) => {
+            !matches!(
+                previous,
+                ast::Kind::Ident(..) | ast::Kind::Close(..) | ast::Kind::Bang
+            )
+        }
+        _ => true,
+    }
+}
@@ -0,0 +1,58 @@
+use rune::ast;
+use rune::{quote, MacroContext, Parser, TokenStream};
+
+/// Implementation for the `vec!` macro.
+///
+/// Supports both a list of expressions, like `vec![1, 2, 3]`, and a repeat
+/// form, like `vec![value; count]`, which constructs a vector where the value
+/// is repeated `count` times. The value in the repeat form is only evaluated
+/// once.
+///
+/// Unlike in Rust, the value isn't cloned, so every element of the repeat form
+/// refers to the same value. `vec![[]; 3]` therefore produces three references
+/// to a single vector, and pushing to one of them is visible through all of
+/// them.
+pub(crate) fn vec(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+
+    if parser.is_eof()? {
+        return Ok(quote!(ctx => []));
+    }
+
+    let first = parser.parse::<ast::Expr>()?;
+
+    if parser.peek::<ast::SemiColon>()? {
+        parser.parse::<ast::SemiColon>()?;
+        let count = parser.parse::<ast::Expr>()?;
+        parser.parse_eof()?;
+
+        return Ok(quote!(ctx => {
+            let value = #first;
+            let count = #count;
+            let vec = std::vec::Vec::with_capacity(count);
+            let index = 0;
+
+            while index < count {
+                vec.push(value);
+                index += 1;
+            }
+
+            vec
+        }));
+    }
+
+    let mut items = vec![first];
+
+    while parser.peek::<ast::Comma>()? {
+        parser.parse::<ast::Comma>()?;
+
+        if parser.is_eof()? {
+            break;
+        }
+
+        items.push(parser.parse::<ast::Expr>()?);
+    }
+
+    parser.parse_eof()?;
+    Ok(quote!(ctx => [#(items),*]))
+}
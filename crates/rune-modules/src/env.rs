@@ -0,0 +1,108 @@
+//! The native `env` module for the [Rune Language].
+//!
+//! [Rune Language]: https://github.com/rune-rs/rune
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.6.16", features = ["env"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::env::module()?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use env;
+//!
+//! fn main() {
+//!     if let Some(home) = env::var("HOME") {
+//!         println(`home is {home}`);
+//!     }
+//!
+//!     dbg(env::args());
+//! }
+//! ```
+//!
+//! Access to the environment can be denied through
+//! [Context::allow_host_access][runestick::Context::allow_host_access] with
+//! [HostAccess::Env].
+
+use runestick::{ContextError, HostAccess, Module, Object, Value, VmError};
+use std::env;
+
+/// Construct the `env` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["env"]);
+    module.function(&["var"], var)?;
+    module.function(&["vars"], vars)?;
+    module.function(&["args"], args)?;
+    Ok(module)
+}
+
+/// Get the value of an environment variable, or `None` if it's not set or
+/// isn't valid unicode.
+fn var(name: &str) -> Result<Option<String>, VmError> {
+    HostAccess::Env.check()?;
+    Ok(env::var(name).ok())
+}
+
+/// Get all environment variables which are valid unicode as an object.
+fn vars() -> Result<Object, VmError> {
+    HostAccess::Env.check()?;
+
+    let mut object = Object::new();
+
+    for (key, value) in env::vars_os() {
+        if let (Ok(key), Ok(value)) = (key.into_string(), value.into_string()) {
+            object.insert(key, Value::from(value));
+        }
+    }
+
+    Ok(object)
+}
+
+/// Get the arguments the current process was started with.
+fn args() -> Result<Vec<String>, VmError> {
+    HostAccess::Env.check()?;
+
+    Ok(env::args_os()
+        .filter_map(|arg| arg.into_string().ok())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{args, var, vars};
+    use runestick::FromValue as _;
+
+    #[test]
+    fn test_env() {
+        std::env::set_var("RUNE_MODULES_TEST_ENV", "hello");
+        std::env::remove_var("RUNE_MODULES_TEST_ENV_MISSING");
+
+        assert_eq!(
+            var("RUNE_MODULES_TEST_ENV").unwrap(),
+            Some(String::from("hello"))
+        );
+        assert_eq!(var("RUNE_MODULES_TEST_ENV_MISSING").unwrap(), None);
+
+        let vars = vars().unwrap();
+        let value = vars.get("RUNE_MODULES_TEST_ENV").unwrap().clone();
+        assert_eq!(String::from_value(value).unwrap(), "hello");
+        assert!(vars.get("RUNE_MODULES_TEST_ENV_MISSING").is_none());
+
+        let expected = std::env::args().collect::<Vec<_>>();
+        assert_eq!(args().unwrap(), expected);
+    }
+}
@@ -26,9 +26,18 @@
 //! fn main() {
 //!     let file = fs::read_to_string("file.txt").await?;
 //!     println(`{file}`);
+//!     fs::write("copy.txt", file).await?;
+//!
+//!     let bytes = fs::read_bytes("image.png").await?;
+//!     fs::write("copy.png", bytes).await?;
 //! }
 //! ```
+//!
+//! Access to the filesystem can be denied through
+//! [Context::allow_host_access][runestick::Context::allow_host_access] with
+//! [HostAccess::Fs].
 
+use runestick::{Bytes, HostAccess, Value, VmError};
 use std::io;
 use tokio::fs;
 
@@ -36,9 +45,31 @@ use tokio::fs;
 pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::new(&["fs"]);
     module.async_function(&["read_to_string"], read_to_string)?;
+    module.async_function(&["read_bytes"], read_bytes)?;
+    module.async_function(&["write"], write)?;
     Ok(module)
 }
 
-async fn read_to_string(path: &str) -> io::Result<String> {
-    fs::read_to_string(path).await
+async fn read_to_string(path: &str) -> Result<io::Result<String>, VmError> {
+    HostAccess::Fs.check()?;
+    Ok(fs::read_to_string(path).await)
+}
+
+async fn read_bytes(path: &str) -> Result<io::Result<Bytes>, VmError> {
+    HostAccess::Fs.check()?;
+    Ok(fs::read(path).await.map(Bytes::from_vec))
+}
+
+/// Write either a string or bytes to the file at the given path.
+async fn write(path: &str, contents: Value) -> Result<io::Result<()>, VmError> {
+    HostAccess::Fs.check()?;
+
+    let contents = match contents {
+        Value::String(string) => string.borrow_ref()?.as_bytes().to_vec(),
+        Value::StaticString(string) => string.as_bytes().to_vec(),
+        Value::Bytes(bytes) => bytes.borrow_ref()?.to_vec(),
+        actual => return Err(VmError::bad_argument::<String>(1, &actual)?),
+    };
+
+    Ok(fs::write(path, contents).await)
 }
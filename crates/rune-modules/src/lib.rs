@@ -48,6 +48,7 @@
 //! * [fs]
 //! * [process]
 //! * [signal]
+//! * [env]
 //!
 //! ## Features
 //!
@@ -59,6 +60,7 @@
 //! * `fs` for the [fs module]][fs]
 //! * `process` for the [process module]][process]
 //! * `signal` for the [process module]][signal]
+//! * `env` for the [env module][env]
 //!
 //! [http]: https://docs.rs/rune-modules/0/rune_modules/http/
 //! [json]: https://docs.rs/rune-modules/0/rune_modules/json/
@@ -67,6 +69,7 @@
 //! [fs]: https://docs.rs/rune-modules/0/rune_modules/fs/
 //! [process]: https://docs.rs/rune-modules/0/rune_modules/process/
 //! [signal]: https://docs.rs/rune-modules/0/rune_modules/signal/
+//! [env]: https://docs.rs/rune-modules/0/rune_modules/env/
 
 #[cfg(feature = "http")]
 pub mod http;
@@ -88,3 +91,6 @@ pub mod process;
 
 #[cfg(feature = "signal")]
 pub mod signal;
+
+#[cfg(feature = "env")]
+pub mod env;
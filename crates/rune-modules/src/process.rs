@@ -16,6 +16,7 @@
 //! # fn main() -> runestick::Result<()> {
 //! let mut context = runestick::Context::with_default_modules()?;
 //! context.install(&rune_modules::process::module()?)?;
+//! context.allow_host_access(runestick::HostAccess::Process, true);
 //! # Ok(())
 //! # }
 //! ```
@@ -28,10 +29,17 @@
 //! fn main() {
 //!     let command = Command::new("ls");
 //!     command.run().await;
+//!
+//!     let output = process::run("git", ["status"]).await?;
+//!     println(`{output.status}`);
 //! }
 //! ```
+//!
+//! Running external commands is denied by default, and has to be granted
+//! through [Context::allow_host_access][runestick::Context::allow_host_access]
+//! with [HostAccess::Process].
 
-use runestick::{Any, Bytes, Shared, Value, VmError};
+use runestick::{Any, Bytes, HostAccess, Shared, Value, VmError};
 use std::fmt;
 use std::io;
 use tokio::process;
@@ -44,6 +52,7 @@ pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     module.ty(&["ExitStatus"]).build::<ExitStatus>()?;
     module.ty(&["Output"]).build::<Output>()?;
 
+    module.async_function(&["run"], run)?;
     module.function(&["Command", "new"], Command::new)?;
     module.inst_fn("spawn", Command::spawn)?;
     module.inst_fn("arg", Command::arg)?;
@@ -59,6 +68,30 @@ pub fn module() -> Result<runestick::Module, runestick::ContextError> {
     Ok(module)
 }
 
+/// Run the given command with arguments to completion, capturing its output.
+///
+/// A non-zero exit code is not an error, but is reported through the status
+/// of the output.
+async fn run(command: &str, args: &[Value]) -> Result<io::Result<Output>, VmError> {
+    HostAccess::Process.check()?;
+
+    let mut command = Command::new(command);
+    command.args(args)?;
+    command.inner.stdout(std::process::Stdio::piped());
+    command.inner.stderr(std::process::Stdio::piped());
+
+    let output = match command.inner.output().await {
+        Ok(output) => output,
+        Err(error) => return Ok(Err(error)),
+    };
+
+    Ok(Ok(Output {
+        status: output.status,
+        stdout: Shared::new(Bytes::from_vec(output.stdout)),
+        stderr: Shared::new(Bytes::from_vec(output.stderr)),
+    }))
+}
+
 #[derive(Any)]
 struct Command {
     inner: process::Command,
@@ -97,10 +130,10 @@ impl Command {
     }
 
     /// Spawn the command.
-    fn spawn(mut self) -> io::Result<Child> {
-        Ok(Child {
-            inner: Some(self.inner.spawn()?),
-        })
+    fn spawn(mut self) -> Result<io::Result<Child>, VmError> {
+        HostAccess::Process.check()?;
+
+        Ok(self.inner.spawn().map(|inner| Child { inner: Some(inner) }))
     }
 }
 
@@ -44,6 +44,7 @@
 
 pub use futures_executor::block_on;
 pub use rune::CompileError::*;
+pub use rune::Options;
 pub use rune::ParseError::*;
 use rune::Sources;
 use rune::UnitBuilder;
@@ -52,7 +53,7 @@ use rune::Warnings;
 pub use runestick::Result;
 pub use runestick::VmErrorKind::*;
 pub use runestick::{CompileMeta, Function, Span, Value};
-use runestick::{Component, Item, Source, Unit};
+use runestick::{Component, Item, Source, Unit, Vm, VmError};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -70,6 +71,94 @@ pub fn compile_source(context: &runestick::Context, source: &str) -> Result<(Uni
     Ok((unit.into_unit(), warnings))
 }
 
+/// Compile the given source into a unit and collection of warnings, using the
+/// specified compiler options.
+pub fn compile_source_with_options(
+    context: &runestick::Context,
+    options: &Options,
+    source: &str,
+) -> Result<(Unit, Warnings)> {
+    let mut warnings = Warnings::new();
+    let mut sources = Sources::new();
+    sources.insert_default(Source::new("main", source));
+    let unit = rune::load_sources(context, options, &mut sources, &mut warnings)?;
+    Ok((unit, warnings))
+}
+
+/// Compile the given source into a unit using the specified compiler
+/// options, panicking if it fails to compile.
+pub fn compile_with_options(context: &runestick::Context, options: &Options, source: &str) -> Unit {
+    let (unit, _) =
+        compile_source_with_options(context, options, source).expect("program to compile");
+    unit
+}
+
+/// Call the specified function in the given unit.
+///
+/// The virtual machine is configured through `configure` before the function
+/// is called, which can be used to for example limit its memory or gas.
+pub fn run_unit<N, A, T, F>(
+    context: Arc<runestick::Context>,
+    unit: Arc<Unit>,
+    function: N,
+    args: A,
+    configure: F,
+) -> Result<T, VmError>
+where
+    N: IntoIterator,
+    N::Item: Into<Component>,
+    A: runestick::Args,
+    T: runestick::FromValue,
+    F: FnOnce(Vm) -> Vm,
+{
+    let vm = configure(Vm::new(context, unit));
+    let output = vm.call(&Item::of(function), args)?.complete()?;
+    T::from_value(output)
+}
+
+/// Compile the given source with the specified context and compiler options,
+/// and call its `main` function.
+///
+/// The virtual machine is configured through `configure` before it's called,
+/// see [run_unit].
+///
+/// # Examples
+///
+/// ```rust
+/// use rune_testing::*;
+/// use runestick::{Context, Gas};
+///
+/// # fn main() {
+/// let mut options = Options::default();
+/// options.const_propagation(true);
+///
+/// let gas = Gas::new(1000);
+///
+/// let value: i64 = run_with_options(
+///     Context::with_default_modules().unwrap(),
+///     &options,
+///     "fn main() { 1 + 2 }",
+///     |vm| vm.with_gas(gas.clone()),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(value, 3);
+/// # }
+/// ```
+pub fn run_with_options<T, F>(
+    context: runestick::Context,
+    options: &Options,
+    source: &str,
+    configure: F,
+) -> Result<T, VmError>
+where
+    T: runestick::FromValue,
+    F: FnOnce(Vm) -> Vm,
+{
+    let unit = compile_with_options(&context, options, source);
+    run_unit(Arc::new(context), Arc::new(unit), &["main"], (), configure)
+}
+
 /// Call the specified function in the given script.
 pub async fn run_async<N, A, T>(function: N, args: A, source: &str) -> Result<T>
 where
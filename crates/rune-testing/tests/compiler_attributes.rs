@@ -0,0 +1,82 @@
+use rune_testing::*;
+use runestick::Item;
+
+#[test]
+fn test_tests_are_registered() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        #[test]
+        fn first() {}
+
+        fn helper() {}
+
+        mod inner {
+            #[test]
+            fn second() {}
+        }
+        "#,
+    )
+    .unwrap();
+
+    let tests = unit.iter_tests().cloned().collect::<Vec<_>>();
+    assert_eq!(
+        tests,
+        vec![Item::of(&["first"]), Item::of(&["inner", "second"])]
+    );
+}
+
+#[test]
+fn test_benches_are_registered() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        #[bench]
+        fn first() {}
+
+        #[test]
+        fn second() {}
+        "#,
+    )
+    .unwrap();
+
+    let benches = unit.iter_benches().cloned().collect::<Vec<_>>();
+    assert_eq!(benches, vec![Item::of(&["first"])]);
+}
+
+#[test]
+fn test_unsupported_attribute() {
+    assert_compile_error! {
+        r#"#[foo] fn main() {}"#,
+        UnsupportedAttribute { span } => {
+            assert_eq!(span, Span::new(0, 6));
+        }
+    };
+}
+
+#[test]
+fn test_assert_eq() {
+    assert!(rune!(bool => r#"fn main() { std::test::assert_eq(1 + 2, 3); true }"#));
+
+    assert_vm_error!(
+        r#"fn main() { std::test::assert_eq(1 + 2, 4); }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed `left == right` (left: 3, right: 4)"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_black_box() {
+    assert_eq!(
+        rune!(i64 => r#"fn main() { std::test::black_box(1 + 2) }"#),
+        3
+    );
+}
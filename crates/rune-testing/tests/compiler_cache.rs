@@ -0,0 +1,160 @@
+use rune::{CompileCache, CompileError, LoadError, LoadErrorKind, Sources, Warnings};
+use rune_testing::*;
+use runestick::{FromValue as _, Item, Source};
+use std::sync::Arc;
+
+fn load(
+    context: &runestick::Context,
+    cache: &mut CompileCache,
+    inputs: &[&str],
+) -> Result<(Arc<runestick::Unit>, Warnings)> {
+    let mut sources = Sources::new();
+    let mut warnings = Warnings::new();
+
+    for (n, input) in inputs.iter().enumerate() {
+        sources.insert_default(Source::new(format!("source{}", n), input));
+    }
+
+    let unit = rune::load_sources_cached(
+        context,
+        &Options::default(),
+        &mut sources,
+        &mut warnings,
+        cache,
+    )?;
+
+    Ok((unit, warnings))
+}
+
+fn call_main(context: &Arc<runestick::Context>, unit: &Arc<runestick::Unit>) -> Result<i64> {
+    let vm = runestick::Vm::new(context.clone(), unit.clone());
+    let output = vm.call(&Item::of(&["main"]), ())?.complete()?;
+    Ok(i64::from_value(output)?)
+}
+
+#[test]
+fn test_compile_cache() -> Result<()> {
+    let context = Arc::new(runestick::Context::with_default_modules()?);
+    let mut cache = CompileCache::new();
+
+    let main = "fn main() { helper() + 1 }";
+
+    let (a, warnings) = load(&context, &mut cache, &[main, "fn helper() { 1; 41 }"])?;
+    assert_eq!(call_main(&context, &a)?, 42);
+    assert_eq!(warnings.len(), 1);
+
+    // Unchanged sources reuse the unit, and reproduce its warnings.
+    let (b, warnings) = load(&context, &mut cache, &[main, "fn helper() { 1; 41 }"])?;
+    assert!(Arc::ptr_eq(&a, &b));
+    assert_eq!(warnings.len(), 1);
+
+    // Changing a source which another one depends on causes a recompile.
+    let (c, warnings) = load(&context, &mut cache, &[main, "fn helper() { 9 }"])?;
+    assert!(!Arc::ptr_eq(&b, &c));
+    assert_eq!(call_main(&context, &c)?, 10);
+    assert!(warnings.is_empty());
+
+    // Changing the options also causes a recompile.
+    let mut sources = Sources::new();
+    let mut warnings = Warnings::new();
+    sources.insert_default(Source::new("source0", main));
+    sources.insert_default(Source::new("source1", "fn helper() { 9 }"));
+
+    let mut options = Options::default();
+    options.debug_info(false);
+
+    let d =
+        rune::load_sources_cached(&*context, &options, &mut sources, &mut warnings, &mut cache)?;
+    assert!(!Arc::ptr_eq(&c, &d));
+    Ok(())
+}
+
+#[test]
+fn test_compile_cache_reuses_functions() -> Result<()> {
+    let context = Arc::new(runestick::Context::with_default_modules()?);
+    let mut cache = CompileCache::new();
+
+    let main = "fn main() { let f = |a| a + helper(); f(1) }";
+
+    let (a, _) = load(&context, &mut cache, &[main, "fn helper() { 1 }"])?;
+    assert_eq!(call_main(&context, &a)?, 2);
+    assert_eq!(cache.reused_functions(), 0);
+
+    // NB: `main` and its closure only refer to `helper` by hash, so they are
+    // reused even though it changed.
+    let (b, _) = load(&context, &mut cache, &[main, "fn helper() { 2 }"])?;
+    assert_eq!(call_main(&context, &b)?, 3);
+    assert_eq!(cache.reused_functions(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_compile_cache_invalidates_dependents() -> Result<()> {
+    let context = Arc::new(runestick::Context::with_default_modules()?);
+    let mut cache = CompileCache::new();
+
+    let main = "fn main() { let p = Point { x: 1, y: 2 }; p.x + p.y }";
+
+    let (a, _) = load(&context, &mut cache, &[main, "struct Point { x, y }"])?;
+    assert_eq!(call_main(&context, &a)?, 3);
+
+    // Changing the declaration of the struct means that `main` has to be
+    // compiled again, which in turn fails since it's missing a field.
+    let error = load(&context, &mut cache, &[main, "struct Point { x, y, z }"]).unwrap_err();
+
+    match error.downcast_ref::<LoadError>().map(LoadError::kind) {
+        Some(LoadErrorKind::CompileError {
+            error: CompileError::LitObjectMissingField { field, .. },
+            ..
+        }) => assert_eq!(field, "z"),
+        _ => panic!("expected missing field, but got {:?}", error),
+    }
+
+    let (b, _) = load(&context, &mut cache, &[main, "struct Point { y, x }"])?;
+    assert_eq!(call_main(&context, &b)?, 3);
+    assert_eq!(cache.reused_functions(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_compile_cache_invalidates_imports() -> Result<()> {
+    let context = Arc::new(runestick::Context::with_default_modules()?);
+    let mut cache = CompileCache::new();
+
+    let main = "fn main() { value() }";
+    let modules = "mod a { fn value() { 1 } } mod b { fn value() { 2 } }";
+
+    let (a, _) = load(
+        &context,
+        &mut cache,
+        &[main, &format!("use a::value; {}", modules)],
+    )?;
+    assert_eq!(call_main(&context, &a)?, 1);
+
+    // NB: `a::value` still exists, but `main` no longer resolves to it.
+    let (b, _) = load(
+        &context,
+        &mut cache,
+        &[main, &format!("use b::value; {}", modules)],
+    )?;
+    assert_eq!(call_main(&context, &b)?, 2);
+    Ok(())
+}
+
+#[test]
+fn test_compile_cache_relocates_static_slots() -> Result<()> {
+    let context = Arc::new(runestick::Context::with_default_modules()?);
+    let mut cache = CompileCache::new();
+
+    let main = r#"fn main() { let o = #{"b": 2}; o.b + "b".len() }"#;
+
+    load(&context, &mut cache, &[r#"fn first() { "a" }"#, main])?;
+
+    // The static slots used by `main` are taken by other values in the new
+    // unit, so they have to be moved when it's reused.
+    let source = r#"fn first() { let o = #{"x": 1}; "x"; "y"; "a" }"#;
+    let (unit, _) = load(&context, &mut cache, &[source, main])?;
+    assert_eq!(cache.reused_functions(), 1);
+    assert_eq!(call_main(&context, &unit)?, 3);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use rune_testing::*;
+use runestick::Context;
+use std::sync::Arc;
+
+fn assert_propagated(source: &str, expected: i64) -> (usize, usize) {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+    let mut options = Options::default();
+
+    options.const_propagation(false);
+    let before = Arc::new(compile_with_options(&context, &options, source));
+    options.const_propagation(true);
+    let after = Arc::new(compile_with_options(&context, &options, source));
+
+    let before_output: i64 =
+        run_unit(context.clone(), before.clone(), &["main"], (), |vm| vm).unwrap();
+    assert_eq!(before_output, expected);
+
+    let after_output: i64 = run_unit(context, after.clone(), &["main"], (), |vm| vm).unwrap();
+    assert_eq!(after_output, expected);
+
+    (
+        before.iter_instructions().count(),
+        after.iter_instructions().count(),
+    )
+}
+
+#[test]
+fn test_const_propagation_arithmetic() {
+    let (before, after) = assert_propagated(
+        r#"
+        fn main() {
+            let a = 5;
+            let b = a * 2;
+            b + (a - 1)
+        }
+        "#,
+        14,
+    );
+
+    assert_eq!(before, 11);
+    assert_eq!(after, 5);
+}
+
+#[test]
+fn test_const_propagation_reassigned() {
+    let (before, after) = assert_propagated(
+        r#"
+        fn main() {
+            let a = 5;
+            let n = 0;
+
+            while n < 3 {
+                n = n + 1;
+                a += 1;
+            }
+
+            a + 1
+        }
+        "#,
+        9,
+    );
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_const_propagation_shadowed() {
+    let (before, after) = assert_propagated(
+        r#"
+        fn main() {
+            let a = 1;
+            let a = { a = 2; a + 1 };
+            a * 10
+        }
+        "#,
+        30,
+    );
+
+    assert!(after <= before);
+}
+
+#[test]
+fn test_const_propagation_overflow() {
+    let context = Context::with_default_modules().unwrap();
+    let mut options = Options::default();
+    options.const_propagation(true);
+
+    let (unit, _) = compile_source_with_options(
+        &context,
+        &options,
+        r#"
+        fn main() {
+            let a = 9223372036854775807;
+            a + 1
+        }
+        "#,
+    )
+    .unwrap();
+
+    let error = run_unit::<_, _, i64, _>(Arc::new(context), Arc::new(unit), &["main"], (), |vm| vm)
+        .unwrap_err();
+
+    let (kind, _) = error.kind().as_unwound_ref();
+    assert!(matches!(kind, Overflow));
+}
@@ -0,0 +1,81 @@
+use rune_testing::*;
+use runestick::{Context, Hash};
+use std::sync::Arc;
+
+const SOURCE: &str = r#"
+fn add(a, b) { a + b }
+fn double(n) { add(n, n) }
+fn unused() { double(1) }
+fn also_unused() { unused() }
+
+struct Counter { count }
+
+impl Counter {
+    fn get(self) { self.count }
+}
+
+#[export]
+fn exported() { 42 }
+
+mod helpers {
+    #[export]
+    fn triple(n) { n * 3 }
+
+    fn unused() { 0 }
+}
+
+fn main() {
+    double(21)
+}
+"#;
+
+fn options(dead_code_elimination: bool) -> Options {
+    let mut options = Options::default();
+    options.dead_code_elimination(dead_code_elimination);
+    options
+}
+
+#[test]
+fn test_dead_code_elimination() {
+    let context = Context::with_default_modules().unwrap();
+    let before = compile_with_options(&context, &options(false), SOURCE);
+    let after = compile_with_options(&context, &options(true), SOURCE);
+
+    // `unused` and `also_unused` are pruned. Note that `Counter::get` counts
+    // twice since it's registered both by name and as an instance function,
+    // and that `helpers::unused` isn't built in either case since it's not
+    // toplevel.
+    assert_eq!(before.iter_functions().count(), 9);
+    assert_eq!(after.iter_functions().count(), 7);
+
+    assert!(before.lookup(Hash::type_hash(["unused"])).is_some());
+    assert!(after.lookup(Hash::type_hash(["unused"])).is_none());
+    assert!(after.lookup(Hash::type_hash(["also_unused"])).is_none());
+    assert!(after
+        .lookup(Hash::type_hash(["helpers", "unused"]))
+        .is_none());
+
+    assert_eq!(
+        run_unit::<_, _, i64, _>(Arc::new(context), Arc::new(after), &["main"], (), |vm| vm)
+            .unwrap(),
+        42
+    );
+}
+
+#[test]
+fn test_exported_functions_are_roots() {
+    let context = Context::with_default_modules().unwrap();
+    let unit = compile_with_options(&context, &options(true), SOURCE);
+    assert!(unit.lookup(Hash::type_hash(["exported"])).is_some());
+    assert_eq!(
+        run_unit::<_, _, i64, _>(
+            Arc::new(context),
+            Arc::new(unit),
+            &["helpers", "triple"],
+            (3,),
+            |vm| vm
+        )
+        .unwrap(),
+        9
+    );
+}
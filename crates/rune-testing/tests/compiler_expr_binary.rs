@@ -15,3 +15,17 @@ fn test_binary_exprs() {
     assert_parse!(r#"fn main() { 0 < 10 && 10 > 0 }"#);
     assert_parse!(r#"fn main() { 0 < 10 && 10 > 0 || true }"#);
 }
+
+#[test]
+fn test_in_expr() {
+    assert_parse!(r#"fn main() { 1 in [1, 2] }"#);
+    assert_parse!(r#"fn main() { 1 + 1 in [1, 2] && "a" in "abc" }"#);
+    assert_parse!(r#"fn main() { for x in [1, 2] { x in [1] } }"#);
+
+    assert_parse_error! {
+        r#"fn main() { 1 in [1] == true }"#,
+        PrecedenceGroupRequired { span } => {
+            assert_eq!(span, Span::new(12, 20));
+        }
+    };
+}
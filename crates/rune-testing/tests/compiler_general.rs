@@ -90,3 +90,62 @@ fn test_bad_struct_declaration() {
         }
     };
 }
+
+#[test]
+fn test_if_without_else_value() {
+    assert_compile_error! {
+        r#"fn main() { let n = if true { 1 }; n }"#,
+        IfWithoutElse { span } => {
+            assert_eq!(span, Span::new(20, 33));
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn main() { let n = if true { } else if false { 2 }; n }"#,
+        IfWithoutElse { span } => {
+            assert_eq!(span, Span::new(20, 51));
+        }
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { let n = if false { 1 } else { 2 }; n }"#),
+        2,
+    };
+
+    assert_eq! {
+        rune!(() => r#"fn main() { let n = if true { 1; }; n }"#),
+        (),
+    };
+
+    assert_compile_error! {
+        r#"fn main() { let n = 0; n = if true { 1 }; n }"#,
+        IfWithoutElse { span } => {
+            assert_eq!(span, Span::new(27, 40));
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn foo(n) { n } fn main() { foo(if true { 1 }) }"#,
+        IfWithoutElse { span } => {
+            assert_eq!(span, Span::new(32, 45));
+        }
+    };
+}
+
+#[test]
+fn test_if_without_else_tail() {
+    assert_eq! {
+        rune!(() => r#"fn main() { let x = 1; if x > 0 { println("hi") } }"#),
+        (),
+    };
+
+    assert_eq! {
+        rune!(() => r#"fn main() { let x = 0; if x > 0 { x } }"#),
+        (),
+    };
+
+    assert_eq! {
+        rune!(() => r#"fn main() { let x = 1; if x > 0 { x }; }"#),
+        (),
+    };
+}
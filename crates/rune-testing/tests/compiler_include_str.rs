@@ -0,0 +1,91 @@
+use rune::{LoadError, LoadErrorKind, Options, Sources, Warnings};
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Source, Vm};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn load_error_kind(source: Source) -> LoadErrorKind {
+    let context = Context::with_default_modules().unwrap();
+    let mut sources = Sources::new();
+    sources.insert_default(source);
+
+    let error: LoadError = rune::load_sources(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut Warnings::new(),
+    )
+    .unwrap_err();
+
+    error.into_kind()
+}
+
+#[test]
+fn test_include_str() {
+    let path = fixture("include_str.rn");
+
+    let context = Context::with_default_modules().unwrap();
+    let mut sources = Sources::new();
+
+    let unit = rune::load_path(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &path,
+        &mut Warnings::new(),
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap();
+
+    assert_eq!(
+        String::from_value(output).unwrap(),
+        "Hello, {name}!\nSecond line.\n"
+    );
+}
+
+#[test]
+fn test_include_str_missing_file() {
+    let source = Source::from_path(&fixture("include_str_missing.rn")).unwrap();
+    let kind = load_error_kind(source.clone());
+    let source = source.as_str();
+
+    match kind {
+        LoadErrorKind::CompileError {
+            error: IncludeFileError { span, .. },
+            ..
+        } => {
+            assert_eq!(&source[span.start..span.end], "\"missing.txt\"");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_include_str_in_memory_source() {
+    let source = r#"fn main() { include_str!("data.txt") }"#;
+
+    let kind = load_error_kind(Source::new("main", source));
+
+    match kind {
+        LoadErrorKind::CompileError {
+            error: UnsupportedFileInclude { span },
+            ..
+        } => {
+            assert_eq!(&source[span.start..span.end], "include_str!(\"data.txt\")");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,135 @@
+use rune::ast::{self, Token};
+use rune::{IntoTokens, Lexer, MacroContext, Parse, Parser, TokenStream};
+use runestick::Span;
+
+/// Lex the given source into a vector of tokens.
+fn lex(source: &str) -> Vec<Token> {
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+
+    while let Some(token) = lexer.next().unwrap() {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+/// Convert the given node into tokens.
+fn tokens_of<T>(node: &T) -> Vec<Token>
+where
+    T: IntoTokens,
+{
+    let mut context = MacroContext::empty();
+    let mut stream = TokenStream::new(Vec::new(), Span::empty());
+    node.into_tokens(&mut context, &mut stream);
+    stream.into_iter().collect()
+}
+
+/// Assert that parsing the given source as `T`, converting it back into
+/// tokens, and parsing those tokens again produces the same tokens as the
+/// source itself.
+fn assert_round_trip<T>(source: &str)
+where
+    T: Parse + IntoTokens,
+{
+    let expected = lex(source);
+
+    let mut parser = Parser::new(source);
+    let node = parser.parse::<T>().unwrap();
+    parser.parse_eof().unwrap();
+
+    let tokens = tokens_of(&node);
+    assert_eq!(
+        tokens, expected,
+        "tokens of `{}` differ from source",
+        source
+    );
+
+    let stream = TokenStream::new(tokens, Span::point(source.len()));
+    let mut parser = Parser::from_token_stream(&stream);
+    let node = parser.parse::<T>().unwrap();
+    parser.parse_eof().unwrap();
+
+    assert_eq!(
+        tokens_of(&node),
+        expected,
+        "tokens of reparsed `{}` differ from source",
+        source
+    );
+}
+
+#[test]
+fn test_round_trip_exprs() {
+    assert_round_trip::<ast::Path>("foo::bar::baz");
+    assert_round_trip::<ast::ExprReturn>("return 42");
+    assert_round_trip::<ast::ExprReturn>("return");
+    assert_round_trip::<ast::LitByteStr>("b\"hello\"");
+    assert_round_trip::<ast::LitVec>("[1, 2, 3,]");
+    assert_round_trip::<ast::LitVec>("[]");
+    assert_round_trip::<ast::LitTuple>("(1, \"two\", 'c',)");
+    assert_round_trip::<ast::LitObject>("#{a: 1, \"b\": 2, c}");
+    assert_round_trip::<ast::LitObject>("Foo {a: 1, b}");
+    assert_round_trip::<ast::LitTemplate>("`hello {name}`");
+    assert_round_trip::<ast::Expr>("a.b.c(1, 2)[3]?.await");
+    assert_round_trip::<ast::Expr>("!a + b * (c - d) / e % f");
+    assert_round_trip::<ast::Expr>("a is Foo && b is not Bar || c");
+    assert_round_trip::<ast::Expr>("a += 1");
+    assert_round_trip::<ast::Expr>("|a, b| a + b");
+    assert_round_trip::<ast::Expr>("async || { 42 }");
+    assert_round_trip::<ast::Expr>("async { a.await }");
+    assert_round_trip::<ast::Expr>("foo(..args, 1)");
+    assert_round_trip::<ast::Expr>("[1, ..rest]");
+    assert_round_trip::<ast::Expr>("a[1..2]");
+    assert_round_trip::<ast::Expr>("yield 1");
+    assert_round_trip::<ast::Expr>("foo!(a b c)");
+}
+
+#[test]
+fn test_round_trip_control_flow() {
+    assert_round_trip::<ast::Expr>("if a { 1 } else if b { 2 } else { 3 }");
+    assert_round_trip::<ast::Expr>("if let Some(a) = b { a }");
+    assert_round_trip::<ast::Expr>("while let Some(a) = b.next() { break }");
+    assert_round_trip::<ast::Expr>("'outer: loop { break 'outer; }");
+    assert_round_trip::<ast::Expr>("for a in b { a }");
+    assert_round_trip::<ast::Expr>("for await a in b { }");
+    assert_round_trip::<ast::Expr>(
+        "match a { Some(b) if b > 1 => b, [a, b, ..] => 1, #{a, b: 2} => 2, _ => 0, }",
+    );
+    assert_round_trip::<ast::Expr>("select { a = b => a, default => 1, }");
+    assert_round_trip::<ast::Expr>("{ let a = 1; let (b, c) = d; a }");
+}
+
+#[test]
+fn test_round_trip_items() {
+    assert_round_trip::<ast::File>(
+        r#"
+        use std::iter::range;
+        use foo::*;
+
+        mod bar {
+            fn baz() { 1 }
+
+            #[test]
+            fn test_baz() { baz() }
+        }
+
+        struct Empty;
+        struct Tuple(a, b);
+        struct Named { a, b }
+
+        enum Enum { A, B(a, b), C { a, b } }
+
+        impl Named {
+            fn new(a, b) { Named { a, b } }
+            async fn get(self) { self.a }
+        }
+
+        static FOO = 1 + 2;
+
+        async fn main() {
+            let v = [1, 2, 3];
+            v.iter().next()
+        }
+        "#,
+    );
+}
@@ -0,0 +1,68 @@
+use rune::{LinkerError, LoadErrorKind};
+use rune_testing::*;
+use runestick::{Context, Item};
+
+fn missing_functions(source: &str) -> Vec<(Option<Item>, Vec<Span>)> {
+    let context = Context::with_default_modules().unwrap();
+    let options = Options::default();
+    let error = compile_source_with_options(&context, &options, source).unwrap_err();
+    let error = error.downcast::<rune::LoadError>().unwrap();
+
+    match error.into_kind() {
+        LoadErrorKind::LinkError { errors } => errors
+            .errors()
+            .map(|error| match error {
+                LinkerError::MissingFunction { item, spans, .. } => {
+                    (item, spans.into_iter().map(|(span, _)| span).collect())
+                }
+            })
+            .collect(),
+        kind => panic!("expected link error but was `{:?}`", kind),
+    }
+}
+
+#[test]
+fn test_all_missing_functions_are_reported() {
+    let missing = missing_functions(
+        r#"
+        fn main() {
+            foo(1);
+            bar::baz();
+            foo(2);
+        }
+        "#,
+    );
+
+    assert_eq!(
+        missing,
+        vec![
+            (
+                Some(Item::of(&["foo"])),
+                vec![Span::new(33, 39), Span::new(77, 83)]
+            ),
+            (Some(Item::of(&["bar", "baz"])), vec![Span::new(53, 63)]),
+        ]
+    );
+}
+
+#[test]
+fn test_missing_function_without_link_checks() {
+    let context = Context::with_default_modules().unwrap();
+    let mut options = Options::default();
+    options.link_checks(false);
+
+    let error =
+        compile_source_with_options(&context, &options, "fn main() { foo(); bar(); }").unwrap_err();
+    let error = error.downcast::<rune::LoadError>().unwrap();
+
+    match error.into_kind() {
+        LoadErrorKind::CompileError {
+            error: rune::CompileError::MissingFunction { item, span },
+            ..
+        } => {
+            assert_eq!(item, Item::of(&["foo"]));
+            assert_eq!(span, Span::new(12, 17));
+        }
+        kind => panic!("expected compile error but was `{:?}`", kind),
+    }
+}
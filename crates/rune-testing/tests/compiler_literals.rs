@@ -39,3 +39,82 @@ fn test_number_literals() {
         }
     };
 }
+
+#[test]
+fn test_number_separators_and_suffixes() {
+    assert_parse!(r#"fn main() { 1_000_000 }"#);
+    assert_parse!(r#"fn main() { 0xff_ff }"#);
+    assert_parse!(r#"fn main() { 1_000.000_1 }"#);
+    assert_parse!(r#"fn main() { 5i64 }"#);
+    assert_parse!(r#"fn main() { -5i64 }"#);
+    assert_parse!(r#"fn main() { 5_i64 }"#);
+    assert_parse!(r#"fn main() { 0xffi64 }"#);
+    assert_parse!(r#"fn main() { 0b101i64 }"#);
+    assert_parse!(r#"fn main() { 5f64 }"#);
+    assert_parse!(r#"fn main() { 1.0f64 }"#);
+    assert_parse!(r#"fn main() { 1.0e10f64 }"#);
+    assert_parse!(r#"fn main() { 5i64.max(3) }"#);
+
+    assert_compile_error! {
+        r#"fn main() { 5u32 }"#,
+        ParseError { error: BadNumberSuffix { span, .. }} => {
+            assert_eq!(span, Span::new(12, 16));
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn main() { 1.0i64 }"#,
+        ParseError { error: BadNumberSuffix { span, .. }} => {
+            assert_eq!(span, Span::new(12, 18));
+        }
+    };
+
+    assert_compile_error! {
+        r#"fn main() { 0b1f64 }"#,
+        ParseError { error: BadNumberSuffix { span, .. }} => {
+            assert_eq!(span, Span::new(12, 18));
+        }
+    };
+}
+
+#[test]
+fn test_static_string_deduplication() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let o = #{a: "hello"};
+            o.a = "hello";
+            o.a = "world";
+            let b = "hello";
+            o.a
+        }
+        "#,
+    )?;
+
+    let mut strings = unit
+        .iter_static_strings()
+        .map(|s| s.as_ref().as_ref().to_owned())
+        .collect::<Vec<String>>();
+
+    strings.sort();
+    assert_eq!(strings, vec!["a", "hello", "world"]);
+    Ok(())
+}
+
+#[test]
+fn test_literal_chaining() {
+    assert_parse!(r#"fn main() { "abc".len() }"#);
+    assert_parse!(r#"fn main() { b"abc".len() }"#);
+    assert_parse!(r#"fn main() { `abc {1}`.len() }"#);
+    assert_parse!(r#"fn main() { 'a'.is_alphabetic() }"#);
+    assert_parse!(r#"fn main() { 42.max(3) }"#);
+    assert_parse!(r#"fn main() { 1.5.max(2.0) }"#);
+    assert_parse!(r#"fn main() { [1, 2].len() }"#);
+    assert_parse!(r#"fn main() { (1, 2).0 }"#);
+    assert_parse!(r#"fn main() { ((1, 2), 3).0.1 }"#);
+    assert_parse!(r#"fn main() { #{a: 1}.a }"#);
+    assert_parse!(r#"fn main() { if "abc".len() == 3 { [1].len() } else { 0 } }"#);
+}
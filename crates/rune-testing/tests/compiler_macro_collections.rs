@@ -0,0 +1,167 @@
+use rune_testing::*;
+use runestick::Context;
+use std::collections::HashMap;
+
+fn context() -> Context {
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&rune_macros::module().unwrap()).unwrap();
+    context
+}
+
+fn options() -> Options {
+    let mut options = Options::default();
+    options.macros(true);
+    options
+}
+
+#[test]
+fn test_vec_macro() {
+    let output: Vec<i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+        fn main() { vec![1, 2, 3,] }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, vec![1, 2, 3]);
+
+    let output: Vec<i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+        fn main() { vec![] }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_vec_macro_repeat() {
+    let output: Vec<i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+        fn main() { let n = 2; vec![7; n + 1] }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, vec![7, 7, 7]);
+
+    let output: Vec<i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+        fn main() { vec![7; 0] }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_vec_macro_repeat_aliases_value() {
+    let output: (usize, Vec<i64>) = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+        fn main() { let v = vec![[]; 3]; v[0].push(1); (v.len(), v[2]) }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, (3, vec![1]));
+}
+
+#[test]
+fn test_vec_macro_repeat_evaluates_value_once() {
+    let output: (Vec<i64>, i64) = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::vec;
+
+        fn main() {
+            let calls = [];
+            let v = vec![{ calls.push(1); 42 }; 3];
+            (v, calls.len())
+        }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, (vec![42, 42, 42], 1));
+}
+
+#[test]
+fn test_object_macro() {
+    let output: HashMap<String, i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::object;
+
+        fn main() {
+            let c = 3;
+            object!{a: 1, "b": 1 + 1, c}
+        }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    let mut expected = HashMap::new();
+    expected.insert(String::from("a"), 1);
+    expected.insert(String::from("b"), 2);
+    expected.insert(String::from("c"), 3);
+    assert_eq!(output, expected);
+
+    let output: HashMap<String, i64> = run_with_options(
+        context(),
+        &options(),
+        r#"
+        use std::experiments::object;
+        fn main() { object!{} }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_collection_macro_errors() {
+    let context = context();
+    let options = options();
+
+    let source = r#"
+    use std::experiments::vec;
+    fn main() { vec![1; 2; 3] }
+    "#;
+
+    assert!(compile_source_with_options(&context, &options, source).is_err());
+
+    let source = r#"
+    use std::experiments::object;
+    fn main() { object!{1: 2} }
+    "#;
+
+    assert!(compile_source_with_options(&context, &options, source).is_err());
+}
@@ -0,0 +1,92 @@
+use rune::{MacroContext, Options, Parser, TokenStream};
+use rune_testing::*;
+use runestick::{Context, Module};
+
+/// Expands `plus_one!(<expr>)` into a block which binds a temporary named
+/// `value` before evaluating the expression.
+fn plus_one(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let expr = parser.parse::<rune::ast::Expr>()?;
+    parser.parse_eof()?;
+    Ok(rune::quote!(ctx => { let value = 1; #expr + value }))
+}
+
+/// Expands `call_helper!()` into a call to the `helper` function.
+fn call_helper(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    parser.parse_eof()?;
+    Ok(rune::quote!(ctx => helper()))
+}
+
+fn context() -> Context {
+    let mut module = Module::new(&["test"]);
+    module.macro_(&["plus_one"], plus_one).unwrap();
+    module.macro_(&["call_helper"], call_helper).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    context
+}
+
+fn options() -> Options {
+    let mut options = Options::default();
+    options.macros(true);
+    options
+}
+
+#[test]
+fn test_macro_temporary_does_not_shadow_user_variable() {
+    let output = run_with_options::<i64, _>(
+        context(),
+        &options(),
+        r#"
+        fn main() {
+            let value = 10;
+            test::plus_one!(value)
+        }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, 11);
+}
+
+#[test]
+fn test_macro_expansions_are_isolated() {
+    let output = run_with_options::<i64, _>(
+        context(),
+        &options(),
+        r#"
+        fn main() {
+            let value = 100;
+            let a = test::plus_one!(test::plus_one!(value));
+            a + value
+        }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, 202);
+}
+
+#[test]
+fn test_macro_identifiers_resolve_items() {
+    let output = run_with_options::<i64, _>(
+        context(),
+        &options(),
+        r#"
+        fn helper() { 42 }
+
+        fn main() {
+            let helper = 1;
+            test::call_helper!()
+        }
+        "#,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(output, 42);
+}
@@ -0,0 +1,55 @@
+use rune::{MacroContext, Options, Parser, Spanned as _, TokenStream};
+use rune_testing::*;
+use runestick::{Context, Module};
+
+/// Expands `at_call_site!(<expr>)` into an expression which uses an unknown
+/// variable, with all synthesized tokens spanning the macro call.
+fn at_call_site(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let expr = parser.parse::<rune::ast::Expr>()?;
+    parser.parse_eof()?;
+    Ok(rune::quote!(ctx => #expr + missing))
+}
+
+/// Expands `at_input!(<expr>)` into an expression which uses an unknown
+/// variable, with all synthesized tokens spanning the input expression.
+fn at_input(ctx: &mut MacroContext, stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let expr = parser.parse::<rune::ast::Expr>()?;
+    parser.parse_eof()?;
+    Ok(ctx.with_span(expr.span(), |ctx| rune::quote!(ctx => #expr + missing)))
+}
+
+fn compile_error_span(source: &str) -> Span {
+    let mut module = Module::new(&["test"]);
+    module.macro_(&["at_call_site"], at_call_site).unwrap();
+    module.macro_(&["at_input"], at_input).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let mut options = Options::default();
+    options.macros(true);
+
+    let error = compile_source_with_options(&context, &options, source).unwrap_err();
+    let error = error.downcast::<rune::LoadError>().unwrap();
+
+    match error.into_kind() {
+        rune::LoadErrorKind::CompileError { error, .. } => error.span(),
+        kind => panic!("expected compile error, but got: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_synthesized_tokens_span_call_site() {
+    let source = "fn main() { test::at_call_site!(1 + 2) }";
+    let span = compile_error_span(source);
+    assert_eq!(&source[span.start..span.end], "test::at_call_site!(1 + 2)");
+}
+
+#[test]
+fn test_synthesized_tokens_span_input() {
+    let source = "fn main() { test::at_input!(1 + 2) }";
+    let span = compile_error_span(source);
+    assert_eq!(&source[span.start..span.end], "1 + 2");
+}
@@ -0,0 +1,40 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+fn stringify(input: &str) -> Result<String> {
+    let mut context = Context::with_default_modules()?;
+    context.install(&rune_macros::module()?)?;
+
+    let mut options = Options::default();
+    options.macros(true);
+
+    let source = format!(
+        "use std::experiments::stringify; fn main() {{ stringify!({}) }}",
+        input
+    );
+
+    let (unit, _) = compile_source_with_options(&context, &options, &source)?;
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm.call(&Item::of(&["main"]), ())?.complete()?;
+    Ok(String::from_value(output)?)
+}
+
+#[test]
+fn test_stringify() {
+    assert_eq!(stringify("1 + 2").unwrap(), "1 + 2");
+    assert_eq!(stringify("1+2").unwrap(), "1 + 2");
+    assert_eq!(
+        stringify("foo.bar(1, \"two\")?").unwrap(),
+        "foo.bar(1, \"two\")?"
+    );
+    assert_eq!(
+        stringify("std::iter::range(0, 10)").unwrap(),
+        "std::iter::range(0, 10)"
+    );
+    assert_eq!(
+        stringify("#{a: [1, 2], b: 'c'}").unwrap(),
+        "#{a: [1, 2], b: 'c'}"
+    );
+    assert_eq!(stringify("").unwrap(), "");
+}
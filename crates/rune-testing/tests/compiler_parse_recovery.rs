@@ -0,0 +1,88 @@
+use rune::ParseError;
+use runestick::Span;
+
+#[test]
+fn test_recover_multiple_errors() {
+    let source = r#"
+    fn a() { let x = ; }
+    fn b() { 1 }
+    fn c() { 1 + }
+    fn d() { 2 }
+    "#;
+
+    let (file, errors) = rune::parse_all_with_recovery(source);
+
+    assert_eq!(file.items.len(), 2);
+    assert_eq!(errors.len(), 2);
+
+    match errors[0] {
+        ParseError::ExpectedExpr { span, .. } => {
+            assert_eq!(&source[span.start..span.end], ";");
+        }
+        ref error => panic!("unexpected error: {:?}", error),
+    }
+
+    match errors[1] {
+        ParseError::ExpectedExpr { span, .. } => {
+            assert_eq!(&source[span.start..span.end], "}");
+            assert!(span.start > source.find("fn c").unwrap());
+        }
+        ref error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn test_recover_missing_close_brace() {
+    let source = r#"
+    fn a() { let x = 1 + ;
+    fn b() { 1 }
+    "#;
+
+    let (file, errors) = rune::parse_all_with_recovery(source);
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], ParseError::ExpectedExpr { .. }));
+    assert_eq!(file.items.len(), 1);
+}
+
+#[test]
+fn test_recover_top_level_garbage() {
+    let source = r#"
+    let x = 1;
+    fn a() { 1 }
+    "#;
+
+    let (file, errors) = rune::parse_all_with_recovery(source);
+
+    assert_eq!(file.items.len(), 1);
+    assert_eq!(errors.len(), 1);
+
+    match errors[0] {
+        ParseError::ExpectedEof { span, .. } => {
+            assert_eq!(&source[span.start..span.end], "let");
+        }
+        ref error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn test_recover_without_errors() {
+    let (file, errors) = rune::parse_all_with_recovery("fn a() { 1 } fn b() { 2 }");
+    assert!(errors.is_empty());
+    assert_eq!(file.items.len(), 2);
+
+    let (file, errors) = rune::parse_all_with_recovery("");
+    assert!(errors.is_empty());
+    assert!(file.items.is_empty());
+}
+
+#[test]
+fn test_recover_lexer_error() {
+    let source = "fn a() { 1 § 2 } fn b() { 2 }";
+    let (file, errors) = rune::parse_all_with_recovery(source);
+
+    assert!(!errors.is_empty());
+    assert!(matches!(errors[0], ParseError::UnexpectedChar { .. }));
+    assert_eq!(errors[0].span(), Span::new(11, 13));
+    assert_eq!(file.items.len(), 1);
+}
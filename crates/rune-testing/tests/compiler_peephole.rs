@@ -0,0 +1,59 @@
+use rune_testing::*;
+use runestick::Context;
+use std::sync::Arc;
+
+fn assert_optimized(source: &str, expected: i64) -> (usize, usize) {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+    let mut options = Options::default();
+
+    options.peephole(false);
+    let before = Arc::new(compile_with_options(&context, &options, source));
+    options.peephole(true);
+    let after = Arc::new(compile_with_options(&context, &options, source));
+
+    let counts = (
+        before.iter_instructions().count(),
+        after.iter_instructions().count(),
+    );
+
+    for unit in [before, after] {
+        let output: i64 = run_unit(context.clone(), unit, &["main"], (), |vm| vm).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    counts
+}
+
+#[test]
+fn test_peephole_while() {
+    let (before, after) = assert_optimized(
+        r#"
+        fn main() {
+            let n = 0;
+            while n < 10 { n = n + 1; if n > 5 { break; } }
+            n
+        }
+        "#,
+        6,
+    );
+
+    assert_eq!(before, 20);
+    assert_eq!(after, 18);
+}
+
+#[test]
+fn test_peephole_if_without_value() {
+    let (before, after) = assert_optimized(
+        r#"
+        fn main() {
+            let n = 1;
+            if n > 0 { n } else { 2 };
+            n
+        }
+        "#,
+        1,
+    );
+
+    assert_eq!(before, 9);
+    assert_eq!(after, 8);
+}
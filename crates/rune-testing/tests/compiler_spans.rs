@@ -0,0 +1,57 @@
+use rune::{ast, parse_all, Spanned};
+use runestick::Span;
+
+/// Get the source covered by the span of any node.
+fn source_of<'a, T>(source: &'a str, node: &T) -> &'a str
+where
+    T: Spanned,
+{
+    let span = node.span();
+    &source[span.start..span.end]
+}
+
+#[test]
+fn test_path_span() {
+    let source = "foo::bar::baz";
+    let path = parse_all::<ast::Path>(source).unwrap();
+    assert_eq!(source_of(source, &path), source);
+    assert_eq!(path.first.span(), Span::new(0, 3));
+}
+
+#[test]
+fn test_expr_return_span() {
+    let source = "return 1 + 2";
+    let expr = parse_all::<ast::ExprReturn>(source).unwrap();
+    assert_eq!(source_of(source, &expr), source);
+
+    let source = "return";
+    let expr = parse_all::<ast::ExprReturn>(source).unwrap();
+    assert_eq!(source_of(source, &expr), source);
+}
+
+#[test]
+fn test_lit_vec_span() {
+    let source = "[1, [2, 3], 4]";
+    let vec = parse_all::<ast::LitVec>(source).unwrap();
+    assert_eq!(source_of(source, &vec), source);
+    assert_eq!(source_of(source, &vec.items[1].0), "[2, 3]");
+}
+
+#[test]
+fn test_boxed_and_nested_spans() {
+    let source = "fn foo(a, b) { a + b * 2 }";
+    let item = parse_all::<ast::ItemFn>(source).unwrap();
+    assert_eq!(source_of(source, &item), source);
+    assert_eq!(source_of(source, &item.body), "{ a + b * 2 }");
+
+    let source = "a + b * 2";
+    let expr = parse_all::<ast::Expr>(source).unwrap();
+
+    match &expr {
+        ast::Expr::ExprBinary(binary) => {
+            assert_eq!(source_of(source, &binary.lhs), "a");
+            assert_eq!(source_of(source, &binary.rhs), "b * 2");
+        }
+        expr => panic!("unexpected expression: {:?}", expr),
+    }
+}
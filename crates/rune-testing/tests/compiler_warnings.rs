@@ -29,3 +29,30 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_inspect_warnings() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+    let (_, warnings) = compile_source(&context, r#"fn main() { `Hello World`; None() }"#)?;
+
+    assert_eq!(warnings.len(), 2);
+
+    let warnings = warnings
+        .iter()
+        .map(|w| (w.source_id(), w.span(), w.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        warnings,
+        vec![
+            (0, Span::new(12, 25), String::from("not used")),
+            (
+                0,
+                Span::new(27, 33),
+                String::from("call paramters are not needed here")
+            ),
+        ]
+    );
+
+    Ok(())
+}
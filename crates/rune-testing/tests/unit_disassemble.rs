@@ -0,0 +1,150 @@
+use rune_testing::*;
+
+#[test]
+fn test_disassemble() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+    let (unit, _) = compile_source(&context, r#"fn main(a) { a + 1 }"#)?;
+
+    let insts = unit.disassemble();
+    assert_eq!(insts.len(), unit.iter_instructions().count());
+
+    let first = &insts[0];
+    assert_eq!(first.index, 0);
+    assert_eq!(first.opcode, "copy");
+    assert_eq!(first.operands, "0");
+    assert_eq!(first.comment, Some("var `a`"));
+    assert!(first.span.is_some());
+
+    let (_, signature) = first
+        .function
+        .expect("expected function at first instruction");
+    assert_eq!(signature.to_string(), "main(a)");
+
+    let add = &insts[2];
+    assert_eq!(add.opcode, "add");
+    assert_eq!(add.operands, "");
+    assert!(add.function.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_symbols() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        struct Foo;
+
+        impl Foo {
+            fn bar(self, a) { a }
+        }
+
+        fn add(a, b) { a + b }
+
+        fn main() { add(1, 2) }
+        "#,
+    )?;
+
+    let symbols = unit
+        .symbols()
+        .into_iter()
+        .map(|s| (s.path.to_string(), s.args, s.source_id, s.span))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        symbols,
+        vec![
+            (String::from("Foo::bar"), 2, 0, Span::new(53, 74)),
+            (String::from("add"), 2, 0, Span::new(94, 116)),
+            (String::from("main"), 0, 0, Span::new(126, 149)),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_hover() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+    let source = r#"fn add(a, b) { a + b } fn main() { add(1, 2) }"#;
+    let (unit, _) = compile_source(&context, source)?;
+
+    let hover = unit
+        .hover(0, source.find("add(1").unwrap())
+        .expect("expected hover information");
+
+    let function = hover.function.expect("expected enclosing function");
+    assert_eq!(function.path.to_string(), "main");
+    assert_eq!(hover.span, Some(Span::new(35, 44)));
+    assert!(matches!(
+        hover.inst,
+        Some(runestick::Inst::Call { args: 2, .. })
+    ));
+
+    let signature = hover.signature.expect("expected call signature");
+    assert_eq!(signature.to_string(), "add(a, b)");
+
+    let hover = unit
+        .hover(0, source.find("b }").unwrap())
+        .expect("expected hover information");
+
+    let function = hover.function.expect("expected enclosing function");
+    assert_eq!(function.path.to_string(), "add");
+    assert!(hover.signature.is_none());
+
+    assert!(unit.hover(1, 0).unwrap().function.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_diff() -> Result<()> {
+    use runestick::{FunctionDiffKind, InstDiff};
+
+    let context = runestick::Context::with_default_modules()?;
+
+    let (old, _) = compile_source(
+        &context,
+        r#"
+        fn add(a, b) { a + b }
+        fn unused() { 1 }
+        fn main() { add(1, 2) }
+        "#,
+    )?;
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        fn add(a, b) { a - b }
+        fn sub(a, b) { a - b }
+        fn main() { add(1, 2) }
+        "#,
+    )?;
+
+    let diffs = old
+        .diff(&new)
+        .into_iter()
+        .map(|d| (d.signature.unwrap().path.to_string(), d.kind, d.insts))
+        .collect::<Vec<_>>();
+
+    assert_eq!(diffs.len(), 3);
+
+    let (name, kind, insts) = &diffs[0];
+    assert_eq!(name, "add");
+    assert_eq!(*kind, FunctionDiffKind::Changed);
+    assert!(matches!(insts[2], InstDiff::Removed(runestick::Inst::Add)));
+    assert!(matches!(insts[3], InstDiff::Added(runestick::Inst::Sub)));
+    assert!(matches!(insts[4], InstDiff::Same(..)));
+
+    let (name, kind, insts) = &diffs[1];
+    assert_eq!(name, "unused");
+    assert_eq!(*kind, FunctionDiffKind::Removed);
+    assert!(insts.iter().all(|i| matches!(i, InstDiff::Removed(..))));
+
+    let (name, kind, insts) = &diffs[2];
+    assert_eq!(name, "sub");
+    assert_eq!(*kind, FunctionDiffKind::Added);
+    assert!(insts.iter().all(|i| matches!(i, InstDiff::Added(..))));
+
+    assert!(old.diff(&old).is_empty());
+    Ok(())
+}
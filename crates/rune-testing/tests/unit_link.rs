@@ -0,0 +1,57 @@
+use rune_testing::*;
+use runestick::{Context, Hash, Item, Module};
+
+fn native_context() -> Result<Context> {
+    let mut module = Module::new(&["native"]);
+    module.function(&["first"], || 1i64)?;
+    module.function(&["second"], || 2i64)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    Ok(context)
+}
+
+#[test]
+fn test_link() -> Result<()> {
+    let context = native_context()?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            native::first() + native::second() + native::first() + helper()
+        }
+
+        fn helper() { 42 }
+        "#,
+    )?;
+
+    unit.link(&context)?;
+
+    let error = unit.link(&Context::with_default_modules()?).unwrap_err();
+
+    assert_eq!(
+        error.missing(),
+        &[
+            (
+                Hash::type_hash(["native", "first"]),
+                Some(Item::of(&["native", "first"]))
+            ),
+            (
+                Hash::type_hash(["native", "second"]),
+                Some(Item::of(&["native", "second"]))
+            ),
+        ]
+    );
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "missing functions: `native::first` ({}), `native::second` ({})",
+            Hash::type_hash(["native", "first"]),
+            Hash::type_hash(["native", "second"]),
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use rune_testing::*;
+use runestick::{Hash, Inst, Unit, UnitFn};
+
+fn unit(instructions: Vec<Inst>) -> Unit {
+    let main = UnitFn::Offset {
+        offset: 0,
+        call: runestick::Call::Immediate,
+        args: 0,
+    };
+
+    Unit::new(
+        instructions,
+        vec![(Hash::type_hash(&["main"]), main)]
+            .into_iter()
+            .collect(),
+        Default::default(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        None,
+    )
+}
+
+#[test]
+fn test_verify() -> Result<()> {
+    let context = runestick::Context::with_default_modules()?;
+
+    let (compiled, _) = compile_source(
+        &context,
+        r#"fn main() { let a = #{b: "c"}; if a.b == "c" { [1, 2] } else { [] } }"#,
+    )?;
+    compiled.verify(&context)?;
+
+    let error = unit(vec![Inst::Jump { offset: 10 }, Inst::ReturnUnit])
+        .verify(&context)
+        .unwrap_err();
+
+    match error.kind() {
+        BadInstruction { ip: 0, error, .. } => {
+            assert!(matches!(error.kind(), IpOutOfBounds));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let error = unit(vec![Inst::Unit, Inst::String { slot: 0 }, Inst::Return])
+        .verify(&context)
+        .unwrap_err();
+
+    match error.kind() {
+        BadInstruction { ip: 1, error, .. } => {
+            assert!(matches!(error.kind(), MissingStaticString { slot: 0 }));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let missing = Hash::type_hash(&["missing"]);
+
+    let error = unit(vec![
+        Inst::Call {
+            hash: missing,
+            args: 0,
+        },
+        Inst::Return,
+    ])
+    .verify(&context)
+    .unwrap_err();
+
+    match error.kind() {
+        BadInstruction { ip: 0, error, .. } => {
+            assert!(
+                matches!(error.kind(), runestick::VmErrorKind::MissingFunction { hash } if *hash == missing)
+            );
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let error = unit(vec![]).verify(&context).unwrap_err();
+    assert!(matches!(error.kind(), BadFunctionOffset { offset: 0, .. }));
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use rune_testing::*;
+use runestick::{
+    AllowedFunctions, Context, FromValue as _, Hash, Type, VmError, GENERATOR_TYPE, STRING_TYPE,
+};
+
+fn assert_denied(result: Result<Value, VmError>, expected: Hash) {
+    let e = result.unwrap_err();
+
+    match e.kind().as_unwound_ref().0 {
+        DeniedFunction { hash } => assert_eq!(*hash, expected),
+        kind => panic!("expected function to be denied, but was {:?}", kind),
+    }
+}
+
+#[test]
+fn test_allowed_functions() {
+    let len = Hash::instance_function(Type::from(STRING_TYPE), Hash::of("len"));
+    let dbg = Hash::type_hash(&["std", "dbg"]);
+
+    let mut allowed = AllowedFunctions::new();
+    allowed.allow(len);
+
+    let value = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn add(a, b) { a + b }
+        fn main() { add("hello".len(), 1) }
+        "#,
+        |vm| vm.with_allowed_functions(allowed.clone()),
+    )
+    .unwrap();
+
+    assert_eq!(i64::from_value(value).unwrap(), 6);
+
+    assert_denied(
+        run_with_options::<Value, _>(
+            Context::with_default_modules().unwrap(),
+            &Options::default(),
+            r#"fn main() { dbg("hello".len()) }"#,
+            |vm| vm.with_allowed_functions(allowed.clone()),
+        ),
+        dbg,
+    );
+
+    assert_denied(
+        run_with_options::<Value, _>(
+            Context::with_default_modules().unwrap(),
+            &Options::default(),
+            r#"fn main() { let f = dbg; f(1) }"#,
+            |vm| vm.with_allowed_functions(allowed.clone()),
+        ),
+        dbg,
+    );
+
+    // Spawned virtual machines share the same restrictions.
+    allowed.allow(Hash::instance_function(
+        Type::from(GENERATOR_TYPE),
+        Hash::of("next"),
+    ));
+
+    assert_denied(
+        run_with_options::<Value, _>(
+            Context::with_default_modules().unwrap(),
+            &Options::default(),
+            r#"
+            fn gen() { yield dbg(1); }
+            fn main() { gen().next() }
+            "#,
+            |vm| vm.with_allowed_functions(allowed.clone()),
+        ),
+        dbg,
+    );
+
+    allowed.allow(dbg);
+
+    let value = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"fn main() { dbg("hello".len()) }"#,
+        |vm| vm.with_allowed_functions(allowed.clone()),
+    )
+    .unwrap();
+    assert!(matches!(value, Value::Unit));
+}
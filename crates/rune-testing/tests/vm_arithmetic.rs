@@ -367,3 +367,47 @@ fn test_bitwise_not() {
         !0b10100,
     };
 }
+
+#[test]
+fn test_arithmetic_modes() {
+    use runestick::{Arithmetic, Context};
+
+    let run = |arithmetic: Arithmetic, source: &str| {
+        run_with_options::<i64, _>(
+            Context::with_default_modules().unwrap(),
+            &Options::default(),
+            source,
+            |vm| vm.with_arithmetic(arithmetic),
+        )
+    };
+
+    const ADD: &str = r#"fn main() { let a = 9223372036854775807; a + 1 }"#;
+    const ADD_ASSIGN: &str = r#"fn main() { let a = 9223372036854775807; a += 1; a }"#;
+    const SUB: &str = r#"fn main() { let a = -9223372036854775807; a - 2 }"#;
+    const MUL: &str = r#"fn main() { let a = 9223372036854775807; a * 2 }"#;
+
+    for source in &[ADD, ADD_ASSIGN, SUB, MUL] {
+        let e = run(Arithmetic::Checked, source).unwrap_err();
+        assert!(matches!(e.kind().as_unwound_ref().0, Overflow | Underflow));
+    }
+
+    assert_eq!(run(Arithmetic::Wrapping, ADD).unwrap(), i64::MIN);
+    assert_eq!(run(Arithmetic::Wrapping, ADD_ASSIGN).unwrap(), i64::MIN);
+    assert_eq!(run(Arithmetic::Wrapping, SUB).unwrap(), i64::MAX);
+    assert_eq!(run(Arithmetic::Wrapping, MUL).unwrap(), -2);
+
+    assert_eq!(run(Arithmetic::Saturating, ADD).unwrap(), i64::MAX);
+    assert_eq!(run(Arithmetic::Saturating, ADD_ASSIGN).unwrap(), i64::MAX);
+    assert_eq!(run(Arithmetic::Saturating, SUB).unwrap(), i64::MIN);
+    assert_eq!(run(Arithmetic::Saturating, MUL).unwrap(), i64::MAX);
+
+    // NB: dividing by zero errors regardless of mode.
+    for arithmetic in &[
+        Arithmetic::Checked,
+        Arithmetic::Wrapping,
+        Arithmetic::Saturating,
+    ] {
+        let e = run(*arithmetic, r#"fn main() { let a = 1; a / 0 }"#).unwrap_err();
+        assert!(matches!(e.kind().as_unwound_ref().0, DivideByZero));
+    }
+}
@@ -1,4 +1,6 @@
 use rune_testing::*;
+use runestick::{Context, Item, Vm, VmErrorKind};
+use std::sync::Arc;
 
 #[test]
 fn test_async_block() {
@@ -19,3 +21,61 @@ fn test_async_block() {
         }
     };
 }
+
+#[test]
+fn test_await_outside_async() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        async fn main() {
+            let future = async { 42 };
+            future.await
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let error = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap_err();
+
+    let (kind, unwound) = error.kind().as_unwound_ref();
+    assert!(matches!(kind, VmErrorKind::AwaitOutsideAsync));
+    assert!(unwound.is_some());
+}
+
+#[test]
+fn test_complete_blocking() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use std::future;
+
+        async fn add(a, b) {
+            a + b
+        }
+
+        async fn main() {
+            let three = async { 3 };
+            let (a, b) = future::join((add(1, 2), three)).await;
+            let sum = add(a, b);
+            sum.await
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete_blocking()
+        .unwrap();
+
+    assert_eq!(output.into_integer().unwrap(), 6);
+}
@@ -0,0 +1,85 @@
+use rune_testing::*;
+use runestick::{Breakpoints, Context, FromValue as _, Item, Source, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_breakpoints_hit_once_per_line() {
+    let context = Context::with_default_modules().unwrap();
+    let mut sources = rune::Sources::new();
+    let source_id = sources.insert_default(Source::new(
+        "main",
+        r#"fn add(a, b) {
+            a + b
+        }
+
+        fn main() {
+            let n = 0;
+            let i = 0;
+
+            while i < 3 {
+                n = add(n, i);
+                i = i + 1;
+            }
+
+            n
+        }
+        "#,
+    ));
+
+    let mut warnings = rune::Warnings::new();
+    let unit =
+        rune::load_sources(&context, &Options::default(), &mut sources, &mut warnings).unwrap();
+
+    let mut breakpoints = Breakpoints::new(rune::diagnostics::instruction_lines(&unit, &sources));
+    // `n = add(n, i);`
+    assert!(breakpoints.insert(source_id, 9));
+    // `a + b`
+    assert!(breakpoints.insert(source_id, 1));
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit)).with_breakpoints(breakpoints);
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+
+    let mut hits = Vec::new();
+
+    let output = loop {
+        if let Some(output) = execution.resume_until_breakpoint().unwrap() {
+            break output;
+        }
+
+        let vm = execution.vm().unwrap();
+        let (_, line) = vm.breakpoints().unwrap().hit().unwrap();
+        hits.push((line, vm.call_frames().len()));
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+    assert_eq!(hits, vec![(9, 0), (1, 1), (9, 0), (1, 1), (9, 0), (1, 1)]);
+}
+
+#[test]
+fn test_breakpoints_ignored_when_completing() {
+    let context = Context::with_default_modules().unwrap();
+    let mut sources = rune::Sources::new();
+    let source_id = sources.insert_default(Source::new(
+        "main",
+        r#"fn main() {
+            let a = 1;
+            a + 2
+        }
+        "#,
+    ));
+
+    let mut warnings = rune::Warnings::new();
+    let unit =
+        rune::load_sources(&context, &Options::default(), &mut sources, &mut warnings).unwrap();
+
+    let mut breakpoints = Breakpoints::new(rune::diagnostics::instruction_lines(&unit, &sources));
+    assert!(breakpoints.insert(source_id, 2));
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit)).with_breakpoints(breakpoints);
+    let output = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+}
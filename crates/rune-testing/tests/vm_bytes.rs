@@ -0,0 +1,128 @@
+use rune_testing::*;
+use runestick::Bytes;
+
+#[test]
+fn test_bytes_index() {
+    assert_eq! {
+        rune!(u8 => r#"fn main() { let b = b"abc"; b[1] }"#),
+        b'b',
+    };
+
+    assert_eq! {
+        rune!(Bytes => r#"
+        fn main() {
+            let b = b"abc";
+            b[0] = 255;
+            b[1] = b'z';
+            b[2] = 0;
+            b
+        }
+        "#),
+        Bytes::from_vec(vec![255, b'z', 0]),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { let b = b"abc"; b[3] }"#,
+        MissingIndex { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let b = b"abc"; b[0] = 256; }"#,
+        ValueToIntegerCoercionError { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let b = b"abc"; b[0] = "a"; }"#,
+        Expected { .. } => {}
+    );
+}
+
+#[test]
+fn test_bytes_push_and_slice() {
+    assert_eq! {
+        rune!(Bytes => r#"
+        fn main() {
+            let b = std::bytes::Bytes::new();
+            b.push(1);
+            b.push(b'a');
+            b.push(255);
+            b
+        }
+        "#),
+        Bytes::from_vec(vec![1, b'a', 255]),
+    };
+
+    assert_eq! {
+        rune!(Bytes => r#"
+        fn main() {
+            let b = b"hello";
+            let s = b[1..3];
+            s[0] = b'a';
+            s.push(b[4]);
+            (s, b.len(), s.len()).0
+        }
+        "#),
+        Bytes::from_vec(b"alo".to_vec()),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { let b = std::bytes::Bytes::new(); b.push(300); }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), ValueToIntegerCoercionError { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    assert_eq! {
+        rune!(Bytes => r#"
+        fn main() {
+            let b = b"abc";
+            let out = std::bytes::Bytes::new();
+
+            let n = 0;
+
+            while n < b.len() {
+                out.push(b[b.len() - n - 1]);
+                n = n + 1;
+            }
+
+            out
+        }
+        "#),
+        Bytes::from_vec(b"cba".to_vec()),
+    };
+}
+
+#[test]
+fn test_bytes_utf8() {
+    assert_eq! {
+        rune!(String => r#"
+        fn main() {
+            let b = "åäö".into_bytes();
+            b.into_string()?
+        }
+        "#),
+        "åäö",
+    };
+
+    assert!(rune!(bool => r#"
+        fn main() {
+            let b = b"abc";
+            b[1] = 255;
+            b.into_string().is_err()
+        }
+        "#));
+
+    assert_eq! {
+        rune!(String => r#"
+        fn main() {
+            let b = b"abc";
+            b[1] = 255;
+            b.into_string_lossy()
+        }
+        "#),
+        "a\u{fffd}c",
+    };
+}
@@ -0,0 +1,68 @@
+use rune_testing::*;
+use runestick::{Context, Item, Shared, Vm, VmErrorKind};
+use std::sync::Arc;
+
+#[test]
+fn test_cancel_between_steps() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main(v) {
+            v.push(1);
+            v.push(2);
+            v.push(3);
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let values = Shared::new(Vec::<Value>::new());
+
+    let mut execution = vm.call(&Item::of(&["main"]), (values.clone(),)).unwrap();
+
+    let token = execution.cancellation_token();
+
+    while values.borrow_ref().unwrap().is_empty() {
+        assert!(execution.step().unwrap().is_none());
+    }
+
+    token.cancel();
+
+    for _ in 0..3 {
+        let error = execution.step().unwrap_err();
+        assert!(matches!(error.kind(), VmErrorKind::Cancelled));
+    }
+
+    let error = execution.complete().unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::Cancelled));
+
+    // NB: the execution is torn down, so no further instructions ran.
+    assert!(execution.vm().is_err());
+    assert_eq!(values.borrow_ref().unwrap().len(), 1);
+}
+
+#[test]
+fn test_cancel_infinite_loop() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, r#"fn main() { loop {} }"#).unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+
+    let token = execution.cancellation_token();
+
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        token.cancel();
+    });
+
+    let error = execution.complete().unwrap_err();
+    assert!(matches!(
+        error.kind().as_unwound_ref().0,
+        VmErrorKind::Cancelled
+    ));
+
+    canceller.join().unwrap();
+}
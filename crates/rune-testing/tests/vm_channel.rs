@@ -0,0 +1,120 @@
+use rune_testing::*;
+use runestick::modules::channel;
+use runestick::Value;
+
+#[test]
+fn test_channel_between_scripts() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            use std::channel;
+            use std::future;
+
+            async fn produce(tx) {
+                tx.send(1);
+                tx.send(2);
+                tx.send(3);
+                tx.close();
+            }
+
+            async fn consume(rx) {
+                let sum = 0;
+
+                while let Some(value) = rx.recv().await {
+                    sum += value;
+                }
+
+                sum
+            }
+
+            async fn main() {
+                let (tx, rx) = channel::channel();
+                let (_, sum) = future::join((produce(tx), consume(rx))).await;
+                sum
+            }
+            "#
+        },
+        6,
+    };
+}
+
+#[test]
+fn test_channel_closed() {
+    assert_eq! {
+        rune! {
+            (bool, bool) => r#"
+            use std::channel;
+
+            async fn main() {
+                let (tx, rx) = channel::channel();
+                tx.close();
+                let closed = match rx.recv().await {
+                    None => true,
+                    _ => false,
+                };
+
+                let (tx, rx) = channel::channel();
+                rx.close();
+                let failed = match tx.send(42) {
+                    Err(value) => value == 42,
+                    _ => false,
+                };
+
+                (closed, failed)
+            }
+            "#
+        },
+        (true, true),
+    };
+}
+
+#[test]
+fn test_channel_select() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            use std::channel;
+
+            async fn main() {
+                let (tx, rx) = channel::channel();
+                tx.send(42);
+
+                select {
+                    value = rx.recv() => value.unwrap_or(0),
+                    default => 1,
+                }
+            }
+            "#
+        },
+        42,
+    };
+}
+
+#[test]
+fn test_channel_with_host() {
+    let (sender, mut receiver) = channel::channel();
+
+    let () = run(
+        &["main"],
+        (sender,),
+        r#"
+        async fn main(tx) {
+            tx.send(1);
+            tx.send(2);
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut values = Vec::new();
+
+    while let Some(value) = block_on(receiver.recv()) {
+        values.push(value.into_integer().unwrap());
+    }
+
+    assert_eq!(values, vec![1, 2]);
+
+    let (sender, receiver) = channel::channel();
+    drop(receiver);
+    assert!(sender.send(Value::Integer(1)).is_err());
+}
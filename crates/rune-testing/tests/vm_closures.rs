@@ -165,3 +165,23 @@ fn test_nested_async_closure() {
         }
     };
 }
+
+#[test]
+fn test_closures_in_chained_calls() {
+    assert_eq! {
+        vec![vec![2], vec![3]],
+        rune! {
+            Vec<Vec<i64>> => r#"
+            fn main() {
+                let out = [];
+
+                for n in [1, 2].iter().map(|a| a + 1).map(|b| [b]) {
+                    out.push(n);
+                }
+
+                out
+            }
+            "#
+        }
+    };
+}
@@ -0,0 +1,65 @@
+use rune_testing::*;
+
+#[test]
+fn test_min_max() {
+    assert_eq! {
+        rune!((i64, i64, f64, String, String) => r#"
+        use std::cmp::min;
+        use std::cmp::max;
+
+        fn main() {
+            (min(1, 2), max(1, 2), max(2.5, 1.5), min("b", "a"), max("b", String::from_str("c")))
+        }
+        "#),
+        (1, 2, 2.5, String::from("a"), String::from("c")),
+    };
+
+    assert_eq! {
+        rune!((Vec<i64>, char) => r#"
+        fn main() {
+            (std::cmp::max([1, 2], [1, 3]), std::cmp::min('x', 'y'))
+        }
+        "#),
+        (vec![1, 3], 'x'),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::cmp::min(1, "a") }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), UnsupportedBinaryOperation { op: "cmp", .. }));
+        }
+    );
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq! {
+        rune!((i64, i64, i64, f64) => r#"
+        use std::cmp::clamp;
+
+        fn main() {
+            (clamp(5, 1, 3), clamp(0, 1, 3), clamp(2, 1, 3), clamp(0.5, 1.0, 1.5))
+        }
+        "#),
+        (3, 1, 2, 1.0),
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { std::cmp::clamp("z", "a", "m") }"#),
+        "m",
+    };
+
+    assert_vm_error!(
+        r#"fn main() { std::cmp::clamp(2, 3, 1) }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), BadClampBounds));
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { std::cmp::clamp(2, 1, 3.0) }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), UnsupportedBinaryOperation { .. }));
+        }
+    );
+}
@@ -0,0 +1,69 @@
+use rune_testing::*;
+
+#[test]
+fn test_structural_eq() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            let a = [1, [2, 3], (4, "five")];
+            let b = [1, [2, 3], (4, "five")];
+            let c = [1, [2, 3], (4, "six")];
+            (a == b, a != c, (1, 2) == (1, 2), [1, 2] == [1, 2, 3])
+        }
+        "#),
+        (true, true, true, false),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            let a = #{"a": [1, 2], "b": #{"c": (3,)}};
+            let b = #{"a": [1, 2], "b": #{"c": (3,)}};
+            let c = #{"a": [1, 2], "b": #{"c": (4,)}};
+            (a == b, a == c, Some([1]) == Some([1]))
+        }
+        "#),
+        (true, false, true),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        struct Point { x, y }
+        struct Other { x, y }
+        enum Shape { Circle(r), Line(a, b) }
+
+        fn main() {
+            (
+                Point { x: 1, y: [2] } == Point { x: 1, y: [2] },
+                Point { x: 1, y: 2 } == Other { x: 1, y: 2 },
+                Shape::Line(1, 2) == Shape::Line(1, 2),
+            )
+        }
+        "#),
+        (true, false, true),
+    };
+}
+
+#[test]
+fn test_cyclic_eq() {
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            let a = [1];
+            a.push(a);
+            let b = [1];
+            b.push(b);
+            let c = [2];
+            c.push(c);
+            let result = (a == a, a == b, a == c);
+
+            // NB: break the cycles so that the vectors are freed.
+            a.clear();
+            b.clear();
+            c.clear();
+            result
+        }
+        "#),
+        (true, true, false),
+    };
+}
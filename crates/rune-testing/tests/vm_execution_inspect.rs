@@ -0,0 +1,35 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_modify_stack_between_steps() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let a = 1;
+            let b = a + 10;
+            b
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+
+    // Declare `a`.
+    assert!(execution.step().unwrap().is_none());
+
+    {
+        let stack = execution.vm_mut().unwrap().stack_mut();
+        let a = stack.at_offset_mut(0).unwrap();
+        assert_eq!(i64::from_value(a.clone()).unwrap(), 1);
+        *a = Value::Integer(5);
+    }
+
+    let output = execution.complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 15);
+}
@@ -0,0 +1,73 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+/// Run `main` in the given source, returning its output together with the
+/// number of field cache hits and misses.
+fn run_cached(source: &str) -> (i64, usize, usize) {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+    let output = execution.complete().unwrap();
+
+    let vm = execution.into_vm().unwrap();
+    let cache = vm.field_cache();
+    (
+        i64::from_value(output).unwrap(),
+        cache.hits(),
+        cache.misses(),
+    )
+}
+
+#[test]
+fn test_monomorphic_field_access() {
+    let (output, hits, misses) = run_cached(
+        r#"
+        struct Point { x, y }
+
+        fn main() {
+            let p = Point { x: 2, y: 3 };
+            let sum = 0;
+            let n = 0;
+
+            while n < 100 {
+                sum = sum + p.x;
+                n = n + 1;
+            }
+
+            sum
+        }
+        "#,
+    );
+
+    assert_eq!(output, 200);
+    assert_eq!(misses, 1);
+    assert_eq!(hits, 99);
+}
+
+#[test]
+fn test_polymorphic_field_access() {
+    let (output, hits, misses) = run_cached(
+        r#"
+        struct A { x }
+        struct B { a, x }
+        enum E { C { b, x } }
+
+        fn main() {
+            let sum = 0;
+
+            for v in [A { x: 1 }, B { a: 0, x: 10 }, B { a: 0, x: 100 }, E::C { b: 0, x: 1000 }, #{x: 10000}] {
+                sum = sum + v.x;
+            }
+
+            sum
+        }
+        "#,
+    );
+
+    assert_eq!(output, 11111);
+    assert_eq!(misses, 3);
+    assert_eq!(hits, 1);
+}
@@ -68,3 +68,72 @@ fn test_function() {
     let value: Value = function.call(()).unwrap();
     assert!(matches!(value, Value::Integer(3)));
 }
+
+#[test]
+fn test_function_reference() {
+    assert_eq! {
+        rune!((i64, Vec<i64>) => r#"
+        fn add(a, b) { a + b }
+        fn double(n) { n * 2 }
+
+        fn main() {
+            let f = add;
+            let out = [];
+
+            for n in [1, 2, 3].iter().map(double) {
+                out.push(n);
+            }
+
+            (f(1, 2), out)
+        }
+        "#),
+        (3, vec![2, 4, 6]),
+    };
+}
+
+#[test]
+fn test_partial() {
+    assert_eq! {
+        rune!((i64, i64, Vec<i64>) => r#"
+        fn add(a, b) { a + b }
+        fn add3(a, b, c) { a + b + c }
+
+        fn main() {
+            let inc = partial(add, 1);
+            let add_three = partial(partial(add3, 1), 2);
+            let out = [];
+
+            for n in [1, 2, 3].iter().map(partial(add, 10)) {
+                out.push(n);
+            }
+
+            (inc(5), add_three(3), out)
+        }
+        "#),
+        (6, 6, vec![11, 12, 13]),
+    };
+
+    let function = rune! {
+        Function => r#"
+        fn add(a, b) { a + b }
+        fn main() { partial(add, 1) }
+        "#
+    };
+
+    assert_eq!(function.call::<_, i64>((2i64,)).unwrap(), 3);
+    assert!(function.call::<_, i64>((2i64, 3i64)).is_err());
+}
+
+#[test]
+fn test_partial_over_applied() {
+    assert_vm_error!(
+        r#"
+        fn add(a, b) { a + b }
+        fn main() { partial(partial(add, 1), 2)(3) }
+        "#,
+        BadArgumentCount { actual, expected } => {
+            assert_eq!(*actual, 3);
+            assert_eq!(*expected, 2);
+        }
+    );
+}
@@ -0,0 +1,153 @@
+use rune_testing::*;
+use runestick::{Context, Gas, GasCosts, Hash, Inst, Item, Vm};
+use std::sync::Arc;
+
+const COUNT: &str = r#"
+fn main() {
+    let n = 0;
+
+    while n < 10 {
+        n += 1;
+    }
+
+    n
+}
+"#;
+
+#[test]
+fn test_out_of_gas() {
+    let gas = Gas::new(100);
+
+    let e = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            loop {}
+        }
+        "#,
+        |vm| vm.with_gas(gas.clone()),
+    )
+    .unwrap_err();
+
+    match e.kind().as_unwound_ref().0 {
+        OutOfGas { limit } => assert_eq!(*limit, 100),
+        kind => panic!("expected to run out of gas, but was {:?}", kind),
+    }
+
+    assert_eq!(gas.remaining(), 0);
+}
+
+#[test]
+fn test_default_costs_count_instructions() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, COUNT).unwrap();
+    let context = Arc::new(context);
+    let unit = Arc::new(unit);
+
+    let vm = Vm::new(context.clone(), unit.clone());
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+    let mut steps = 1;
+
+    while execution.step().unwrap().is_none() {
+        steps += 1;
+    }
+
+    let gas = Gas::new(1000);
+    let output = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        COUNT,
+        |vm| vm.with_gas(gas.clone()),
+    )
+    .unwrap();
+    assert!(matches!(output, Value::Integer(10)));
+    assert_eq!(gas.used(), steps);
+
+    // NB: exactly enough gas to run to completion.
+    let gas = Gas::new(steps);
+    assert!(run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        COUNT,
+        |vm| vm.with_gas(gas.clone())
+    )
+    .is_ok());
+
+    let gas = Gas::new(steps - 1);
+    assert!(run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        COUNT,
+        |vm| vm.with_gas(gas.clone())
+    )
+    .is_err());
+}
+
+#[test]
+fn test_weighted_costs() {
+    let mut costs = GasCosts::with_default(0);
+    costs.set(
+        &Inst::Call {
+            hash: Hash::of("foo"),
+            args: 0,
+        },
+        10,
+    );
+
+    let gas = Gas::with_costs(1000, costs);
+
+    let output = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn foo() { 1 }
+
+        fn main() {
+            foo() + foo() + foo()
+        }
+        "#,
+        |vm| vm.with_gas(gas.clone()),
+    )
+    .unwrap();
+
+    assert!(matches!(output, Value::Integer(3)));
+    assert_eq!(gas.used(), 30);
+}
+
+#[test]
+fn test_gas_shared_with_generators() {
+    let gas = Gas::new(1000);
+
+    let output = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn gen() {
+            yield 1;
+            yield 2;
+        }
+
+        fn main() {
+            let g = gen();
+            g.next();
+            g.next();
+            0
+        }
+        "#,
+        |vm| vm.with_gas(gas.clone()),
+    )
+    .unwrap();
+
+    assert!(matches!(output, Value::Integer(0)));
+
+    let without_generator = Gas::new(1000);
+    run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        "fn main() { 0 }",
+        |vm| vm.with_gas(without_generator.clone()),
+    )
+    .unwrap();
+    assert!(gas.used() > without_generator.used() + 10);
+}
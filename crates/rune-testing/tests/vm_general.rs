@@ -67,6 +67,88 @@ fn test_boolean_ops() {
     };
 }
 
+#[test]
+fn test_boolean_ops_short_circuit() {
+    assert_eq! {
+        rune!((bool, i64) => r#"
+        fn side_effect(o) { o.count = o.count + 1; true }
+        fn main() { let o = #{count: 0}; let out = false && side_effect(o); (out, o.count) }
+        "#),
+        (false, 0),
+    };
+
+    assert_eq! {
+        rune!((bool, i64) => r#"
+        fn side_effect(o) { o.count = o.count + 1; false }
+        fn main() { let o = #{count: 0}; let out = true || side_effect(o); (out, o.count) }
+        "#),
+        (true, 0),
+    };
+
+    assert_eq! {
+        rune!((bool, i64) => r#"
+        fn side_effect(o) { o.count = o.count + 1; true }
+        fn main() { let o = #{count: 0}; let out = true && side_effect(o); (out, o.count) }
+        "#),
+        (true, 1),
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"
+        fn side_effect(o) { o.count = o.count + 1; true }
+        fn main() { let o = #{count: 0}; false || side_effect(o); o.count }
+        "#),
+        1,
+    };
+}
+
+#[test]
+fn test_null_coalesce() {
+    assert_eq! {
+        rune!((i64, i64) => r#"
+        fn side_effect(o) { o.count = o.count + 1; 2 }
+        fn main() { let o = #{count: 0}; let out = Some(1) ?? side_effect(o); (out, o.count) }
+        "#),
+        (1, 0),
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => r#"
+        fn side_effect(o) { o.count = o.count + 1; 2 }
+        fn main() { let o = #{count: 0}; let out = None ?? side_effect(o); (out, o.count) }
+        "#),
+        (2, 1),
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => r#"
+        fn main() { let a = Ok(1); (a ?? 2, Err("error") ?? 3 + 1) }
+        "#),
+        (1, 4),
+    };
+
+    // NB: the operand is not consumed.
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { let a = Some(1); a ?? 2; a }"#),
+        Some(1),
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { None ?? (None ?? 3) }"#),
+        3,
+    };
+
+    assert_parse_error! {
+        r#"fn main() { None ?? None ?? 3 }"#,
+        PrecedenceGroupRequired { .. } => {}
+    };
+
+    assert_vm_error!(
+        r#"fn main() { 1 ?? 2 }"#,
+        UnsupportedIsValueOperand { .. } => {}
+    );
+}
+
 #[test]
 fn test_if() {
     assert_eq! {
@@ -882,3 +964,59 @@ fn test_index_get() {
         32,
     };
 }
+
+#[test]
+fn test_print_any_value() {
+    use runestick::{Context, Output, OutputBuffer};
+
+    let stdout = OutputBuffer::new();
+
+    run_with_options::<(), _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            print("a string ");
+            println(42);
+            println([1, 2.5, "three"]);
+            println(#{"a": (1, true)});
+            println(Some('c'));
+        }
+        "#,
+        |vm| vm.with_output(Output::new(stdout.clone(), std::io::sink())),
+    )
+    .unwrap();
+
+    assert_eq!(
+        String::from_utf8(stdout.take()).unwrap(),
+        "a string 42\n[1, 2.5, \"three\"]\n{\"a\": (1, true)}\nSome('c')\n"
+    );
+}
+
+#[test]
+fn test_eprint_any_value() {
+    use runestick::{Context, Output, OutputBuffer};
+
+    let stdout = OutputBuffer::new();
+    let stderr = OutputBuffer::new();
+
+    run_with_options::<(), _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            eprint("a string ");
+            eprintln(42);
+            eprintln([1, 2.5, "three"]);
+        }
+        "#,
+        |vm| vm.with_output(Output::new(stdout.clone(), stderr.clone())),
+    )
+    .unwrap();
+
+    assert!(stdout.take().is_empty());
+    assert_eq!(
+        String::from_utf8(stderr.take()).unwrap(),
+        "a string 42\n[1, 2.5, \"three\"]\n"
+    );
+}
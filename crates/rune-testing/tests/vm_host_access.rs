@@ -0,0 +1,109 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, HostAccess, Item, Module, Vm, VmError};
+use std::sync::Arc;
+
+async fn check_fs() -> Result<(), VmError> {
+    HostAccess::Fs.check()
+}
+
+fn context(access: HostAccess, allowed: bool) -> Context {
+    let mut module = Module::new(&["host"]);
+    module
+        .function(&["env"], || HostAccess::Env.check())
+        .unwrap();
+    module.async_function(&["fs"], check_fs).unwrap();
+    module
+        .function(&["process"], || HostAccess::Process.check())
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    context.allow_host_access(access, allowed);
+    context
+}
+
+fn call_async(context: Context, source: &str) -> Result<(), VmError> {
+    let unit = compile_with_options(&context, &Options::default(), source);
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = block_on(vm.call(&Item::of(&["main"]), ())?.async_complete())?;
+    <()>::from_value(output)
+}
+
+fn assert_denied(result: Result<(), VmError>, expected: HostAccess) {
+    let e = result.unwrap_err();
+
+    // NB: errors raised by async native functions aren't wrapped.
+    let kind = match e.kind().as_unwound_ref().0 {
+        BadReturn { error, .. } => error.kind(),
+        kind => kind,
+    };
+
+    match kind {
+        DeniedHostAccess { access } => assert_eq!(*access, expected),
+        kind => panic!("expected host access to be denied, but was {:?}", kind),
+    }
+}
+
+#[test]
+fn test_host_access_env() {
+    let source = r#"fn main() { host::env() }"#;
+
+    run_with_options::<(), _>(
+        context(HostAccess::Env, true),
+        &Options::default(),
+        source,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_denied(
+        run_with_options(
+            context(HostAccess::Env, false),
+            &Options::default(),
+            source,
+            |vm| vm,
+        ),
+        HostAccess::Env,
+    );
+}
+
+#[test]
+fn test_host_access_fs() {
+    // NB: async native functions are polled outside of the virtual machine
+    // which called them, and must still see the access granted by its context.
+    let source = r#"async fn main() { host::fs().await }"#;
+
+    call_async(context(HostAccess::Fs, true), source).unwrap();
+    assert_denied(
+        call_async(context(HostAccess::Fs, false), source),
+        HostAccess::Fs,
+    );
+
+    call_async(context(HostAccess::Env, false), source).unwrap();
+}
+
+#[test]
+fn test_host_access_process() {
+    let source = r#"fn main() { host::process() }"#;
+
+    // NB: process execution is denied unless explicitly granted.
+    assert!(!Context::new().is_host_access_allowed(HostAccess::Process));
+
+    assert_denied(
+        run_with_options(
+            context(HostAccess::Env, true),
+            &Options::default(),
+            source,
+            |vm| vm,
+        ),
+        HostAccess::Process,
+    );
+
+    run_with_options::<(), _>(
+        context(HostAccess::Process, true),
+        &Options::default(),
+        source,
+        |vm| vm,
+    )
+    .unwrap();
+}
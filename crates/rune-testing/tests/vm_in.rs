@@ -0,0 +1,60 @@
+use rune_testing::*;
+
+#[test]
+fn test_in_vec_and_tuple() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            let v = [1, "two", [3]];
+            (1 in v, [3] in v, 4 in v, "b" in ("a", "b"))
+        }
+        "#),
+        (true, true, false, true),
+    };
+}
+
+#[test]
+fn test_in_object() {
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            let o = #{"a": 1, "b": 2};
+            let key = String::from_str("b");
+            ("a" in o, key in o, "c" in o)
+        }
+        "#),
+        (true, true, false),
+    };
+}
+
+#[test]
+fn test_in_string() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            let s = String::from_str("hello world");
+            ("lo w" in s, "bye" in s, 'w' in "world", "" in "abc")
+        }
+        "#),
+        (true, false, true, true),
+    };
+
+    assert!(rune!(bool => r#"fn main() { b'b' in b"abc" }"#));
+}
+
+#[test]
+fn test_in_unsupported() {
+    assert_vm_error!(
+        r#"fn main() { 1 in 2 }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(*op, "in");
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { 1 in #{} }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(*op, "in");
+        }
+    );
+}
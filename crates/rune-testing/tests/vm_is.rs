@@ -1,4 +1,6 @@
 use rune_testing::*;
+use runestick::{Any, Context, FromValue as _, Item, Module, Vm};
+use std::sync::Arc;
 
 #[test]
 fn test_binop_override() {
@@ -24,3 +26,68 @@ fn test_binop_override() {
         (true, false, false, true),
     };
 }
+
+#[derive(Debug, Any)]
+struct Foo;
+
+#[derive(Debug, Any)]
+struct Bar;
+
+#[test]
+fn test_is_host_type() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.ty(&["Foo"]).build::<Foo>()?;
+    module.ty(&["Bar"]).build::<Bar>()?;
+    module.function(&["Foo", "new"], || Foo)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use native::Foo;
+        use native::Bar;
+
+        fn main(host) {
+            let foo = Foo::new();
+            (foo is Foo, foo is Bar, host is Foo, host is not Bar, 1 is Foo)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm.call(&Item::of(&["main"]), (Foo,))?.complete()?;
+    let output = <(bool, bool, bool, bool, bool)>::from_value(output)?;
+    assert_eq!(output, (true, false, true, true, false));
+    Ok(())
+}
+
+#[test]
+fn test_is_capability() {
+    assert_eq! {
+        rune! {
+            (bool, bool, bool) => r#"
+            fn main() {
+                let v = [1, 2, 3];
+                (v is Iterable, v is Indexable, v is Callable)
+            }
+            "#
+        },
+        (true, true, false),
+    };
+
+    assert_eq! {
+        rune! {
+            (bool, bool, bool, bool) => r#"
+            fn add(a, b) { a + b }
+
+            fn main() {
+                let o = #{a: 1};
+                (add is Callable, add is Iterable, o is Iterable, 1 is not Indexable)
+            }
+            "#
+        },
+        (true, false, true, true),
+    };
+}
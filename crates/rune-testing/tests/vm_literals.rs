@@ -38,3 +38,39 @@ fn test_octal() {
         -63,
     };
 }
+
+#[test]
+fn test_separators() {
+    assert_eq! {
+        rune!(i64 => r#"fn main() { 1_000_000 }"#),
+        1000000,
+    };
+
+    assert_eq! {
+        rune!(f64 => r#"fn main() { 1_000.5 }"#),
+        1000.5,
+    };
+}
+
+#[test]
+fn test_suffixes() {
+    assert_eq! {
+        rune!(i64 => r#"fn main() { 5i64 }"#),
+        5,
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"fn main() { -0xffi64 }"#),
+        -255,
+    };
+
+    assert_eq! {
+        rune!(f64 => r#"fn main() { 5f64 }"#),
+        5.0,
+    };
+
+    assert_eq! {
+        rune!(f64 => r#"fn main() { -1.5f64 }"#),
+        -1.5,
+    };
+}
@@ -0,0 +1,56 @@
+use rune_testing::*;
+use runestick::Context;
+
+fn assert_memoized(source: &str, expected: Vec<i64>) {
+    for &memoize_instance_fn in &[false, true] {
+        let mut options = Options::default();
+        options.memoize_instance_fn(memoize_instance_fn);
+
+        let context = Context::with_default_modules().unwrap();
+        let output: Vec<i64> = run_with_options(context, &options, source, |vm| vm).unwrap();
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_loop_variable_changes_type() {
+    assert_memoized(
+        r#"
+        fn main() {
+            let lens = [];
+
+            for v in ["ab", [1, 2, 3], #{a: 1}] {
+                lens.push(v.len());
+                v = 42;
+            }
+
+            lens
+        }
+        "#,
+        vec![2, 3, 1],
+    );
+}
+
+#[test]
+fn test_loop_reentered_with_other_iterables() {
+    assert_memoized(
+        r#"
+        fn main() {
+            let totals = [];
+
+            for iterable in [[1, 2], std::iter::range(0, 4), #{a: 1, b: 2}.values()] {
+                let total = 0;
+
+                for v in iterable {
+                    total = total + v;
+                }
+
+                totals.push(total);
+            }
+
+            totals
+        }
+        "#,
+        vec![3, 6, 3],
+    );
+}
@@ -0,0 +1,139 @@
+use rune_testing::*;
+use runestick::{Context, Memory};
+
+#[test]
+fn test_memory_limit_exceeded() {
+    let memory = Memory::new(4096);
+
+    let e = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            let out = [];
+
+            loop {
+                out.push(#{a: 1, b: 2});
+            }
+        }
+        "#,
+        |vm| vm.with_memory(memory.clone()),
+    )
+    .unwrap_err();
+
+    match e.kind().as_unwound_ref().0 {
+        MemoryLimitExceeded { limit, used } => {
+            assert_eq!(*limit, 4096);
+            assert!(*used > 4096);
+        }
+        kind => panic!("expected memory limit to be exceeded, but was {:?}", kind),
+    }
+}
+
+#[test]
+fn test_memory_released_when_freed() {
+    let memory = Memory::new(4096);
+
+    let output = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            let n = 0;
+
+            while n < 1000 {
+                let o = #{a: 1, b: 2};
+                n = n + 1;
+            }
+
+            n
+        }
+        "#,
+        |vm| vm.with_memory(memory.clone()),
+    )
+    .unwrap();
+
+    assert!(matches!(output, Value::Integer(1000)));
+    assert_eq!(memory.used(), 0);
+}
+
+#[test]
+fn test_memory_limit_huge_string() {
+    let memory = Memory::new(4096);
+
+    let e = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            "a".repeat(1099511627776)
+        }
+        "#,
+        |vm| vm.with_memory(memory.clone()),
+    )
+    .unwrap_err();
+
+    match e.kind().as_unwound_ref().0 {
+        BadReturn { error, .. } => match error.kind() {
+            MemoryLimitExceeded { limit, used } => {
+                assert_eq!(*limit, 4096);
+                assert!(*used >= 1099511627776);
+            }
+            kind => panic!("expected memory limit to be exceeded, but was {:?}", kind),
+        },
+        kind => panic!("expected memory limit to be exceeded, but was {:?}", kind),
+    }
+}
+
+#[test]
+fn test_memory_limit_vec_growth() {
+    let memory = Memory::new(4096);
+
+    let e = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            let out = [];
+
+            loop {
+                out.push(1);
+            }
+        }
+        "#,
+        |vm| vm.with_memory(memory.clone()),
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        e.kind().as_unwound_ref().0,
+        MemoryLimitExceeded { .. }
+    ));
+}
+
+#[test]
+fn test_memory_limit_string_growth() {
+    let memory = Memory::new(4096);
+
+    let e = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &Options::default(),
+        r#"
+        fn main() {
+            let s = String::new();
+
+            loop {
+                s.push_str("abcd");
+            }
+        }
+        "#,
+        |vm| vm.with_memory(memory.clone()),
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        e.kind().as_unwound_ref().0,
+        MemoryLimitExceeded { .. }
+    ));
+    assert_eq!(memory.used(), 0);
+}
@@ -0,0 +1,112 @@
+use rune_testing::*;
+use runestick::{Context, FromValue, Integer, VmError, VmErrorKind};
+
+fn options(negative_index: bool) -> Options {
+    let mut options = Options::default();
+    options.negative_index(negative_index);
+    options
+}
+
+fn missing_index(error: VmError) -> i64 {
+    match error.kind().as_unwound_ref().0 {
+        VmErrorKind::MissingIndex {
+            index: Integer::I64(index),
+            ..
+        } => *index,
+        kind => panic!("expected missing index but was: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_negative_index_get() {
+    let source = r#"fn main() { let v = [1, 2, 3]; (v[-1], v[-3], (4, 5)[-2]) }"#;
+    let value = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(true),
+        source,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(<(i64, i64, i64)>::from_value(value).unwrap(), (3, 1, 4));
+
+    let error = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(false),
+        source,
+        |vm| vm,
+    )
+    .unwrap_err();
+    assert_eq!(missing_index(error), -1);
+}
+
+#[test]
+fn test_negative_index_set() {
+    let source = r#"fn main() { let v = [1, 2, 3]; v[-1] = 10; v[0] = 20; v }"#;
+    let value = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(true),
+        source,
+        |vm| vm,
+    )
+    .unwrap();
+
+    assert_eq!(<Vec<i64>>::from_value(value).unwrap(), vec![20, 2, 10]);
+}
+
+#[test]
+fn test_negative_index_out_of_range() {
+    let error = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(true),
+        r#"fn main() { let v = [1, 2, 3]; v[-4] }"#,
+        |vm| vm,
+    )
+    .unwrap_err();
+    assert_eq!(missing_index(error), -4);
+
+    let error = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(true),
+        r#"fn main() { let v = [1, 2, 3]; v[-4] = 1; }"#,
+        |vm| vm,
+    )
+    .unwrap_err();
+    assert_eq!(missing_index(error), -4);
+
+    let error = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(true),
+        r#"fn main() { let v = [1, 2, 3]; v[3] = 1; }"#,
+        |vm| vm,
+    )
+    .unwrap_err();
+    assert_eq!(missing_index(error), 3);
+}
+
+#[test]
+fn test_index_set_without_negative_index() {
+    let source = r#"fn main() { let v = [1, 2, 3]; v[0] = 20; v }"#;
+    let error = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(false),
+        source,
+        |vm| vm,
+    )
+    .unwrap_err();
+
+    match error.kind().as_unwound_ref().0 {
+        VmErrorKind::UnsupportedIndexSet { .. } => (),
+        kind => panic!("expected unsupported index set but was: {:?}", kind),
+    }
+
+    let source = r#"fn main() { let o = #{}; o["a"] = 1; o["a"] }"#;
+    let value = run_with_options::<Value, _>(
+        Context::with_default_modules().unwrap(),
+        &options(false),
+        source,
+        |vm| vm,
+    )
+    .unwrap();
+    assert_eq!(i64::from_value(value).unwrap(), 1);
+}
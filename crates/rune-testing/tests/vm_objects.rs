@@ -0,0 +1,108 @@
+use rune_testing::*;
+
+#[test]
+fn test_object_insertion_order() {
+    assert_eq! {
+        rune!(Vec<String> => r#"
+        fn main() {
+            let o = #{c: 1, a: 2};
+            o.insert("d", 3);
+            o.insert("b", 4);
+            o.remove("a");
+            o.insert("e", 5);
+            // NB: updating an existing key keeps its position.
+            o.insert("c", 6);
+
+            let out = [];
+
+            for entry in o {
+                out.push(`{entry.0}={entry.1}`);
+            }
+
+            out
+        }
+        "#),
+        vec!["c=6", "d=3", "b=4", "e=5"],
+    };
+}
+
+#[test]
+fn test_object_keys_values() {
+    assert_eq! {
+        rune!((Vec<String>, Vec<i64>) => r#"
+        fn main() {
+            let o = #{z: 1, y: 2, x: 3};
+            o.remove("y");
+            o.insert("w", 4);
+            (o.keys(), o.values())
+        }
+        "#),
+        (
+            vec![String::from("z"), String::from("x"), String::from("w")],
+            vec![1, 3, 4],
+        ),
+    };
+}
+
+#[test]
+fn test_collect_object() {
+    assert_eq! {
+        rune!(Vec<String> => r#"
+        fn main() {
+            let o = [("a", 1), ("b", 2)].iter().collect_object();
+            o.insert("c", 3);
+            o.keys()
+        }
+        "#),
+        vec!["a", "b", "c"],
+    };
+
+    // Transform the entries of an object and rebuild it.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let o = #{a: 1, b: 2, c: 3};
+            let o = o.iter().flat_map(|e| if e.1 % 2 == 1 { Some((e.0, e.1 * 10)) } else { None }).collect_object();
+            [o.a, o.c, o.len()]
+        }
+        "#),
+        vec![10, 30, 2],
+    };
+}
+
+#[test]
+fn test_collect_object_errors() {
+    assert_vm_error!(
+        r#"fn main() { [("a", 1), 2].iter().collect_object() }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), Expected { .. }));
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { [(1, 1)].iter().collect_object() }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), Expected { .. }));
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { [("a", 1, 2)].iter().collect_object() }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), ExpectedTupleLength { actual: 3, expected: 2 }));
+        }
+    );
+}
+
+#[test]
+fn test_object_get() {
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { #{a: 1, b: 2}.get("b") }"#),
+        Some(2),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { #{a: 1, b: 2}.get("c") }"#),
+        None,
+    };
+}
@@ -0,0 +1,76 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_constructors() {
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::some(1) }"#),
+        Some(1),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { std::none() }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Result<i64, String> => r#"fn main() { std::ok(1) }"#),
+        Ok(1),
+    };
+
+    assert_eq! {
+        rune!(Result<i64, String> => r#"fn main() { std::err("bad") }"#),
+        Err(String::from("bad")),
+    };
+}
+
+#[test]
+fn test_predicates() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() { (Some(1).is_some(), Some(1).is_none(), None.is_some(), None.is_none()) }
+        "#),
+        (true, false, false, true),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() { (Ok(1).is_ok(), Ok(1).is_err(), Err(1).is_ok(), Err(1).is_err()) }
+        "#),
+        (true, false, false, true),
+    };
+
+    assert_eq! {
+        rune!((bool, bool) => r#"
+        use std::some;
+        use std::err;
+        fn main() { (some(()).is_some(), err(()).is_err()) }
+        "#),
+        (true, true),
+    };
+}
+
+#[test]
+fn test_wrap_host_values() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"fn main(value) { if value > 0 { std::ok(value) } else { std::err(value) } }"#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+
+    let output = vm
+        .clone()
+        .call(&Item::of(&["main"]), (1,))
+        .unwrap()
+        .complete();
+    let output = Result::<i64, i64>::from_value(output.unwrap()).unwrap();
+    assert_eq!(output, Ok(1));
+
+    let output = vm.call(&Item::of(&["main"]), (-1,)).unwrap().complete();
+    let output = Result::<i64, i64>::from_value(output.unwrap()).unwrap();
+    assert_eq!(output, Err(-1));
+}
@@ -0,0 +1,87 @@
+use rune_testing::*;
+use runestick::{Context, Item, Output, OutputBuffer, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_captured_output() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        async fn inner(n) {
+            println(`async {n}`);
+        }
+
+        async fn main() {
+            print("hello ");
+            println("world");
+            eprintln("oops");
+
+            let f = |n| println(`closure {n}`);
+            f(1);
+
+            inner(2).await;
+            dbg(3);
+        }
+        "#,
+    )
+    .unwrap();
+
+    let stdout = OutputBuffer::new();
+    let stderr = OutputBuffer::new();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit))
+        .with_output(Output::new(stdout.clone(), stderr.clone()));
+
+    block_on(vm.call(&Item::of(&["main"]), ()).unwrap().async_complete()).unwrap();
+
+    assert_eq!(
+        String::from_utf8(stdout.take()).unwrap(),
+        "hello world\nclosure 1\nasync 2\n3\n"
+    );
+    assert_eq!(String::from_utf8(stderr.take()).unwrap(), "oops\n");
+}
+
+#[test]
+fn test_pretty_dbg() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            dbg(#{a: [1, (2, "x")], b: Some(#{c: true}), d: ()});
+        }
+        "#,
+    )
+    .unwrap();
+
+    let stdout = OutputBuffer::new();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit))
+        .with_output(Output::new(stdout.clone(), std::io::sink()).with_pretty(true));
+
+    vm.call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(stdout.take()).unwrap(),
+        r#"{
+    "a": [
+        1,
+        (
+            2,
+            "x",
+        ),
+    ],
+    "b": Some(
+        {
+            "c": true,
+        },
+    ),
+    "d": (),
+}
+"#
+    );
+}
@@ -0,0 +1,61 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_reset_retains_capacity() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main(n) {
+            let a = n + 1;
+            let b = a * 2;
+            let c = (a, b, [a, b]);
+            c.0 + c.1
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let mut capacity = None;
+
+    for n in 0..100i64 {
+        let mut execution = vm.call(&Item::of(&["main"]), (n,)).unwrap();
+        let output = execution.complete().unwrap();
+        assert_eq!(i64::from_value(output).unwrap(), (n + 1) * 3);
+
+        vm = execution.into_vm().unwrap();
+        assert!(vm.stack().is_empty());
+        assert_eq!(vm.ip(), 0);
+
+        // NB: the stack grows during the first run, after which the same
+        // allocation is reused.
+        let current = vm.stack().capacity();
+        assert!(current > 0);
+        assert_eq!(*capacity.get_or_insert(current), current);
+    }
+}
+
+#[test]
+fn test_into_vm_discards_unfinished_execution() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, r#"fn main(a, b) { a + b }"#).unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+
+    let mut execution = vm.call(&Item::of(&["main"]), (1i64, 2i64)).unwrap();
+    assert!(execution.step().unwrap().is_none());
+
+    let vm = execution.into_vm().unwrap();
+    assert!(vm.stack().is_empty());
+
+    let output = vm
+        .call(&Item::of(&["main"]), (3i64, 4i64))
+        .unwrap()
+        .complete()
+        .unwrap();
+
+    assert_eq!(i64::from_value(output).unwrap(), 7);
+}
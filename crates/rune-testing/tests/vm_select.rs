@@ -0,0 +1,53 @@
+use rune_testing::*;
+use runestick::{Future, VmError};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future which never completes.
+struct Pending;
+
+impl std::future::Future for Pending {
+    type Output = Result<i64, VmError>;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn test_select_default_pending() {
+    let pending = Future::new(Pending);
+
+    let output: i64 = run(
+        &["main"],
+        (pending,),
+        r#"
+        async fn main(f) {
+            select {
+                _ = f => 1,
+                default => 2,
+            }
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(output, 2);
+}
+
+#[test]
+fn test_select_default_ready() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            async fn main() {
+                select {
+                    value = async { 42 } => value,
+                    default => 0,
+                }
+            }
+            "#
+        },
+        42,
+    };
+}
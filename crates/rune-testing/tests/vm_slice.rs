@@ -0,0 +1,93 @@
+use rune_testing::*;
+
+#[test]
+fn test_slice_string() {
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = "hello"; s[1..3] }"#),
+        "el",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = String::from_str("hello"); s[2..] }"#),
+        "llo",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = "hello"; s[..2] }"#),
+        "he",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = "hello"; s[..] }"#),
+        "hello",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = "åäö"; s[2..4] }"#),
+        "ä",
+    };
+}
+
+#[test]
+fn test_slice_vec() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[2..] }"#),
+        vec![3, 4],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[1..3] }"#),
+        vec![2, 3],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { let v = [1, 2, 3, 4]; v[4..] }"#),
+        Vec::<i64>::new(),
+    };
+
+    // Slices are fresh copies which don't affect the original.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let v = [1, 2, 3];
+            let s = v[..2];
+            s.push(10);
+            v
+        }
+        "#),
+        vec![1, 2, 3],
+    };
+}
+
+#[test]
+fn test_slice_errors() {
+    assert_vm_error!(
+        r#"fn main() { let v = [1, 2, 3]; v[1..4] }"#,
+        SliceOutOfRange { start, end, len, .. } => {
+            assert_eq!(*start, 1);
+            assert_eq!(*end, 4);
+            assert_eq!(*len, 3);
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let v = [1, 2, 3]; v[2..1] }"#,
+        SliceOutOfRange { start, end, .. } => {
+            assert_eq!(*start, 2);
+            assert_eq!(*end, 1);
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let s = "åäö"; s[1..] }"#,
+        SliceNotCharBoundary { start, end } => {
+            assert_eq!(*start, 1);
+            assert_eq!(*end, 6);
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let n = 42; n[1..] }"#,
+        UnsupportedSlice { .. } => {}
+    );
+}
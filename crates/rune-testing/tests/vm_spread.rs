@@ -0,0 +1,122 @@
+use rune_testing::*;
+
+#[test]
+fn test_spread_vec() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let a = [1, 2, 3];
+            let b = [5, 6];
+            [..a, 4, ..b]
+        }
+        "#),
+        vec![1, 2, 3, 4, 5, 6],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let a = [];
+            [0, ..a, ..[], ..[1]]
+        }
+        "#),
+        vec![0, 1],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { [..[]] }"#),
+        Vec::<i64>::new(),
+    };
+}
+
+#[test]
+fn test_spread_call() {
+    assert_eq! {
+        rune!((i64, i64, i64) => r#"
+        fn foo(a, b, c) { (a, b, c) }
+
+        fn main() {
+            let args = [2, 3];
+            foo(1, ..args)
+        }
+        "#),
+        (1, 2, 3),
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"
+        fn main() {
+            let f = |a, b, c| a * b + c;
+            let args = [2, 3];
+            f(..args, 4)
+        }
+        "#),
+        10,
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => r#"
+        struct Point(x, y);
+
+        fn main() {
+            let p = Point(..[1, 2]);
+            (p.0, p.1)
+        }
+        "#),
+        (1, 2),
+    };
+}
+
+#[test]
+fn test_spread_instance_call() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let v = [1, 2];
+            v.push(..[3]);
+            v
+        }
+        "#),
+        vec![1, 2, 3],
+    };
+}
+
+#[test]
+fn test_spread_bad_argument_count() {
+    assert_vm_error!(
+        r#"
+        fn foo(a, b) { a + b }
+        fn main() { foo(..[1, 2, 3]) }
+        "#,
+        BadArgumentCount { actual, expected } => {
+            assert_eq!(*actual, 3);
+            assert_eq!(*expected, 2);
+        }
+    );
+}
+
+#[test]
+fn test_spread_non_vec() {
+    assert_vm_error!(
+        r#"fn main() { [1, ..2] }"#,
+        Expected { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        fn foo(a) { a }
+        fn main() { foo(..(1, 2)) }
+        "#,
+        Expected { .. } => {}
+    );
+}
+
+#[test]
+fn test_spread_unsupported() {
+    assert_compile_error! {
+        r#"fn main() { let a = [1]; ..a }"#,
+        UnsupportedSpread { span } => {
+            assert_eq!(span, Span::new(25, 28));
+        }
+    };
+}
@@ -0,0 +1,100 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_static() {
+    assert_eq! {
+        rune!((i64, i64, usize, Option<usize>) => r#"
+        static TABLE = {
+            let table = [];
+            table.push(1);
+            table.push(2);
+            table
+        };
+
+        fn sum() {
+            let sum = 0;
+
+            for n in TABLE {
+                sum += n;
+            }
+
+            sum
+        }
+
+        fn gen() {
+            yield TABLE.len();
+        }
+
+        fn main() {
+            let f = || TABLE.len();
+            (sum(), sum(), f(), gen().next())
+        }
+        "#),
+        (3, 3, 2, Some(2)),
+    };
+
+    assert_eq! {
+        rune!(i64 => r#"
+        mod config {
+            static LIMIT = 10;
+        }
+
+        fn main() {
+            config::LIMIT * 2
+        }
+        "#),
+        20,
+    };
+}
+
+#[test]
+fn test_static_initialized_once() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        static CALLS = [];
+
+        fn main() {
+            CALLS.push(());
+            CALLS.len()
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context), Arc::new(unit));
+
+    // NB: the same value is observed by every call, since the initializer only
+    // runs the first time the static is accessed.
+    for n in 1..=3usize {
+        let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+        let output = execution.complete().unwrap();
+        assert_eq!(usize::from_value(output).unwrap(), n);
+        vm = execution.into_vm().unwrap();
+    }
+
+    vm.clear_statics();
+
+    let output = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap();
+    assert_eq!(usize::from_value(output).unwrap(), 1);
+}
+
+#[test]
+fn test_static_cycle() {
+    assert_vm_error!(
+        r#"
+        static A = B + 1;
+        static B = A + 1;
+
+        fn main() { A }
+        "#,
+        StaticCycle { .. } => {}
+    );
+}
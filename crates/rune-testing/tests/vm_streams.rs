@@ -71,3 +71,55 @@ fn test_resume() {
         6,
     };
 }
+
+#[test]
+fn test_for_await() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            async fn foo() {
+                yield 1;
+                yield 2;
+                yield 3;
+            }
+
+            async fn main() {
+                let result = 0;
+
+                for await value in foo() {
+                    result += value;
+                }
+
+                result
+            }
+            "#
+        },
+        6,
+    };
+}
+
+#[test]
+fn test_for_await_native_stream() {
+    use runestick::{Shared, Stream};
+
+    let stream = Stream::from_stream(futures::stream::iter(vec![1i64, 2, 3]));
+
+    let result: i64 = run(
+        &["main"],
+        (Shared::new(stream),),
+        r#"
+        async fn main(stream) {
+            let result = 0;
+
+            for await value in stream {
+                result += value;
+            }
+
+            result
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(result, 6);
+}
@@ -0,0 +1,218 @@
+use rune_testing::*;
+
+#[test]
+fn test_string_repeat() {
+    assert_eq! {
+        rune!(String => r#"fn main() { "ab".repeat(3) }"#),
+        "ababab",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { "ab".repeat(0) }"#),
+        "",
+    };
+}
+
+#[test]
+fn test_string_repeat_overflow() {
+    assert_vm_error!(
+        r#"fn main() { "ab".repeat(9223372036854775807) }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), Overflow));
+        }
+    );
+}
+
+#[test]
+fn test_vec_join() {
+    assert_eq! {
+        rune!(String => r#"fn main() { ["a", "b"].join(",") }"#),
+        "a,b",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { let s = String::from_str("b"); ["a", s, "c"].join(", ") }"#),
+        "a, b, c",
+    };
+
+    assert_eq! {
+        rune!(String => r#"fn main() { [].join(",") }"#),
+        "",
+    };
+
+    assert_vm_error!(
+        String => r#"fn main() { ["a", 1].join(",") }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                Expected { expected, actual } => {
+                    assert_eq!(expected.to_string(), "String");
+                    assert_eq!(actual.to_string(), "integer");
+                }
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+}
+
+#[test]
+fn test_string_find() {
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "abcabc".find("bc") }"#),
+        Some(1),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "abcabc".rfind("bc") }"#),
+        Some(4),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "aaa".find("aa") }"#),
+        Some(0),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "aaa".rfind("aa") }"#),
+        Some(1),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "abc".find("x") }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "abc".rfind("x") }"#),
+        None,
+    };
+}
+
+#[test]
+fn test_string_find_multi_byte() {
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "åäö-ö".find("ö") }"#),
+        Some(4),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { "åäö-ö".rfind("ö") }"#),
+        Some(7),
+    };
+}
+
+#[test]
+fn test_string_ordering() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => r#"
+        fn main() {
+            let a = "apple";
+            let b = String::from_str("banana");
+            (a < b, a > b, a <= "apple", b >= "bananas")
+        }
+        "#),
+        (true, false, true, false),
+    };
+
+    // NB: prefixes order before longer strings.
+    assert_eq! {
+        rune!((bool, bool) => r#"fn main() { ("ab" < "abc", "abc" < "ab") }"#),
+        (true, false),
+    };
+
+    // Unicode strings are ordered by code point.
+    assert_eq! {
+        rune!((bool, bool, bool) => r#"
+        fn main() {
+            ("z" < "å", "ä" < "å", "äpple" < "öl")
+        }
+        "#),
+        (true, true, true),
+    };
+}
+
+#[test]
+fn test_string_ordering_incompatible() {
+    assert_vm_error!(
+        r#"fn main() { "1" < 1 }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(*op, "<");
+        }
+    );
+}
+
+#[test]
+fn test_template_display() {
+    assert_eq! {
+        rune!(String => r#"fn main() { `{"hi"} {'c'} {true} {1.5} {42}` }"#),
+        "hi c true 1.5 42",
+    };
+}
+
+#[test]
+fn test_float_template() {
+    assert_eq! {
+        rune!(String => r#"fn main() { `{1.0} {2.5} {100000000000000000000000.0}` }"#),
+        "1.0 2.5 1.0e23",
+    };
+}
+
+#[test]
+fn test_string_builder() {
+    assert_eq! {
+        rune!(String => r#"
+        use std::string::StringBuilder;
+
+        fn main() {
+            let b = StringBuilder::new();
+            b.push_str("hello");
+            b.push(' ');
+            b.push_str(String::from_str("world"));
+            b.build()
+        }
+        "#),
+        "hello world",
+    };
+
+    // NB: the builder produces the same output as naive concatenation.
+    assert! {
+        rune!(bool => r#"
+        use std::iter::range;
+        use std::string::StringBuilder;
+
+        fn main() {
+            let b = StringBuilder::with_capacity(4);
+            let s = "";
+
+            for n in range(0, 100) {
+                b.push_str(`{n},`);
+                s = s + `{n},`;
+            }
+
+            b.build() == s
+        }
+        "#),
+    };
+}
+
+#[test]
+fn test_string_builder_large() {
+    // NB: appending in place is linear in the size of the output, building a
+    // string of this size through repeated concatenation would copy gigabytes.
+    assert_eq! {
+        rune!(usize => r#"
+        use std::iter::range;
+        use std::string::StringBuilder;
+
+        fn main() {
+            let b = StringBuilder::new();
+
+            for n in range(0, 200000) {
+                b.push_str("abcdefgh");
+            }
+
+            b.len()
+        }
+        "#),
+        1_600_000,
+    };
+}
@@ -0,0 +1,86 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm};
+use std::sync::Arc;
+
+const SOURCE: &str = r#"
+fn count(n, acc) {
+    if n == 0 {
+        return acc;
+    }
+
+    let next = acc + 1;
+    count(n - 1, next)
+}
+
+fn main(n) {
+    count(n, 0)
+}
+"#;
+
+/// Run `main` to completion one instruction at a time, returning its output
+/// and the deepest the call frames got.
+fn run(tail_calls: bool, n: i64) -> (i64, usize) {
+    let context = Context::with_default_modules().unwrap();
+    let mut options = Options::default();
+    options.tail_calls(tail_calls);
+
+    let (unit, _) = compile_source_with_options(&context, &options, SOURCE).unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let mut execution = vm.call(&Item::of(&["main"]), (n,)).unwrap();
+    let mut max_frames = 0;
+
+    loop {
+        if let Some(value) = execution.step().unwrap() {
+            return (i64::from_value(value).unwrap(), max_frames);
+        }
+
+        max_frames = max_frames.max(execution.vm().unwrap().call_frames().len());
+    }
+}
+
+#[test]
+fn test_tail_calls_reuse_frame() {
+    assert_eq!(run(false, 1000), (1000, 1001));
+    assert_eq!(run(true, 1000), (1000, 1));
+}
+
+#[test]
+fn test_deep_tail_recursion() {
+    assert_eq!(run(true, 100_000), (100_000, 1));
+}
+
+#[test]
+fn test_non_tail_calls_are_kept() {
+    let context = Context::with_default_modules().unwrap();
+    let mut options = Options::default();
+    options.tail_calls(true);
+
+    let (unit, _) = compile_source_with_options(
+        &context,
+        &options,
+        r#"
+        fn sum(n) {
+            if n == 0 {
+                0
+            } else {
+                n + sum(n - 1)
+            }
+        }
+
+        fn main() {
+            sum(100)
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm
+        .call(&Item::of(&["main"]), ())
+        .unwrap()
+        .complete()
+        .unwrap();
+
+    assert_eq!(i64::from_value(output).unwrap(), 5050);
+}
@@ -38,3 +38,44 @@ fn test_unwrap() {
         Err(3),
     };
 }
+
+#[test]
+fn test_unwrap_option() {
+    assert_eq! {
+        rune! {
+            (Option<i64>, Option<i64>, Option<i64>) => r#"
+            fn add(a, b) {
+                let a = a?;
+                let b = b?;
+                Some(a + b)
+            }
+
+            fn main() {
+                (add(Some(1), Some(2)), add(None, Some(2)), add(Some(1), None))
+            }
+            "#
+        },
+        (Some(3), None, None),
+    };
+
+    assert_eq! {
+        rune! {
+            Option<i64> => r#"
+            fn first(v) {
+                let n = 0;
+
+                for value in v {
+                    n = n + value?;
+                }
+
+                Some(n)
+            }
+
+            fn main() {
+                first([Some(1), None, Some(3)])
+            }
+            "#
+        },
+        None,
+    };
+}
@@ -16,3 +16,52 @@ fn test_mutate_tuples() {
         "Now You Don't !",
     };
 }
+
+#[test]
+fn test_nested_tuple_index() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let m = ((1, 2), (3, (4, 5)));
+                m.0.1 + m.1.1.0 + ((6, 7), 8).0.1
+            }
+            "#
+        },
+        13,
+    };
+}
+
+#[test]
+fn test_dynamic_tuple_index() {
+    assert_eq! {
+        rune! {
+            i64 => r#"
+            fn main() {
+                let t = (1, 2, 3);
+                let sum = 0;
+                let i = 0;
+
+                while i < 3 {
+                    t[i] = t[i] * 10;
+                    sum = sum + t[i];
+                    i = i + 1;
+                }
+
+                sum + t.2
+            }
+            "#
+        },
+        90,
+    };
+
+    assert_vm_error!(
+        r#"fn main() { let t = (1, 2, 3); let i = 3; t[i] }"#,
+        MissingIndex { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"fn main() { let t = (1, 2, 3); let i = 3; t[i] = 4; }"#,
+        MissingIndex { .. } => {}
+    );
+}
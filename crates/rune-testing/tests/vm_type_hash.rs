@@ -0,0 +1,36 @@
+use rune_testing::*;
+use runestick::{Any, Context, FromValue as _, Hash, Item, Module, Vm};
+use std::any::TypeId;
+use std::sync::Arc;
+
+#[derive(Debug, Any)]
+struct Foo;
+
+#[test]
+fn test_type_hash() -> Result<()> {
+    let mut module = Module::new(&["native"]);
+    module.ty(&["Foo"]).build::<Foo>()?;
+    module.function(&["Foo", "new"], || Foo)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        use native::Foo;
+
+        fn main() {
+            (Foo::new(), 42)
+        }
+        "#,
+    )?;
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit));
+    let output = vm.call(&Item::of(&["main"]), ())?.complete()?;
+    let (foo, number) = <(Value, Value)>::from_value(output)?;
+
+    assert_eq!(foo.type_hash()?, Hash::from_type_id(TypeId::of::<Foo>()));
+    assert_eq!(number.type_hash()?, runestick::INTEGER_TYPE.hash);
+    Ok(())
+}
@@ -0,0 +1,461 @@
+use rune_testing::*;
+use runestick::{FromValue as _, VmErrorKind};
+
+#[test]
+fn test_binary_search() {
+    assert_eq! {
+        rune!(Result<usize, usize> => r#"fn main() { [1, 3, 5, 7].binary_search(5) }"#),
+        Ok(2),
+    };
+
+    assert_eq! {
+        rune!(Result<usize, usize> => r#"fn main() { [1, 3, 5, 7].binary_search(4) }"#),
+        Err(2),
+    };
+
+    assert_eq! {
+        rune!(Result<usize, usize> => r#"fn main() { [1, 3, 5, 7].binary_search(8) }"#),
+        Err(4),
+    };
+
+    assert_eq! {
+        rune!(Result<usize, usize> => r#"fn main() { [].binary_search(1) }"#),
+        Err(0),
+    };
+
+    assert_eq! {
+        rune!(Result<usize, usize> => r#"fn main() { ["a", "bb", "c"].binary_search("bb") }"#),
+        Ok(1),
+    };
+}
+
+#[test]
+fn test_binary_search_mismatched_types() {
+    assert_vm_error!(
+        r#"fn main() { [1, 2, 3].binary_search("2") }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                UnsupportedBinaryOperation { op, .. } => assert_eq!(*op, "cmp"),
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+}
+
+#[test]
+fn test_cmp_self_referential() {
+    assert_vm_error!(
+        r#"
+        fn main() {
+            let a = [1];
+            a.push(a);
+            let b = [1];
+            b.push(b);
+            a < b
+        }
+        "#,
+        CyclicComparison { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            let a = [1];
+            a.push(a);
+            [a].binary_search(a)
+        }
+        "#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                CyclicComparison { .. } => (),
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+    );
+
+    assert_eq! {
+        rune!(bool => r#"fn main() { let a = [1]; [a, a] <= [a, a] }"#),
+        true,
+    };
+}
+
+#[test]
+fn test_flatten() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { [[1, 2], [3], [], (4, 5)].flatten() }"#),
+        vec![1, 2, 3, 4, 5],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"fn main() { [].flatten() }"#),
+        Vec::<i64>::new(),
+    };
+
+    assert_vm_error!(
+        r#"fn main() { [[1], 2].flatten() }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), UnsupportedIterable { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_flat_map() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 3].iter().flat_map(|n| [n, n * 10]) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 10, 2, 20, 3, 30],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 4).flat_map(|n| if n % 2 == 0 { Some(n) } else { None }) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 2],
+    };
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            for n in [1].iter().flat_map(|n| n) {
+            }
+        }
+        "#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), UnsupportedIterable { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_peekable() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let it = [1, 2, 3].iter().peekable();
+            let out = [];
+
+            out.push(it.peek().unwrap_or(0));
+            out.push(it.peek().unwrap_or(0));
+            out.push(it.next().unwrap_or(0));
+            out.push(it.next().unwrap_or(0));
+            out.push(it.peek().unwrap_or(0));
+
+            for n in it {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![1, 1, 1, 2, 3, 3],
+    };
+
+    // Sum up runs of equal numbers by looking ahead.
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let it = [1, 1, 2, 3, 3, 3].iter().peekable();
+            let out = [];
+
+            loop {
+                let n = match it.next() {
+                    Some(n) => n,
+                    None => break,
+                };
+
+                let sum = n;
+
+                loop {
+                    match it.peek() {
+                        Some(m) if m == n => {
+                            sum = sum + m;
+                            it.next();
+                        }
+                        _ => break,
+                    }
+                }
+
+                out.push(sum);
+            }
+
+            out
+        }
+        "#),
+        vec![2, 2, 9],
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"
+        fn main() {
+            let it = std::iter::range(0, 1).peekable();
+            it.next();
+            it.peek()
+        }
+        "#),
+        None,
+    };
+}
+
+#[test]
+fn test_take_while() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            let seen = [];
+            let out = [];
+
+            for n in [1, 2, 3, 4, 1].iter().take_while(|n| { seen.push(n); n < 3 }) {
+                out.push(n);
+            }
+
+            (out, seen)
+        }
+        "#),
+        (vec![1, 2], vec![1, 2, 3]),
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in std::iter::range(0, 4).take_while(|n| true) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![0, 1, 2, 3],
+    };
+}
+
+#[test]
+fn test_skip_while() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>) => r#"
+        fn main() {
+            let seen = [];
+            let out = [];
+
+            for n in [1, 2, 3, 4, 1].iter().skip_while(|n| { seen.push(n); n < 3 }) {
+                out.push(n);
+            }
+
+            (out, seen)
+        }
+        "#),
+        (vec![3, 4, 1], vec![1, 2, 3]),
+    };
+
+    assert_vm_error!(
+        r#"
+        fn main() {
+            for n in [1].iter().skip_while(|n| n) {
+            }
+        }
+        "#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), Expected { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_chained_adapters() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let out = [];
+
+            for n in [1, 2, 3, 4].iter().skip_while(|n| n < 2).map(|n| n * 10) {
+                out.push(n);
+            }
+
+            out
+        }
+        "#),
+        vec![20, 30, 40],
+    };
+
+    assert_eq! {
+        rune!((Vec<i64>, Option<i64>) => r#"
+        fn main() {
+            let it = [1, 2, 3, 4, 5].iter().rev().take_while(|n| n > 1).flat_map(|n| [n, n]).peekable();
+            let out = [];
+
+            while let Some(n) = it.next() {
+                out.push(n);
+
+                if it.peek() == Some(3) {
+                    break;
+                }
+            }
+
+            (out, it.next())
+        }
+        "#),
+        (vec![5, 5, 4, 4], Some(3)),
+    };
+
+    assert_eq! {
+        rune!(Vec<String> => r#"
+        fn main() {
+            #{"a": 1, "b": 2, "c": 3}.iter().skip_while(|e| e.1 < 2).collect_object().keys()
+        }
+        "#),
+        vec![String::from("b"), String::from("c")],
+    };
+}
+
+#[test]
+fn test_group_by() {
+    assert_eq! {
+        rune!((Vec<i64>, Vec<i64>, Vec<String>) => r#"
+        fn main() {
+            let groups = [1, 2, 3, 4, 5].iter().group_by(|n| if n % 2 == 0 { "even" } else { "odd" });
+            (groups.odd, groups.even, groups.keys())
+        }
+        "#),
+        (vec![1, 3, 5], vec![2, 4], vec![String::from("odd"), String::from("even")]),
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let groups = std::iter::range(0, 7).group_by(|n| n % 3);
+            groups["1"]
+        }
+        "#),
+        vec![1, 4],
+    };
+
+    assert_vm_error!(
+        r#"fn main() { [1].iter().group_by(|n| [n]) }"#,
+        BadReturn { error, .. } => {
+            assert!(matches!(error.kind(), Expected { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_vec_push_many() {
+    // NB: pushing appends to the vector in place, so this would take
+    // prohibitively long if each push had to rebuild the vector.
+    assert_eq! {
+        rune!((usize, i64) => r#"
+        use std::iter::range;
+
+        fn main() {
+            let v = Vec::with_capacity(16);
+
+            for n in range(0, 100000) {
+                v.push(n);
+            }
+
+            let sum = 0;
+
+            for n in v {
+                sum += n;
+            }
+
+            (v.len(), sum)
+        }
+        "#),
+        (100000, 4999950000),
+    };
+}
+
+#[test]
+fn test_vec_extend() {
+    assert_eq! {
+        rune!(Vec<i64> => r#"
+        fn main() {
+            let v = [1];
+            v.extend([2, 3]);
+            v.extend((4, 5));
+            v.extend(Some(6));
+            v
+        }
+        "#),
+        vec![1, 2, 3, 4, 5, 6],
+    };
+}
+
+#[test]
+fn test_vec_borrow_conflict() {
+    // NB: the vector is exclusively borrowed as the receiver while it's being
+    // read as the argument.
+    assert_vm_error!(
+        r#"fn main() { let v = [1, 2]; v.extend(v); }"#,
+        BadReturn { error, .. } => {
+            assert_eq!(
+                error.to_string(),
+                "failed to access value: cannot read, value of type `Vec<Value>` is exclusively accessed"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_get() {
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { [1, 2, 3].get(1) }"#),
+        Some(2),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { [1, 2, 3].get(3) }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { [1, 2, 3].get(-1) }"#),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => r#"fn main() { [].get(0) }"#),
+        None,
+    };
+}
+
+#[test]
+fn test_vec_from_value() {
+    let value = rune!(Value => r#"fn main() { [1, 2, 3] }"#);
+    assert_eq!(Vec::<i64>::from_value(value).unwrap(), vec![1, 2, 3]);
+
+    let value = rune!(Value => r#"fn main() { [1, 2, "three"] }"#);
+    let error = Vec::<i64>::from_value(value).unwrap_err();
+
+    match error.kind() {
+        VmErrorKind::BadVecElement { index, error } => {
+            assert_eq!(*index, 2);
+            assert!(matches!(error.kind(), VmErrorKind::Expected { .. }));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    assert_eq!(
+        error.to_string(),
+        "failed to convert element at index `2`: expected `integer`, but found `String`"
+    );
+}
@@ -0,0 +1,47 @@
+use rune_testing::*;
+use runestick::{Context, FromValue as _, Item, Vm, Watchpoints};
+use std::sync::Arc;
+
+#[test]
+fn test_watchpoints_hit_on_change() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn main() {
+            let a = 1;
+            let b = 2;
+            b = b + 1;
+            a = 10;
+            a += 0;
+            b += 1;
+            a += 5;
+            a + b
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut watchpoints = Watchpoints::new();
+    // Watch `a`.
+    assert!(watchpoints.insert(0, 0));
+
+    let vm = Vm::new(Arc::new(context), Arc::new(unit)).with_watchpoints(watchpoints);
+    let mut execution = vm.call(&Item::of(&["main"]), ()).unwrap();
+
+    let mut hits = Vec::new();
+
+    let output = loop {
+        if let Some(output) = execution.resume_until_breakpoint().unwrap() {
+            break output;
+        }
+
+        let vm = execution.vm().unwrap();
+        assert_eq!(vm.watchpoints().unwrap().hit(), Some((0, 0)));
+        let a = vm.stack().at_offset(0).unwrap().clone();
+        hits.push(i64::from_value(a).unwrap());
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 19);
+    assert_eq!(hits, vec![10, 15]);
+}
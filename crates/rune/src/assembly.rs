@@ -1,7 +1,8 @@
 //! Helpers for building assembly.
 
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
 use crate::unit_builder::UnitBuilderError;
+use crate::Options;
 use runestick::{Hash, Inst, Label, Span};
 
 #[derive(Debug, Clone)]
@@ -122,3 +123,431 @@ impl Assembly {
         self.push(raw, span);
     }
 }
+
+impl Assembly {
+    /// Optimize the assembly according to the given options.
+    pub(crate) fn optimize(&mut self, options: &Options) {
+        if options.peephole {
+            self.peephole();
+        }
+    }
+
+    /// Perform peephole optimizations over the assembly.
+    ///
+    /// This threads jumps which target other unconditional jumps, removes
+    /// jumps to the instruction immediately following them, fuses conditional
+    /// jumps over unconditional jumps, and removes values which are pushed
+    /// only to be immediately popped.
+    pub(crate) fn peephole(&mut self) {
+        loop {
+            if self.simplify() {
+                continue;
+            }
+
+            if !self.thread_jumps() {
+                break;
+            }
+        }
+    }
+
+    /// Perform a single simplification pass, returning `true` if any
+    /// instructions were changed.
+    fn simplify(&mut self) -> bool {
+        let targets = self.labels.values().copied().collect::<HashSet<_>>();
+        let mut removed = vec![false; self.instructions.len()];
+        let mut changed = false;
+        let mut pos = 0;
+
+        while pos < self.instructions.len() {
+            let next = pos + 1;
+
+            match &self.instructions[pos].0 {
+                AssemblyInst::Jump { label } if self.labels.get(label) == Some(&next) => {
+                    removed[pos] = true;
+                    changed = true;
+                }
+                AssemblyInst::JumpIf { label } | AssemblyInst::JumpIfNot { label } => {
+                    let offset = self.labels.get(label).copied();
+
+                    // A conditional jump to the next instruction only has to
+                    // pop the condition.
+                    if offset == Some(next) {
+                        self.instructions[pos].0 = AssemblyInst::Raw { raw: Inst::Pop };
+                        changed = true;
+                        pos += 1;
+                        continue;
+                    }
+
+                    // A conditional jump over an unconditional jump can be
+                    // replaced with a single inverted conditional jump.
+                    if offset == Some(next + 1) && !targets.contains(&next) {
+                        if let Some((AssemblyInst::Jump { label: target }, _)) =
+                            self.instructions.get(next)
+                        {
+                            let label = *target;
+
+                            self.instructions[pos].0 = match &self.instructions[pos].0 {
+                                AssemblyInst::JumpIf { .. } => AssemblyInst::JumpIfNot { label },
+                                _ => AssemblyInst::JumpIf { label },
+                            };
+
+                            removed[next] = true;
+                            changed = true;
+                            pos += 2;
+                            continue;
+                        }
+                    }
+                }
+                AssemblyInst::Raw { raw } if is_pure_push(raw) => {
+                    // NB: if the pop is a jump target it can't be removed,
+                    // since it's reachable without the push.
+                    if let Some((AssemblyInst::Raw { raw: Inst::Pop }, _)) =
+                        self.instructions.get(next)
+                    {
+                        if !targets.contains(&next) {
+                            removed[pos] = true;
+                            removed[next] = true;
+                            changed = true;
+                            pos += 2;
+                            continue;
+                        }
+                    }
+                }
+                _ => (),
+            }
+
+            pos += 1;
+        }
+
+        if removed.iter().any(|removed| *removed) {
+            self.remove_instructions(&removed);
+        }
+
+        changed
+    }
+
+    /// Replace calls to the function with the given hash which are in tail
+    /// position with a jump to the start of the function, reusing the current
+    /// call frame instead of pushing a new one.
+    ///
+    /// A call is in tail position if it's only followed by unconditional jumps
+    /// and cleanups of local variables before its value is returned. The
+    /// arguments of the call are moved into the slots of the arguments of the
+    /// function, and the rest of the local variables are popped.
+    pub(crate) fn tail_calls(&mut self, hash: Hash, args: usize) -> Result<(), UnitBuilderError> {
+        let mut calls = Vec::new();
+
+        for (pos, (inst, _)) in self.instructions.iter().enumerate() {
+            match inst {
+                AssemblyInst::Raw {
+                    raw: Inst::Call { hash: h, args: a },
+                } if *h == hash && *a == args => (),
+                _ => continue,
+            }
+
+            if let Some(count) = self.tail_cleanup(pos + 1) {
+                if count >= args {
+                    calls.push((pos, count));
+                }
+            }
+        }
+
+        if calls.is_empty() {
+            return Ok(());
+        }
+
+        let start = match self.labels_rev.get(&0) {
+            Some(label) => *label,
+            None => {
+                let label = self.new_label("tail_call_start");
+                self.labels.insert(label, 0);
+                self.labels_rev.insert(0, label);
+                label
+            }
+        };
+
+        let mut replacements = HashMap::new();
+
+        for (pos, count) in calls {
+            let span = self.instructions[pos].1;
+            let mut replacement = Vec::new();
+
+            for offset in (0..args).rev() {
+                replacement.push((
+                    AssemblyInst::Raw {
+                        raw: Inst::Replace { offset },
+                    },
+                    span,
+                ));
+            }
+
+            match count - args {
+                0 => (),
+                1 => replacement.push((AssemblyInst::Raw { raw: Inst::Pop }, span)),
+                count => replacement.push((
+                    AssemblyInst::Raw {
+                        raw: Inst::PopN { count },
+                    },
+                    span,
+                )),
+            }
+
+            replacement.push((AssemblyInst::Jump { label: start }, span));
+            replacements.insert(pos, replacement);
+        }
+
+        self.replace_instructions(replacements);
+        Ok(())
+    }
+
+    /// Follow the instructions starting at `pos` through unconditional jumps
+    /// and cleanups, and return the number of values cleaned up if they end
+    /// in a return of the value on top of the stack.
+    fn tail_cleanup(&self, mut pos: usize) -> Option<usize> {
+        let mut count = 0;
+
+        // NB: bounded to protect against jump cycles.
+        for _ in 0..self.instructions.len() {
+            match &self.instructions.get(pos)?.0 {
+                AssemblyInst::Jump { label } => {
+                    pos = *self.labels.get(label)?;
+                }
+                AssemblyInst::Raw {
+                    raw: Inst::Clean { count: n },
+                } => {
+                    count += *n;
+                    pos += 1;
+                }
+                AssemblyInst::Raw { raw: Inst::Return } => return Some(count),
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    /// Retarget jumps which land on an unconditional jump to the final
+    /// destination of that jump, returning `true` if any jump was changed.
+    fn thread_jumps(&mut self) -> bool {
+        let mut targets = HashMap::new();
+
+        for (label, offset) in &self.labels {
+            if let Some((AssemblyInst::Jump { label: target }, _)) = self.instructions.get(*offset)
+            {
+                if target != label {
+                    targets.insert(*label, *target);
+                }
+            }
+        }
+
+        let mut changed = false;
+
+        for (inst, _) in &mut self.instructions {
+            let label = match inst {
+                AssemblyInst::Jump { label } => label,
+                AssemblyInst::JumpIf { label } => label,
+                AssemblyInst::JumpIfNot { label } => label,
+                AssemblyInst::JumpIfBranch { label, .. } => label,
+                AssemblyInst::PopAndJumpIfNot { label, .. } => label,
+                AssemblyInst::Raw { .. } => continue,
+            };
+
+            // NB: bounded to protect against jump cycles.
+            for _ in 0..targets.len() {
+                match targets.get(label) {
+                    Some(target) if target != label => {
+                        *label = *target;
+                        changed = true;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Replace the instructions at the given offsets with sequences of
+    /// instructions, fixing up labels and comments to point to the start of
+    /// each replacement.
+    fn replace_instructions(
+        &mut self,
+        mut replacements: HashMap<usize, Vec<(AssemblyInst, Span)>>,
+    ) {
+        let mut offsets = Vec::with_capacity(self.instructions.len() + 1);
+        let instructions = std::mem::take(&mut self.instructions);
+
+        for (pos, inst) in instructions.into_iter().enumerate() {
+            offsets.push(self.instructions.len());
+
+            match replacements.remove(&pos) {
+                Some(replacement) => self.instructions.extend(replacement),
+                None => self.instructions.push(inst),
+            }
+        }
+
+        offsets.push(self.instructions.len());
+
+        for offset in self.labels.values_mut() {
+            *offset = offsets[*offset];
+        }
+
+        self.labels_rev = std::mem::take(&mut self.labels_rev)
+            .into_iter()
+            .map(|(offset, label)| (offsets[offset], label))
+            .collect();
+
+        self.comments = std::mem::take(&mut self.comments)
+            .into_iter()
+            .map(|(offset, comments)| (offsets[offset], comments))
+            .collect();
+    }
+
+    /// Remove the instructions marked as removed, fixing up labels and
+    /// comments to point to the same logical location.
+    fn remove_instructions(&mut self, removed: &[bool]) {
+        // Maps an old offset to a new offset. Labels pointing to a removed
+        // instruction are moved to the next instruction that is kept.
+        let mut offsets = Vec::with_capacity(removed.len() + 1);
+        let mut offset = 0;
+
+        for removed in removed {
+            offsets.push(offset);
+
+            if !removed {
+                offset += 1;
+            }
+        }
+
+        offsets.push(offset);
+
+        let instructions = std::mem::take(&mut self.instructions);
+
+        self.instructions = instructions
+            .into_iter()
+            .zip(removed)
+            .filter(|(_, removed)| !**removed)
+            .map(|(inst, _)| inst)
+            .collect();
+
+        for offset in self.labels.values_mut() {
+            *offset = offsets[*offset];
+        }
+
+        self.labels_rev = std::mem::take(&mut self.labels_rev)
+            .into_iter()
+            .map(|(offset, label)| (offsets[offset], label))
+            .collect();
+
+        self.comments = std::mem::take(&mut self.comments)
+            .into_iter()
+            .filter(|(offset, _)| !removed.get(*offset).copied().unwrap_or_default())
+            .map(|(offset, comments)| (offsets[offset], comments))
+            .collect();
+    }
+}
+
+/// Test if the instruction only pushes a value onto the stack without any
+/// other side effects.
+fn is_pure_push(inst: &Inst) -> bool {
+    matches!(
+        inst,
+        Inst::Copy { .. }
+            | Inst::Dup
+            | Inst::Unit
+            | Inst::Bool { .. }
+            | Inst::Integer { .. }
+            | Inst::Float { .. }
+            | Inst::Char { .. }
+            | Inst::Byte { .. }
+            | Inst::String { .. }
+            | Inst::Bytes { .. }
+            | Inst::Type { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Assembly, AssemblyInst};
+    use runestick::{Hash, Inst, Span};
+
+    #[test]
+    fn test_peephole_push_pop() {
+        let span = Span::empty();
+        let mut asm = Assembly::new(0, 0);
+
+        let start = asm.new_label("start");
+        let end = asm.new_label("end");
+
+        asm.label(start).unwrap();
+        asm.push(Inst::Integer { number: 1 }, span);
+        asm.push(Inst::Pop, span);
+        asm.push(Inst::Bool { value: true }, span);
+        asm.jump_if(start, span);
+        asm.label(end).unwrap();
+        asm.push(Inst::ReturnUnit, span);
+
+        asm.peephole();
+
+        assert_eq!(asm.instructions.len(), 3);
+        assert_eq!(asm.labels[&start], 0);
+        assert_eq!(asm.labels[&end], 2);
+        assert!(matches!(asm.instructions[1].0, AssemblyInst::JumpIf { label } if label == start));
+    }
+
+    #[test]
+    fn test_peephole_pop_is_jump_target() {
+        let span = Span::empty();
+        let mut asm = Assembly::new(0, 0);
+
+        let pop = asm.new_label("pop");
+
+        asm.push(Inst::Integer { number: 1 }, span);
+        asm.label(pop).unwrap();
+        asm.push(Inst::Pop, span);
+        asm.jump(pop, span);
+
+        asm.peephole();
+
+        assert_eq!(asm.instructions.len(), 3);
+    }
+
+    #[test]
+    fn test_tail_calls() {
+        let span = Span::empty();
+        let hash = Hash::type_hash(&["count"]);
+        let mut asm = Assembly::new(0, 0);
+
+        let end = asm.new_label("end");
+
+        // fn count(n) { if n == 0 { n } else { count(n - 1) } }
+        asm.push(Inst::Copy { offset: 0 }, span);
+        asm.push(Inst::Integer { number: 0 }, span);
+        asm.push(Inst::Eq, span);
+        asm.jump_if_not(end, span);
+        asm.push(Inst::Copy { offset: 0 }, span);
+        asm.push(Inst::Return, span);
+        asm.label(end).unwrap();
+        asm.push(Inst::Copy { offset: 0 }, span);
+        asm.push(Inst::Integer { number: 1 }, span);
+        asm.push(Inst::Sub, span);
+        asm.push(Inst::Call { hash, args: 1 }, span);
+        asm.push(Inst::Clean { count: 1 }, span);
+        asm.push(Inst::Return, span);
+
+        asm.tail_calls(hash, 1).unwrap();
+
+        assert_eq!(asm.instructions.len(), 13);
+        assert_eq!(asm.labels[&end], 6);
+        assert!(matches!(
+            asm.instructions[9].0,
+            AssemblyInst::Raw {
+                raw: Inst::Replace { offset: 0 }
+            }
+        ));
+        assert!(matches!(
+            asm.instructions[10].0,
+            AssemblyInst::Jump { label } if asm.labels[&label] == 0
+        ));
+    }
+}
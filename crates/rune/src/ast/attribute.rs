@@ -0,0 +1,63 @@
+use crate::ast;
+use crate::ast::{Delimiter, Kind, Token};
+use crate::{Parse, ParseError, Parser, Peek, Spanned};
+use runestick::Span;
+
+/// An attribute attached to an item, like `#[test]`.
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    /// The `#` token.
+    pub hash: ast::Hash,
+    /// The opening bracket.
+    pub open: ast::OpenBracket,
+    /// The path of the attribute.
+    pub path: ast::Path,
+    /// The closing bracket.
+    pub close: ast::CloseBracket,
+}
+
+into_tokens!(Attribute {
+    hash,
+    open,
+    path,
+    close
+});
+
+impl Spanned for Attribute {
+    fn span(&self) -> Span {
+        self.hash.span().join(self.close.span())
+    }
+}
+
+impl Peek for Attribute {
+    fn peek(t1: Option<Token>, t2: Option<Token>) -> bool {
+        match (t1, t2) {
+            (Some(t1), Some(t2)) => {
+                matches!(t1.kind, Kind::Pound) && matches!(t2.kind, Kind::Open(Delimiter::Bracket))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parse implementation for an attribute.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// parse_all::<ast::Attribute>("#[test]").unwrap();
+/// parse_all::<ast::Attribute>("#[foo::bar]").unwrap();
+/// assert!(parse_all::<ast::Attribute>("#{test}").is_err());
+/// ```
+impl Parse for Attribute {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(Self {
+            hash: parser.parse()?,
+            open: parser.parse()?,
+            path: parser.parse()?,
+            close: parser.parse()?,
+        })
+    }
+}
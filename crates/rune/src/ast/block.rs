@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 
 /// A block of expressions.
@@ -20,11 +20,6 @@ into_tokens!(Block {
 });
 
 impl Block {
-    /// Get the span of the block.
-    pub fn span(&self) -> Span {
-        self.open.span().join(self.close.span())
-    }
-
     /// Test if the block expression doesn't produce a value.
     pub fn produces_nothing(&self) -> bool {
         let mut it = self.statements.iter();
@@ -54,6 +49,12 @@ impl Block {
     }
 }
 
+impl Spanned for Block {
+    fn span(&self) -> Span {
+        self.open.span().join(self.close.span())
+    }
+}
+
 /// Parse implementation for a block.
 ///
 /// # Examples
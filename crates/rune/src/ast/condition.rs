@@ -2,6 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// An if condition.
@@ -15,9 +16,8 @@ pub enum Condition {
 
 into_tokens_enum!(Condition { Expr, ExprLet });
 
-impl Condition {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for Condition {
+    fn span(&self) -> Span {
         match self {
             Self::Expr(expr) => expr.span(),
             Self::ExprLet(expr_let) => expr_let.span(),
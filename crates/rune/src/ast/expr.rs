@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser, Peek};
+use crate::{Parse, ParseError, Parser, Peek, Spanned};
 use runestick::Span;
 use std::ops;
 
@@ -67,6 +67,10 @@ pub enum Expr {
     ExprUnary(ast::ExprUnary),
     /// An index set operation.
     ExprIndexGet(ast::ExprIndexGet),
+    /// A slice operation.
+    ExprSlice(ast::ExprSlice),
+    /// A spread expression.
+    ExprSpread(ast::ExprSpread),
     /// A break expression.
     ExprBreak(ast::ExprBreak),
     /// A yield expression.
@@ -128,6 +132,8 @@ into_tokens_enum! {
         ExprBinary,
         ExprUnary,
         ExprIndexGet,
+        ExprSlice,
+        ExprSpread,
         ExprBreak,
         ExprYield,
         ExprBlock,
@@ -196,49 +202,6 @@ impl Expr {
         }
     }
 
-    /// Get the span of the expression.
-    pub fn span(&self) -> Span {
-        match self {
-            Self::Self_(s) => s.span(),
-            Self::Path(path) => path.span(),
-            Self::Item(decl) => decl.span(),
-            Self::ExprWhile(expr) => expr.span(),
-            Self::ExprLoop(expr) => expr.span(),
-            Self::ExprFor(expr) => expr.span(),
-            Self::ExprLet(expr) => expr.span(),
-            Self::ExprIndexSet(expr) => expr.span(),
-            Self::ExprIf(expr) => expr.span(),
-            Self::ExprMatch(expr) => expr.span(),
-            Self::ExprCall(expr) => expr.span(),
-            Self::MacroCall(expr) => expr.span(),
-            Self::ExprFieldAccess(expr) => expr.span(),
-            Self::ExprGroup(expr) => expr.span(),
-            Self::ExprUnary(expr) => expr.span(),
-            Self::ExprBinary(expr) => expr.span(),
-            Self::ExprIndexGet(expr) => expr.span(),
-            Self::ExprBreak(b) => b.span(),
-            Self::ExprYield(b) => b.span(),
-            Self::ExprBlock(b) => b.span(),
-            Self::ExprAsync(b) => b.span(),
-            Self::ExprReturn(ret) => ret.span(),
-            Self::ExprAwait(ret) => ret.span(),
-            Self::ExprTry(ret) => ret.span(),
-            Self::ExprSelect(ret) => ret.span(),
-            Self::ExprClosure(ret) => ret.span(),
-            Self::LitUnit(unit) => unit.span(),
-            Self::LitBool(b) => b.span(),
-            Self::LitVec(expr) => expr.span(),
-            Self::LitObject(expr) => expr.span(),
-            Self::LitTuple(expr) => expr.span(),
-            Self::LitNumber(expr) => expr.span(),
-            Self::LitByte(expr) => expr.span(),
-            Self::LitChar(expr) => expr.span(),
-            Self::LitStr(expr) => expr.span(),
-            Self::LitByteStr(expr) => expr.span(),
-            Self::LitTemplate(expr) => expr.span(),
-        }
-    }
-
     /// Test if the entire expression is constant.
     pub fn is_const(&self) -> bool {
         match self {
@@ -393,6 +356,7 @@ impl Expr {
             ast::Kind::Break => Self::ExprBreak(parser.parse()?),
             ast::Kind::Yield => Self::ExprYield(parser.parse()?),
             ast::Kind::Return => Self::ExprReturn(parser.parse()?),
+            ast::Kind::DotDot => Self::ExprSpread(parser.parse()?),
             _ => {
                 return Err(ParseError::ExpectedExpr {
                     actual: token.kind,
@@ -415,10 +379,43 @@ impl Expr {
 
             match token.kind {
                 ast::Kind::Open(ast::Delimiter::Bracket) if is_chainable => {
+                    let target = Box::new(expr);
+                    let open = parser.parse()?;
+
+                    let from = if parser.peek::<ast::DotDot>()? {
+                        None
+                    } else {
+                        Some(Box::new(parser.parse()?))
+                    };
+
+                    let index = match from {
+                        Some(index) if !parser.peek::<ast::DotDot>()? => index,
+                        from => {
+                            let dot_dot = parser.parse()?;
+
+                            let to = if parser.peek::<ast::CloseBracket>()? {
+                                None
+                            } else {
+                                Some(Box::new(parser.parse()?))
+                            };
+
+                            expr = Self::ExprSlice(ast::ExprSlice {
+                                target,
+                                open,
+                                from,
+                                dot_dot,
+                                to,
+                                close: parser.parse()?,
+                            });
+
+                            continue;
+                        }
+                    };
+
                     let index_get = ast::ExprIndexGet {
-                        target: Box::new(expr),
-                        open: parser.parse()?,
-                        index: parser.parse()?,
+                        target,
+                        open,
+                        index,
                         close: parser.parse()?,
                     };
 
@@ -558,6 +555,52 @@ impl Expr {
     }
 }
 
+impl Spanned for Expr {
+    fn span(&self) -> Span {
+        match self {
+            Self::Self_(s) => s.span(),
+            Self::Path(path) => path.span(),
+            Self::Item(decl) => decl.span(),
+            Self::ExprWhile(expr) => expr.span(),
+            Self::ExprLoop(expr) => expr.span(),
+            Self::ExprFor(expr) => expr.span(),
+            Self::ExprLet(expr) => expr.span(),
+            Self::ExprIndexSet(expr) => expr.span(),
+            Self::ExprIf(expr) => expr.span(),
+            Self::ExprMatch(expr) => expr.span(),
+            Self::ExprCall(expr) => expr.span(),
+            Self::MacroCall(expr) => expr.span(),
+            Self::ExprFieldAccess(expr) => expr.span(),
+            Self::ExprGroup(expr) => expr.span(),
+            Self::ExprUnary(expr) => expr.span(),
+            Self::ExprBinary(expr) => expr.span(),
+            Self::ExprIndexGet(expr) => expr.span(),
+            Self::ExprSlice(expr) => expr.span(),
+            Self::ExprSpread(expr) => expr.span(),
+            Self::ExprBreak(b) => b.span(),
+            Self::ExprYield(b) => b.span(),
+            Self::ExprBlock(b) => b.span(),
+            Self::ExprAsync(b) => b.span(),
+            Self::ExprReturn(ret) => ret.span(),
+            Self::ExprAwait(ret) => ret.span(),
+            Self::ExprTry(ret) => ret.span(),
+            Self::ExprSelect(ret) => ret.span(),
+            Self::ExprClosure(ret) => ret.span(),
+            Self::LitUnit(unit) => unit.span(),
+            Self::LitBool(b) => b.span(),
+            Self::LitVec(expr) => expr.span(),
+            Self::LitObject(expr) => expr.span(),
+            Self::LitTuple(expr) => expr.span(),
+            Self::LitNumber(expr) => expr.span(),
+            Self::LitByte(expr) => expr.span(),
+            Self::LitChar(expr) => expr.span(),
+            Self::LitStr(expr) => expr.span(),
+            Self::LitByteStr(expr) => expr.span(),
+            Self::LitTemplate(expr) => expr.span(),
+        }
+    }
+}
+
 /// Parsing a block expression.
 ///
 /// # Examples
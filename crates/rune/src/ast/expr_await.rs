@@ -2,6 +2,7 @@ use crate::ast::{Await, Dot, Expr};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A return statement `<expr>.await`.
@@ -17,9 +18,8 @@ pub struct ExprAwait {
 
 into_tokens!(ExprAwait { expr, dot, await_ });
 
-impl ExprAwait {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprAwait {
+    fn span(&self) -> Span {
         self.expr.span().join(self.await_.span())
     }
 }
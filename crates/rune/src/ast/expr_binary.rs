@@ -1,5 +1,6 @@
 use crate::ast;
 use crate::traits::Peek;
+use crate::Spanned;
 use runestick::Span;
 use std::fmt;
 
@@ -27,17 +28,18 @@ impl ExprBinary {
         matches!(self.op, BinOp::Assign { .. })
     }
 
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.lhs.span().join(self.rhs.span())
-    }
-
     /// Test if the expression is a constant expression.
     pub fn is_const(&self) -> bool {
         self.lhs.is_const() && self.rhs.is_const()
     }
 }
 
+impl Spanned for ExprBinary {
+    fn span(&self) -> Span {
+        self.lhs.span().join(self.rhs.span())
+    }
+}
+
 /// A binary operation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BinOp {
@@ -67,10 +69,15 @@ pub enum BinOp {
     Is,
     /// Negated instance of test `a is not b`.
     IsNot,
+    /// Containment test `a in b`.
+    In,
     /// Lazy and operator `&&`.
     And,
     /// Lazy or operator `||`.
     Or,
+    /// Lazy null-coalescing operator `a ?? b`, which evaluates to the value
+    /// inside of `a` if it's `Some` or `Ok`, and otherwise evaluates to `b`.
+    NullCoalesce,
     /// Bitwise left shift operator `a << b`.
     Shl,
     /// Bitwise right shift operator `a >> b`.
@@ -128,16 +135,17 @@ impl BinOp {
     pub(super) fn precedence(self) -> usize {
         // NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
         match self {
-            Self::Is | Self::IsNot => 11,
-            Self::Mul | Self::Div | Self::Rem => 10,
-            Self::Add | Self::Sub => 9,
-            Self::Shl | Self::Shr => 8,
-            Self::BitAnd => 7,
-            Self::BitXor => 6,
-            Self::BitOr => 5,
-            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Lte | Self::Gte => 4,
-            Self::And => 3,
-            Self::Or => 2,
+            Self::Is | Self::IsNot => 12,
+            Self::Mul | Self::Div | Self::Rem => 11,
+            Self::Add | Self::Sub => 10,
+            Self::Shl | Self::Shr => 9,
+            Self::BitAnd => 8,
+            Self::BitXor => 7,
+            Self::BitOr => 6,
+            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Lte | Self::Gte | Self::In => 5,
+            Self::And => 4,
+            Self::Or => 3,
+            Self::NullCoalesce => 2,
             // assign operators
             _ => 1,
         }
@@ -181,9 +189,11 @@ impl BinOp {
 
                 Self::Is
             }
+            ast::Kind::In => Self::In,
             ast::Kind::Eq => Self::Assign,
             ast::Kind::AmpAmp => Self::And,
             ast::Kind::PipePipe => Self::Or,
+            ast::Kind::QuestionQuestion => Self::NullCoalesce,
             ast::Kind::LtLt => Self::Shl,
             ast::Kind::GtGt => Self::Shr,
             ast::Kind::Amp => Self::BitAnd,
@@ -230,9 +240,11 @@ impl fmt::Display for BinOp {
             Self::Lte => write!(f, "<="),
             Self::Is => write!(f, "is"),
             Self::IsNot => write!(f, "is not"),
+            Self::In => write!(f, "in"),
             Self::Assign => write!(f, "="),
             Self::And => write!(f, "&&"),
             Self::Or => write!(f, "||"),
+            Self::NullCoalesce => write!(f, "??"),
             Self::Shl => write!(f, "<<"),
             Self::Shr => write!(f, ">>"),
             Self::BitAnd => write!(f, "&"),
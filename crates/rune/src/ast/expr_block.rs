@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 
 /// A block of expressions.
@@ -12,11 +12,6 @@ pub struct ExprBlock {
 into_tokens!(ExprBlock { block });
 
 impl ExprBlock {
-    /// Get the span of the block.
-    pub fn span(&self) -> Span {
-        self.block.span()
-    }
-
     /// Test if the block expression doesn't produce a value.
     pub fn produces_nothing(&self) -> bool {
         self.block.produces_nothing()
@@ -28,6 +23,12 @@ impl ExprBlock {
     }
 }
 
+impl Spanned for ExprBlock {
+    fn span(&self) -> Span {
+        self.block.span()
+    }
+}
+
 impl Parse for ExprBlock {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         Ok(Self {
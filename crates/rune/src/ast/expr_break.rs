@@ -2,6 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::{Parse, Peek};
+use crate::Spanned;
 use runestick::Span;
 
 /// A return statement `break [expr]`.
@@ -15,9 +16,8 @@ pub struct ExprBreak {
 
 into_tokens!(ExprBreak { break_, expr });
 
-impl ExprBreak {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprBreak {
+    fn span(&self) -> Span {
         if let Some(expr) = &self.expr {
             self.break_.span().join(expr.span())
         } else {
@@ -46,9 +46,8 @@ pub enum ExprBreakValue {
 
 into_tokens_enum!(ExprBreakValue { Expr, Label });
 
-impl ExprBreakValue {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprBreakValue {
+    fn span(&self) -> Span {
         match self {
             Self::Expr(expr) => expr.span(),
             Self::Label(label) => label.span(),
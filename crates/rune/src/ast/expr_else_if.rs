@@ -2,6 +2,7 @@ use crate::ast::{Condition, Else, ExprBlock, If};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// An else branch of an if expression.
@@ -24,9 +25,8 @@ into_tokens!(ExprElseIf {
     block
 });
 
-impl ExprElseIf {
-    /// Access the span for the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprElseIf {
+    fn span(&self) -> Span {
         self.else_.span().join(self.block.span())
     }
 }
@@ -1,4 +1,5 @@
 use crate::ast;
+use crate::Spanned;
 use runestick::Span;
 
 /// The field being accessed.
@@ -12,9 +13,8 @@ pub enum ExprField {
 
 into_tokens_enum!(ExprField { Ident, LitNumber });
 
-impl ExprField {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprField {
+    fn span(&self) -> Span {
         match self {
             Self::Ident(ident) => ident.span(),
             Self::LitNumber(n) => n.span(),
@@ -39,9 +39,8 @@ into_tokens!(ExprFieldAccess {
     expr_field
 });
 
-impl ExprFieldAccess {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprFieldAccess {
+    fn span(&self) -> Span {
         self.expr.span().join(self.expr_field.span())
     }
 }
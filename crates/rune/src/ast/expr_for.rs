@@ -1,7 +1,8 @@
-use crate::ast::{Colon, Expr, ExprBlock, For, Ident, In, Label};
+use crate::ast::{Await, Colon, Expr, ExprBlock, For, Ident, In, Label};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -11,6 +12,9 @@ pub struct ExprFor {
     pub label: Option<(Label, Colon)>,
     /// The `for` keyword.
     pub for_: For,
+    /// The optional `await` keyword, which makes this a loop over an
+    /// asynchronous stream.
+    pub await_: Option<Await>,
     /// The variable binding.
     /// TODO: should be a pattern when that is supported.
     pub var: Ident,
@@ -25,6 +29,7 @@ pub struct ExprFor {
 into_tokens!(ExprFor {
     label,
     for_,
+    await_,
     var,
     in_,
     iter,
@@ -32,11 +37,6 @@ into_tokens!(ExprFor {
 });
 
 impl ExprFor {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.for_.token.span.join(self.body.span())
-    }
-
     /// Parse with the given label.
     pub fn parse_with_label(
         parser: &mut Parser<'_>,
@@ -45,6 +45,7 @@ impl ExprFor {
         Ok(Self {
             label,
             for_: parser.parse()?,
+            await_: parser.parse()?,
             var: parser.parse()?,
             in_: parser.parse()?,
             iter: Box::new(Expr::parse_without_eager_brace(parser)?),
@@ -53,6 +54,24 @@ impl ExprFor {
     }
 }
 
+impl Spanned for ExprFor {
+    fn span(&self) -> Span {
+        self.for_.token.span.join(self.body.span())
+    }
+}
+
+/// Parse a for loop.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// parse_all::<ast::ExprFor>("for x in xs {}").unwrap();
+///
+/// let expr = parse_all::<ast::ExprFor>("for await x in stream {}").unwrap();
+/// assert!(expr.await_.is_some());
+/// ```
 impl Parse for ExprFor {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         let label = if parser.peek::<Label>()? {
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A prioritized expression group `(<expr>)`.
@@ -18,17 +19,18 @@ pub struct ExprGroup {
 into_tokens!(ExprGroup { open, expr, close });
 
 impl ExprGroup {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.open.span().join(self.close.span())
-    }
-
     /// Check if expression is empty.
     pub fn produces_nothing(&self) -> bool {
         self.expr.produces_nothing()
     }
 }
 
+impl Spanned for ExprGroup {
+    fn span(&self) -> Span {
+        self.open.span().join(self.close.span())
+    }
+}
+
 impl Parse for ExprGroup {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         Ok(Self {
@@ -2,6 +2,7 @@ use crate::ast::{Condition, Else, ExprBlock, ExprElse, ExprElseIf, If};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// An if expression.
@@ -28,8 +29,14 @@ into_tokens!(ExprIf {
 });
 
 impl ExprIf {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+    /// An if statement evaluates to empty if it does not have an else branch.
+    pub fn produces_nothing(&self) -> bool {
+        self.expr_else.is_none()
+    }
+}
+
+impl Spanned for ExprIf {
+    fn span(&self) -> Span {
         if let Some(else_) = &self.expr_else {
             self.if_.token.span.join(else_.block.span())
         } else if let Some(else_if) = self.expr_else_ifs.last() {
@@ -38,11 +45,6 @@ impl ExprIf {
             self.if_.token.span.join(self.block.span())
         }
     }
-
-    /// An if statement evaluates to empty if it does not have an else branch.
-    pub fn produces_nothing(&self) -> bool {
-        self.expr_else.is_none()
-    }
 }
 
 /// Parse an if statement.
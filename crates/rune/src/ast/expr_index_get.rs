@@ -1,4 +1,5 @@
 use crate::ast::{CloseBracket, Expr, OpenBracket};
+use crate::Spanned;
 use runestick::Span;
 
 /// An index get operation `<target>[<index>]`.
@@ -21,9 +22,8 @@ into_tokens!(ExprIndexGet {
     close
 });
 
-impl ExprIndexGet {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprIndexGet {
+    fn span(&self) -> Span {
         self.target.span().join(self.close.span())
     }
 }
@@ -1,4 +1,5 @@
 use crate::ast::{CloseBracket, Eq, Expr, OpenBracket};
+use crate::Spanned;
 use runestick::Span;
 
 /// An index set operation `<target>[<index>] = <value>`.
@@ -27,9 +28,8 @@ into_tokens!(ExprIndexSet {
     value
 });
 
-impl ExprIndexSet {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprIndexSet {
+    fn span(&self) -> Span {
         self.target.span().join(self.value.span())
     }
 }
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// An is expression.
@@ -25,17 +26,18 @@ impl ExprIsNot {
         false
     }
 
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.lhs.span().join(self.rhs.span())
-    }
-
     /// Test if the expression is a constant expression.
     pub fn is_const(&self) -> bool {
         self.lhs.is_const() && self.rhs.is_const()
     }
 }
 
+impl Spanned for ExprIsNot {
+    fn span(&self) -> Span {
+        self.lhs.span().join(self.rhs.span())
+    }
+}
+
 impl Parse for ExprIsNot {
     fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
         Ok(Self {
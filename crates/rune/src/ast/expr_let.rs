@@ -2,6 +2,7 @@ use crate::ast::{Eq, Expr, Let, Pat};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -25,11 +26,6 @@ into_tokens!(ExprLet {
 });
 
 impl ExprLet {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.let_.token.span.join(self.expr.span())
-    }
-
     /// Parse a let expression without eager bracing.
     pub fn parse_without_eager_brace(parser: &mut Parser) -> Result<Self, ParseError> {
         Ok(Self {
@@ -41,6 +37,12 @@ impl ExprLet {
     }
 }
 
+impl Spanned for ExprLet {
+    fn span(&self) -> Span {
+        self.let_.token.span.join(self.expr.span())
+    }
+}
+
 impl Parse for ExprLet {
     fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
         Ok(Self {
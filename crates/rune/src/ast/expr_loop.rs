@@ -2,6 +2,7 @@ use crate::ast::{Colon, ExprBlock, Label, Loop};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -18,11 +19,6 @@ pub struct ExprLoop {
 into_tokens!(ExprLoop { label, loop_, body });
 
 impl ExprLoop {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.loop_.token.span.join(self.body.span())
-    }
-
     /// Parse with the given label.
     pub fn parse_with_label(
         parser: &mut Parser<'_>,
@@ -36,6 +32,12 @@ impl ExprLoop {
     }
 }
 
+impl Spanned for ExprLoop {
+    fn span(&self) -> Span {
+        self.loop_.token.span.join(self.body.span())
+    }
+}
+
 impl Parse for ExprLoop {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         let label = if parser.peek::<Label>()? {
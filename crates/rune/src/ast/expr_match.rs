@@ -3,6 +3,7 @@ use crate::ast::{CloseBrace, Comma, Expr, If, Match, OpenBrace, Pat, Rocket};
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A match expression.
@@ -28,9 +29,8 @@ into_tokens!(ExprMatch {
     close
 });
 
-impl ExprMatch {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprMatch {
+    fn span(&self) -> Span {
         self.match_.span().join(self.close.span())
     }
 }
@@ -103,17 +103,18 @@ into_tokens!(ExprMatchBranch {
 });
 
 impl ExprMatchBranch {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.pat.span().join(self.body.span())
-    }
-
     /// Test if the branch produces nothing.
     pub fn produces_nothing(&self) -> bool {
         self.body.produces_nothing()
     }
 }
 
+impl Spanned for ExprMatchBranch {
+    fn span(&self) -> Span {
+        self.pat.span().join(self.body.span())
+    }
+}
+
 /// Parse a match statement.
 ///
 /// # Examples
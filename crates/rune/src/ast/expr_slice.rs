@@ -0,0 +1,35 @@
+use crate::ast::{CloseBracket, DotDot, Expr, OpenBracket};
+use crate::Spanned;
+use runestick::Span;
+
+/// A slice operation `<target>[<from>..<to>]`, where both bounds are optional.
+#[derive(Debug, Clone)]
+pub struct ExprSlice {
+    /// The target being sliced.
+    pub target: Box<Expr>,
+    /// The opening bracket.
+    pub open: OpenBracket,
+    /// The inclusive start of the slice.
+    pub from: Option<Box<Expr>>,
+    /// The `..` separating the bounds.
+    pub dot_dot: DotDot,
+    /// The exclusive end of the slice.
+    pub to: Option<Box<Expr>>,
+    /// The closening bracket.
+    pub close: CloseBracket,
+}
+
+into_tokens!(ExprSlice {
+    target,
+    open,
+    from,
+    dot_dot,
+    to,
+    close
+});
+
+impl Spanned for ExprSlice {
+    fn span(&self) -> Span {
+        self.target.span().join(self.close.span())
+    }
+}
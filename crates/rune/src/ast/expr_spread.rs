@@ -0,0 +1,42 @@
+use crate::ast;
+use crate::{Parse, ParseError, Parser, Spanned};
+use runestick::Span;
+
+/// A spread expression `..<expr>`.
+///
+/// Spreads are only supported as items in vector literals, like `[..a, 4]`,
+/// and as arguments to function calls, like `f(..args)`.
+#[derive(Debug, Clone)]
+pub struct ExprSpread {
+    /// The `..` token.
+    pub dot_dot: ast::DotDot,
+    /// The expression being spread.
+    pub expr: Box<ast::Expr>,
+}
+
+into_tokens!(ExprSpread { dot_dot, expr });
+
+impl Spanned for ExprSpread {
+    fn span(&self) -> Span {
+        self.dot_dot.span().join(self.expr.span())
+    }
+}
+
+/// Parse a spread expression.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// parse_all::<ast::ExprSpread>("..a").unwrap();
+/// parse_all::<ast::ExprSpread>("..[1, 2]").unwrap();
+/// ```
+impl Parse for ExprSpread {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(Self {
+            dot_dot: parser.parse()?,
+            expr: Box::new(parser.parse()?),
+        })
+    }
+}
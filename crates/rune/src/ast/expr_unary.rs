@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::ast::expr::{EagerBrace, ExprChain};
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 use std::fmt;
 
@@ -17,9 +17,8 @@ pub struct ExprUnary {
 
 into_tokens!(ExprUnary { token, expr });
 
-impl ExprUnary {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for ExprUnary {
+    fn span(&self) -> Span {
         self.token.span.join(self.expr.span())
     }
 }
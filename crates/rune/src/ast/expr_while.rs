@@ -2,6 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
+use crate::Spanned;
 use runestick::Span;
 
 /// A let expression `let <name> = <expr>;`
@@ -25,11 +26,6 @@ into_tokens!(ExprWhile {
 });
 
 impl ExprWhile {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.while_.token.span.join(self.body.span())
-    }
-
     /// Parse with the given label.
     pub fn parse_with_label(
         parser: &mut Parser<'_>,
@@ -44,6 +40,12 @@ impl ExprWhile {
     }
 }
 
+impl Spanned for ExprWhile {
+    fn span(&self) -> Span {
+        self.while_.token.span.join(self.body.span())
+    }
+}
+
 impl Parse for ExprWhile {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         let label = if parser.peek::<ast::Label>()? {
@@ -1,5 +1,6 @@
 use crate::ast;
 use crate::{IntoTokens, Parse, ParseError, Parser};
+use runestick::Span;
 
 /// A parsed file.
 #[derive(Debug, Clone)]
@@ -67,6 +68,122 @@ impl Parse for File {
     }
 }
 
+impl File {
+    /// Parse a file, recovering from errors at item boundaries so that
+    /// multiple errors can be collected in one pass.
+    ///
+    /// After an error the remainder of the offending item is skipped, and
+    /// parsing resumes after the first `;` or `}` which brings the item back to
+    /// the top level, or before the next `fn` keyword. Items which failed to
+    /// parse are left out of the returned file.
+    pub(crate) fn parse_with_recovery(source: &str) -> (Self, Vec<ParseError>) {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let mut parser = Parser::new_with_start(source, offset);
+
+            let (start, error) = match Self::parse_items(&mut parser, &mut items) {
+                Ok(()) => break,
+                Err(error) => error,
+            };
+
+            let error_span = error.span();
+            errors.push(error);
+
+            match Self::resync(source, start, error_span) {
+                Some(next) if next > offset => offset = next,
+                _ => break,
+            }
+        }
+
+        (Self { items }, errors)
+    }
+
+    /// Parse items until end-of-file, returning the offset of the item that
+    /// failed to parse together with the error if any.
+    fn parse_items(
+        parser: &mut Parser<'_>,
+        items: &mut Vec<(ast::Item, Option<ast::SemiColon>)>,
+    ) -> Result<(), (usize, ParseError)> {
+        loop {
+            let token = match parser.token_peek() {
+                Ok(Some(token)) => token,
+                Ok(None) => return Ok(()),
+                Err(error) => return Err((error.span().start, error)),
+            };
+
+            let start = token.span.start;
+            let with_start = |error| (start, error);
+
+            if !parser.peek::<ast::Item>().map_err(with_start)? {
+                return Err(with_start(ParseError::ExpectedEof {
+                    span: token.span,
+                    actual: token.kind,
+                }));
+            }
+
+            let item: ast::Item = parser.parse().map_err(with_start)?;
+
+            let needs_semi_colon =
+                item.needs_semi_colon() || parser.peek::<ast::SemiColon>().map_err(with_start)?;
+
+            let semi_colon = if needs_semi_colon {
+                Some(parser.parse::<ast::SemiColon>().map_err(with_start)?)
+            } else {
+                None
+            };
+
+            items.push((item, semi_colon));
+        }
+    }
+
+    /// Find the offset to resume parsing at after the item starting at
+    /// `start` failed to parse with an error at `error_span`.
+    fn resync(source: &str, start: usize, error_span: Span) -> Option<usize> {
+        let mut parser = Parser::new_with_start(source, start);
+        let mut depth = 0usize;
+        let mut first = true;
+        // The first `fn` after the error, used if the item never returns to
+        // the top level, like when a closing brace is missing.
+        let mut fallback = None;
+
+        loop {
+            let token = match parser.token_next() {
+                Ok(token) => token,
+                Err(ParseError::UnexpectedEof { .. }) => return fallback,
+                // NB: skip past tokens which can't be lexed.
+                Err(error) => return Some(error.span().end),
+            };
+
+            match token.kind {
+                ast::Kind::Fn if !first => {
+                    if depth == 0 {
+                        return Some(token.span.start);
+                    }
+
+                    if fallback.is_none() && token.span.start >= error_span.end {
+                        fallback = Some(token.span.start);
+                    }
+                }
+                ast::Kind::SemiColon if depth == 0 => return Some(token.span.end),
+                ast::Kind::Open(ast::Delimiter::Brace) => depth += 1,
+                ast::Kind::Close(ast::Delimiter::Brace) => {
+                    depth = depth.saturating_sub(1);
+
+                    if depth == 0 {
+                        return Some(token.span.end);
+                    }
+                }
+                _ => (),
+            }
+
+            first = false;
+        }
+    }
+}
+
 impl IntoTokens for File {
     fn into_tokens(&self, context: &mut crate::MacroContext, stream: &mut crate::TokenStream) {
         self.items.into_tokens(context, stream);
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser, Peek, Resolve, Storage};
+use crate::{Parse, ParseError, Parser, Peek, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -13,8 +13,31 @@ pub struct Ident {
 }
 
 impl Ident {
-    /// Access the span of the identifier.
-    pub fn span(&self) -> Span {
+    /// Resolve the name of the local variable this identifier refers to.
+    ///
+    /// This is the same as the resolved identifier, except for hygienic
+    /// identifiers introduced by a macro expansion. These are given a name
+    /// which can't be written in source, so that they can't collide with
+    /// variables declared anywhere else.
+    pub fn resolve_local<'a>(
+        &self,
+        storage: &Storage,
+        source: &'a Source,
+    ) -> Result<Cow<'a, str>, ParseError> {
+        let ident = self.resolve(storage, source)?;
+
+        if let ast::StringSource::Synthetic(id) = self.kind {
+            if let Some(mark) = storage.get_hygiene_mark(id) {
+                return Ok(Cow::Owned(format!("{}#{}", ident, mark)));
+            }
+        }
+
+        Ok(ident)
+    }
+}
+
+impl Spanned for Ident {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::parser::Parser;
 use crate::traits::{Parse, Peek};
 use crate::ParseError;
+use crate::Spanned;
 use runestick::Span;
 
 /// A declaration.
@@ -20,6 +21,8 @@ pub enum Item {
     ItemStruct(ast::ItemStruct),
     /// An impl declaration.
     ItemImpl(ast::ItemImpl),
+    /// A static declaration.
+    ItemStatic(ast::ItemStatic),
     /// A module declaration.
     ItemMod(ast::ItemMod),
     /// A macro call expanding into an item.
@@ -32,24 +35,12 @@ into_tokens_enum!(Item {
     ItemEnum,
     ItemStruct,
     ItemImpl,
+    ItemStatic,
     ItemMod,
     MacroCall
 });
 
 impl Item {
-    /// The span of the declaration.
-    pub fn span(&self) -> Span {
-        match self {
-            Self::ItemUse(decl) => decl.span(),
-            Self::ItemFn(decl) => decl.span(),
-            Self::ItemEnum(decl) => decl.span(),
-            Self::ItemStruct(decl) => decl.span(),
-            Self::ItemImpl(decl) => decl.span(),
-            Self::ItemMod(decl) => decl.span(),
-            Self::MacroCall(expr) => expr.span(),
-        }
-    }
-
     /// Indicates if the declaration needs a semi-colon or not.
     pub fn needs_semi_colon(&self) -> bool {
         matches!(self, Self::MacroCall(..))
@@ -57,6 +48,10 @@ impl Item {
 
     /// Test if declaration is suitable inside of a block.
     pub fn peek_as_stmt(parser: &mut Parser<'_>) -> Result<bool, ParseError> {
+        if parser.peek::<ast::Attribute>()? {
+            return Ok(true);
+        }
+
         let t1 = parser.token_peek()?;
 
         let t1 = match t1 {
@@ -69,6 +64,7 @@ impl Item {
             ast::Kind::Enum => true,
             ast::Kind::Struct => true,
             ast::Kind::Impl => true,
+            ast::Kind::Static => true,
             ast::Kind::Async | ast::Kind::Fn => true,
             ast::Kind::Mod => true,
             _ => false,
@@ -76,8 +72,27 @@ impl Item {
     }
 }
 
+impl Spanned for Item {
+    fn span(&self) -> Span {
+        match self {
+            Self::ItemUse(decl) => decl.span(),
+            Self::ItemFn(decl) => decl.span(),
+            Self::ItemEnum(decl) => decl.span(),
+            Self::ItemStruct(decl) => decl.span(),
+            Self::ItemImpl(decl) => decl.span(),
+            Self::ItemStatic(decl) => decl.span(),
+            Self::ItemMod(decl) => decl.span(),
+            Self::MacroCall(expr) => expr.span(),
+        }
+    }
+}
+
 impl Peek for Item {
-    fn peek(t1: Option<ast::Token>, _: Option<ast::Token>) -> bool {
+    fn peek(t1: Option<ast::Token>, t2: Option<ast::Token>) -> bool {
+        if ast::Attribute::peek(t1, t2) {
+            return true;
+        }
+
         let t1 = match t1 {
             Some(t1) => t1,
             None => return false,
@@ -88,6 +103,7 @@ impl Peek for Item {
             ast::Kind::Enum => true,
             ast::Kind::Struct => true,
             ast::Kind::Impl => true,
+            ast::Kind::Static => true,
             ast::Kind::Async | ast::Kind::Fn => true,
             ast::Kind::Mod => true,
             ast::Kind::Ident(..) => true,
@@ -98,6 +114,11 @@ impl Peek for Item {
 
 impl Parse for Item {
     fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        // NB: attributes are currently only supported on functions.
+        if parser.peek::<ast::Attribute>()? {
+            return Ok(Self::ItemFn(parser.parse()?));
+        }
+
         let t = parser.token_peek_eof()?;
 
         Ok(match t.kind {
@@ -105,6 +126,7 @@ impl Parse for Item {
             ast::Kind::Enum => Self::ItemEnum(parser.parse()?),
             ast::Kind::Struct => Self::ItemStruct(parser.parse()?),
             ast::Kind::Impl => Self::ItemImpl(parser.parse()?),
+            ast::Kind::Static => Self::ItemStatic(parser.parse()?),
             ast::Kind::Async | ast::Kind::Fn => Self::ItemFn(parser.parse()?),
             ast::Kind::Mod => Self::ItemMod(parser.parse()?),
             ast::Kind::Ident(..) => Self::MacroCall(parser.parse()?),
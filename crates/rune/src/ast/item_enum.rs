@@ -2,7 +2,7 @@ use crate::ast;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::traits::Parse;
-use crate::{IntoTokens, MacroContext, TokenStream};
+use crate::{IntoTokens, MacroContext, Spanned, TokenStream};
 use runestick::Span;
 
 /// An enum declaration.
@@ -20,9 +20,8 @@ pub struct ItemEnum {
     pub close: ast::CloseBrace,
 }
 
-impl ItemEnum {
-    /// Access the span for the enum declaration.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemEnum {
+    fn span(&self) -> Span {
         self.enum_.span().join(self.close.span())
     }
 }
@@ -109,7 +108,7 @@ pub enum ItemEnumVariant {
 /// ```rust
 /// use rune::{parse_all, ast};
 ///
-/// parse_all::<ast::ItemEnumVariant>("( a, b, c );").unwrap();
+/// parse_all::<ast::ItemEnumVariant>("( a, b, c )").unwrap();
 /// parse_all::<ast::ItemEnumVariant>("{ a, b, c }").unwrap();
 /// ```
 impl Parse for ItemEnumVariant {
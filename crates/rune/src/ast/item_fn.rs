@@ -2,12 +2,14 @@ use crate::ast;
 use crate::ast::{Kind, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::{IntoTokens, Parse, Peek};
+use crate::{IntoTokens, Parse, Peek, Spanned};
 use runestick::Span;
 
 /// A function.
 #[derive(Debug, Clone)]
 pub struct ItemFn {
+    /// Attributes attached to the function, like `#[test]`.
+    pub attributes: Vec<ast::Attribute>,
     /// The optional `async` keyword.
     pub async_: Option<ast::Async>,
     /// The `fn` token.
@@ -30,23 +32,30 @@ impl ItemFn {
         }
     }
 
-    /// Access the span for the function declaration.
-    pub fn span(&self) -> Span {
-        if let Some(async_) = &self.async_ {
+    /// Test if function is an instance fn.
+    pub fn is_instance(&self) -> bool {
+        matches!(self.args.items.first(), Some((ast::FnArg::Self_(..), _)))
+    }
+}
+
+impl Spanned for ItemFn {
+    fn span(&self) -> Span {
+        if let Some(attribute) = self.attributes.first() {
+            attribute.span().join(self.body.span())
+        } else if let Some(async_) = &self.async_ {
             async_.span().join(self.body.span())
         } else {
             self.fn_.span().join(self.body.span())
         }
     }
-
-    /// Test if function is an instance fn.
-    pub fn is_instance(&self) -> bool {
-        matches!(self.args.items.first(), Some((ast::FnArg::Self_(..), _)))
-    }
 }
 
 impl Peek for ItemFn {
-    fn peek(t1: Option<Token>, _: Option<Token>) -> bool {
+    fn peek(t1: Option<Token>, t2: Option<Token>) -> bool {
+        if ast::Attribute::peek(t1, t2) {
+            return true;
+        }
+
         let t = match t1 {
             Some(t) => t,
             None => return false,
@@ -71,10 +80,14 @@ impl Peek for ItemFn {
 ///
 /// let item = parse_all::<ast::ItemFn>("fn hello(foo, bar) {}").unwrap();
 /// assert_eq!(item.args.items.len(), 2);
+///
+/// let item = parse_all::<ast::ItemFn>("#[test] fn hello() {}").unwrap();
+/// assert_eq!(item.attributes.len(), 1);
 /// ```
 impl Parse for ItemFn {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
         Ok(Self {
+            attributes: parser.parse()?,
             async_: parser.parse()?,
             fn_: parser.parse()?,
             name: parser.parse()?,
@@ -86,6 +99,7 @@ impl Parse for ItemFn {
 
 impl IntoTokens for ItemFn {
     fn into_tokens(&self, context: &mut crate::MacroContext, stream: &mut crate::TokenStream) {
+        self.attributes.into_tokens(context, stream);
         self.async_.into_tokens(context, stream);
         self.fn_.into_tokens(context, stream);
         self.name.into_tokens(context, stream);
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Peek};
+use crate::{IntoTokens, Parse, ParseError, Parser, Peek, Spanned};
 use runestick::Span;
 
 /// A module declaration.
@@ -13,9 +13,8 @@ pub struct ItemMod {
     pub body: ItemModBody,
 }
 
-impl ItemMod {
-    /// The span of the declaration.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemMod {
+    fn span(&self) -> Span {
         self.mod_.span().join(self.body.span())
     }
 }
@@ -47,9 +46,8 @@ pub enum ItemModBody {
     InlineBody(ItemInlineBody),
 }
 
-impl ItemModBody {
-    /// Get the span of the mod body.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemModBody {
+    fn span(&self) -> Span {
         match self {
             Self::EmptyBody(semi) => semi.span(),
             Self::InlineBody(body) => body.span(),
@@ -88,9 +86,8 @@ pub struct ItemInlineBody {
     pub close: ast::CloseBrace,
 }
 
-impl ItemInlineBody {
-    /// The span of the body.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemInlineBody {
+    fn span(&self) -> Span {
         self.open.span().join(self.close.span())
     }
 }
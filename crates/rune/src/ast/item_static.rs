@@ -0,0 +1,57 @@
+use crate::ast;
+use crate::{Parse, ParseError, Parser, Spanned};
+use runestick::Span;
+
+/// A static declaration `static <ident> = <expr>;`.
+///
+/// The expression is evaluated lazily the first time the static is accessed,
+/// after which the value is cached by the virtual machine.
+#[derive(Debug, Clone)]
+pub struct ItemStatic {
+    /// The `static` keyword.
+    pub static_: ast::Static,
+    /// The name of the static.
+    pub name: ast::Ident,
+    /// The equals sign.
+    pub eq: ast::Eq,
+    /// The expression initializing the static.
+    pub expr: Box<ast::Expr>,
+    /// The terminating semicolon.
+    pub semi: ast::SemiColon,
+}
+
+into_tokens!(ItemStatic {
+    static_,
+    name,
+    eq,
+    expr,
+    semi
+});
+
+impl Spanned for ItemStatic {
+    fn span(&self) -> Span {
+        self.static_.span().join(self.semi.span())
+    }
+}
+
+/// Parse implementation for a static.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{parse_all, ast};
+///
+/// parse_all::<ast::ItemStatic>("static FOO = 42;").unwrap();
+/// parse_all::<ast::ItemStatic>("static TABLE = { [1, 2, 3] };").unwrap();
+/// ```
+impl Parse for ItemStatic {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(Self {
+            static_: parser.parse()?,
+            name: parser.parse()?,
+            eq: parser.parse()?,
+            expr: Box::new(parser.parse()?),
+            semi: parser.parse()?,
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, MacroContext, Parse, ParseError, Parser, TokenStream};
+use crate::{IntoTokens, MacroContext, Parse, ParseError, Parser, Spanned, TokenStream};
 use runestick::Span;
 
 /// A struct declaration.
@@ -13,9 +13,8 @@ pub struct ItemStruct {
     pub body: ItemStructBody,
 }
 
-impl ItemStruct {
-    /// Get the span for the declaration.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemStruct {
+    fn span(&self) -> Span {
         let start = self.struct_.span();
 
         match &self.body {
@@ -66,6 +65,16 @@ pub enum ItemStructBody {
     StructBody(StructBody),
 }
 
+impl Spanned for ItemStructBody {
+    fn span(&self) -> Span {
+        match self {
+            Self::EmptyBody(semi) => semi.span(),
+            Self::TupleBody(body, semi) => body.span().join(semi.span()),
+            Self::StructBody(body) => body.span(),
+        }
+    }
+}
+
 /// Parse implementation for a struct body.
 ///
 /// # Examples
@@ -122,9 +131,8 @@ pub struct TupleBody {
     pub close: ast::CloseParen,
 }
 
-impl TupleBody {
-    /// Get the span for the tuple body.
-    pub fn span(&self) -> Span {
+impl Spanned for TupleBody {
+    fn span(&self) -> Span {
         self.open.span().join(self.close.span())
     }
 }
@@ -194,9 +202,8 @@ pub struct StructBody {
     pub close: ast::CloseBrace,
 }
 
-impl StructBody {
-    /// Get the span for the tuple body.
-    pub fn span(&self) -> Span {
+impl Spanned for StructBody {
+    fn span(&self) -> Span {
         self.open.span().join(self.close.span())
     }
 }
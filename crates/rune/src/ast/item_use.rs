@@ -2,6 +2,7 @@ use crate::ast;
 use crate::ast::Kind;
 use crate::error::ParseError;
 use crate::parser::Parser;
+use crate::Spanned;
 use crate::{
     traits::{Parse, Peek},
     IntoTokens,
@@ -28,9 +29,8 @@ into_tokens!(ItemUse {
     semi
 });
 
-impl ItemUse {
-    /// Get the span for the declaration.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemUse {
+    fn span(&self) -> Span {
         self.use_.span().join(self.semi.span())
     }
 }
@@ -66,9 +66,8 @@ pub enum ItemUseComponent {
     Wildcard(ast::Mul),
 }
 
-impl ItemUseComponent {
-    /// Get the span for the declaration.
-    pub fn span(&self) -> Span {
+impl Spanned for ItemUseComponent {
+    fn span(&self) -> Span {
         match self {
             Self::Ident(ident) => ident.span(),
             Self::Wildcard(wildcard) => wildcard.span(),
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser, Peek, Resolve, Storage};
+use crate::{Parse, ParseError, Parser, Peek, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -12,9 +12,8 @@ pub struct Label {
     pub kind: ast::StringSource,
 }
 
-impl Label {
-    /// Access the span of the identifier.
-    pub fn span(&self) -> Span {
+impl Spanned for Label {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
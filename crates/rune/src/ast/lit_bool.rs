@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser, Peek};
+use crate::{Parse, ParseError, Parser, Peek, Spanned};
 use runestick::Span;
 
 /// The unit literal `()`.
@@ -13,9 +13,8 @@ pub struct LitBool {
 
 into_tokens!(LitBool { token });
 
-impl LitBool {
-    /// Get the span of this unit literal.
-    pub fn span(&self) -> Span {
+impl Spanned for LitBool {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
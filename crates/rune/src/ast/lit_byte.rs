@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Storage};
+use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 
 /// A byte literal.
@@ -11,9 +11,8 @@ pub struct LitByte {
     pub source: ast::CopySource<u8>,
 }
 
-impl LitByte {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for LitByte {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
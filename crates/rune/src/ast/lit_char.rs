@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Storage};
+use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 
 /// A character literal.
@@ -11,9 +11,8 @@ pub struct LitChar {
     pub source: ast::CopySource<char>,
 }
 
-impl LitChar {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for LitChar {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
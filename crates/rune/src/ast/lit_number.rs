@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Storage};
+use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 
 /// A number literal.
@@ -11,9 +11,8 @@ pub struct LitNumber {
     token: ast::Token,
 }
 
-impl LitNumber {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for LitNumber {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
@@ -29,6 +28,9 @@ impl LitNumber {
 /// parse_all::<ast::LitNumber>("42.42").unwrap();
 /// parse_all::<ast::LitNumber>("0.42").unwrap();
 /// parse_all::<ast::LitNumber>("0.42e10").unwrap();
+/// parse_all::<ast::LitNumber>("1_000_000").unwrap();
+/// parse_all::<ast::LitNumber>("5i64").unwrap();
+/// parse_all::<ast::LitNumber>("1.0f64").unwrap();
 /// ```
 impl Parse for LitNumber {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -51,6 +53,7 @@ impl<'a> Resolve<'a> for LitNumber {
 
     fn resolve(&self, storage: &Storage, source: &'a Source) -> Result<ast::Number, ParseError> {
         use num::{Num as _, ToPrimitive as _};
+        use std::borrow::Cow;
         use std::ops::Neg as _;
         use std::str::FromStr as _;
 
@@ -80,32 +83,110 @@ impl<'a> Resolve<'a> for LitNumber {
             string
         };
 
-        if text.is_fractional {
-            let number = f64::from_str(string).map_err(err_span(span))?;
-            return Ok(ast::Number::Float(number));
-        }
+        let (string, suffix) = split_suffix(string, text.base);
 
-        let (s, radix) = match text.base {
-            ast::NumberBase::Binary => (2, 2),
-            ast::NumberBase::Octal => (2, 8),
-            ast::NumberBase::Hex => (2, 16),
-            ast::NumberBase::Decimal => (0, 10),
+        let suffix = match suffix {
+            "" => None,
+            "i64" => Some(Suffix::Integer),
+            "f64" => Some(Suffix::Float),
+            suffix if is_type_suffix(suffix) => {
+                return Err(ParseError::BadNumberSuffix { span });
+            }
+            _ => return Err(ParseError::BadNumberLiteral { span }),
         };
 
-        let number = num::BigUint::from_str_radix(&string[s..], radix).map_err(err_span(span))?;
-
-        let number = if text.is_negative {
-            num::BigInt::from(number).neg().to_i64()
+        // NB: underscores are only separators, and have no meaning.
+        let string = if string.contains('_') {
+            Cow::Owned(string.replace('_', ""))
         } else {
-            number.to_i64()
+            Cow::Borrowed(string)
         };
 
-        let number = match number {
-            Some(n) => n,
-            None => return Err(ParseError::BadNumberOutOfBounds { span }),
-        };
+        match suffix {
+            Some(Suffix::Integer) if text.is_fractional => {
+                return Err(ParseError::BadNumberSuffix { span });
+            }
+            Some(Suffix::Float) if text.base != ast::NumberBase::Decimal => {
+                return Err(ParseError::BadNumberSuffix { span });
+            }
+            Some(Suffix::Float) => (),
+            _ if text.is_fractional => (),
+            _ => return resolve_integer(&string, text, span),
+        }
+
+        let number = f64::from_str(&string).map_err(err_span(span))?;
+
+        let number = if text.is_negative { -number } else { number };
+
+        return Ok(ast::Number::Float(number));
+
+        fn resolve_integer(
+            string: &str,
+            text: ast::NumberSourceText,
+            span: Span,
+        ) -> Result<ast::Number, ParseError> {
+            let (s, radix) = match text.base {
+                ast::NumberBase::Binary => (2, 2),
+                ast::NumberBase::Octal => (2, 8),
+                ast::NumberBase::Hex => (2, 16),
+                ast::NumberBase::Decimal => (0, 10),
+            };
+
+            let number =
+                num::BigUint::from_str_radix(&string[s..], radix).map_err(err_span(span))?;
+
+            let number = if text.is_negative {
+                num::BigInt::from(number).neg().to_i64()
+            } else {
+                number.to_i64()
+            };
+
+            let number = match number {
+                Some(n) => n,
+                None => return Err(ParseError::BadNumberOutOfBounds { span }),
+            };
 
-        return Ok(ast::Number::Integer(number));
+            Ok(ast::Number::Integer(number))
+        }
+
+        /// Split a number literal into its digits and its type suffix, which
+        /// starts at the first character which can't be a digit in the given
+        /// base.
+        fn split_suffix(string: &str, base: ast::NumberBase) -> (&str, &str) {
+            let mut it = string.char_indices().peekable();
+
+            // NB: skip the base prefix, like `0x`.
+            if base != ast::NumberBase::Decimal {
+                it.next();
+                it.next();
+            }
+
+            while let Some((n, c)) = it.next() {
+                let is_digit = match base {
+                    ast::NumberBase::Hex => c.is_ascii_hexdigit(),
+                    // NB: an `e` followed by a digit is an exponent.
+                    ast::NumberBase::Decimal if c == 'e' || c == 'E' => {
+                        matches!(it.peek(), Some((_, c)) if c.is_ascii_digit())
+                    }
+                    _ => c.is_ascii_digit() || c == '.',
+                };
+
+                if !is_digit && c != '_' {
+                    return string.split_at(n);
+                }
+            }
+
+            (string, "")
+        }
+
+        /// Test if the given suffix looks like a type suffix, like `u32`.
+        fn is_type_suffix(suffix: &str) -> bool {
+            let mut it = suffix.chars();
+
+            matches!(it.next(), Some('i') | Some('u') | Some('f'))
+                && !it.as_str().is_empty()
+                && it.all(|c| c.is_ascii_digit())
+        }
 
         fn err_span<E>(span: Span) -> impl Fn(E) -> ParseError {
             move |_| ParseError::BadNumberLiteral { span }
@@ -113,6 +194,15 @@ impl<'a> Resolve<'a> for LitNumber {
     }
 }
 
+/// The type suffix of a number literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Suffix {
+    /// An `i64` suffix.
+    Integer,
+    /// An `f64` suffix.
+    Float,
+}
+
 impl IntoTokens for LitNumber {
     fn into_tokens(&self, _: &mut crate::MacroContext, stream: &mut crate::TokenStream) {
         stream.push(self.token);
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Storage};
+use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -12,9 +12,8 @@ pub enum LitObjectIdent {
     Named(ast::Path),
 }
 
-impl LitObjectIdent {
-    /// Get the span of the identifier.
-    pub fn span(&self) -> Span {
+impl Spanned for LitObjectIdent {
+    fn span(&self) -> Span {
         match self {
             Self::Anonymous(hash) => hash.span(),
             Self::Named(path) => path.span(),
@@ -52,15 +51,6 @@ pub struct LitObjectFieldAssign {
 }
 
 impl LitObjectFieldAssign {
-    /// Get the span of the assignment.
-    pub fn span(&self) -> Span {
-        if let Some((_, expr)) = &self.assign {
-            self.key.span().join(expr.span())
-        } else {
-            self.key.span()
-        }
-    }
-
     /// Check if assignment is constant or not.
     pub fn is_const(&self) -> bool {
         match &self.assign {
@@ -70,6 +60,16 @@ impl LitObjectFieldAssign {
     }
 }
 
+impl Spanned for LitObjectFieldAssign {
+    fn span(&self) -> Span {
+        if let Some((_, expr)) = &self.assign {
+            self.key.span().join(expr.span())
+        } else {
+            self.key.span()
+        }
+    }
+}
+
 /// Parse an object literal.
 ///
 /// # Examples
@@ -113,9 +113,8 @@ pub enum LitObjectKey {
     Ident(ast::Ident),
 }
 
-impl LitObjectKey {
-    /// Get the span of the object key.
-    pub fn span(&self) -> Span {
+impl Spanned for LitObjectKey {
+    fn span(&self) -> Span {
         match self {
             Self::LitStr(lit_str) => lit_str.span(),
             Self::Ident(ident) => ident.span(),
@@ -178,7 +177,7 @@ pub struct LitObject {
     /// The open bracket.
     pub open: ast::OpenBrace,
     /// Items in the object declaration.
-    pub assignments: Vec<LitObjectFieldAssign>,
+    pub assignments: Vec<(LitObjectFieldAssign, Option<ast::Comma>)>,
     /// The close bracket.
     pub close: ast::CloseBrace,
     /// Indicates if the object is completely literal and cannot have side
@@ -187,11 +186,6 @@ pub struct LitObject {
 }
 
 impl LitObject {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.ident.span().join(self.close.span())
-    }
-
     /// Test if the entire expression is constant.
     pub fn is_const(&self) -> bool {
         self.is_const
@@ -215,11 +209,16 @@ impl LitObject {
                 is_const = false;
             }
 
-            assignments.push(assign);
-
-            if parser.peek::<ast::Comma>()? {
-                parser.parse::<ast::Comma>()?;
+            let comma = if parser.peek::<ast::Comma>()? {
+                Some(parser.parse::<ast::Comma>()?)
             } else {
+                None
+            };
+
+            let is_end = comma.is_none();
+            assignments.push((assign, comma));
+
+            if is_end {
                 break;
             }
         }
@@ -236,6 +235,12 @@ impl LitObject {
     }
 }
 
+impl Spanned for LitObject {
+    fn span(&self) -> Span {
+        self.ident.span().join(self.close.span())
+    }
+}
+
 /// Parse an object literal.
 ///
 /// # Examples
@@ -259,8 +264,9 @@ impl IntoTokens for LitObject {
         self.ident.into_tokens(context, stream);
         self.open.into_tokens(context, stream);
 
-        for assign in &self.assignments {
+        for (assign, comma) in &self.assignments {
             assign.into_tokens(context, stream);
+            comma.into_tokens(context, stream);
         }
 
         self.close.into_tokens(context, stream);
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Storage};
+use crate::{IntoTokens, Parse, ParseError, Parser, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -12,9 +12,8 @@ pub struct LitTemplate {
     source: ast::LitStrSource,
 }
 
-impl LitTemplate {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for LitTemplate {
+    fn span(&self) -> Span {
         self.token.span
     }
 }
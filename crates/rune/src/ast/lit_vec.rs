@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 
 /// A number literal.
@@ -8,7 +8,7 @@ pub struct LitVec {
     /// The open bracket.
     pub open: ast::OpenBracket,
     /// Items in the array.
-    pub items: Vec<ast::Expr>,
+    pub items: Vec<(ast::Expr, Option<ast::Comma>)>,
     /// The close bracket.
     pub close: ast::CloseBracket,
     /// If the entire array is constant.
@@ -18,17 +18,18 @@ pub struct LitVec {
 into_tokens!(LitVec { open, items, close });
 
 impl LitVec {
-    /// Access the span of the expression.
-    pub fn span(&self) -> Span {
-        self.open.span().join(self.close.span())
-    }
-
     /// Test if the entire expression is constant.
     pub fn is_const(&self) -> bool {
         self.is_const
     }
 }
 
+impl Spanned for LitVec {
+    fn span(&self) -> Span {
+        self.open.span().join(self.close.span())
+    }
+}
+
 /// Parse an array literal.
 ///
 /// # Examples
@@ -54,11 +55,16 @@ impl Parse for LitVec {
                 is_const = false;
             }
 
-            items.push(expr);
-
-            if parser.peek::<ast::Comma>()? {
-                parser.parse::<ast::Comma>()?;
+            let comma = if parser.peek::<ast::Comma>()? {
+                Some(parser.parse::<ast::Comma>()?)
             } else {
+                None
+            };
+
+            let is_end = comma.is_none();
+            items.push((expr, comma));
+
+            if is_end {
                 break;
             }
         }
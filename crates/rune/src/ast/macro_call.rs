@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::token_stream::TokenStream;
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 
 /// A function call `<expr>!(<args>)`.
@@ -27,11 +27,6 @@ into_tokens!(MacroCall {
 });
 
 impl MacroCall {
-    /// Access the span of expression.
-    pub fn span(&self) -> Span {
-        self.path.span().join(self.close.span)
-    }
-
     /// Parse with an expression.
     pub fn parse_with_path(parser: &mut Parser, path: ast::Path) -> Result<Self, ParseError> {
         let bang: ast::Bang = parser.parse()?;
@@ -92,6 +87,12 @@ impl MacroCall {
     }
 }
 
+impl Spanned for MacroCall {
+    fn span(&self) -> Span {
+        self.path.span().join(self.close.span)
+    }
+}
+
 impl Parse for MacroCall {
     fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
         let path = parser.parse()?;
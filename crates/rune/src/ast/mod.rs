@@ -3,6 +3,7 @@
 use crate::{Parse, ParseError, Parser, Peek};
 use runestick::Span;
 
+mod attribute;
 mod block;
 mod condition;
 mod expr;
@@ -28,6 +29,8 @@ mod expr_loop;
 mod expr_match;
 mod expr_return;
 mod expr_select;
+mod expr_slice;
+mod expr_spread;
 mod expr_try;
 mod expr_unary;
 mod expr_while;
@@ -40,6 +43,7 @@ mod item_enum;
 mod item_fn;
 mod item_impl;
 mod item_mod;
+mod item_static;
 mod item_struct;
 mod item_use;
 mod label;
@@ -66,6 +70,7 @@ mod stmt;
 mod token;
 pub(super) mod utils;
 
+pub use self::attribute::Attribute;
 pub use self::block::Block;
 pub use self::condition::Condition;
 pub use self::expr::Expr;
@@ -91,6 +96,8 @@ pub use self::expr_loop::ExprLoop;
 pub use self::expr_match::{ExprMatch, ExprMatchBranch};
 pub use self::expr_return::ExprReturn;
 pub use self::expr_select::ExprSelect;
+pub use self::expr_slice::ExprSlice;
+pub use self::expr_spread::ExprSpread;
 pub use self::expr_try::ExprTry;
 pub use self::expr_unary::{ExprUnary, UnaryOp};
 pub use self::expr_while::ExprWhile;
@@ -103,6 +110,7 @@ pub use self::item_enum::{ItemEnum, ItemEnumVariant};
 pub use self::item_fn::ItemFn;
 pub use self::item_impl::ItemImpl;
 pub use self::item_mod::{ItemMod, ItemModBody};
+pub use self::item_static::ItemStatic;
 pub use self::item_struct::{ItemStruct, ItemStructBody, StructBody, TupleBody};
 pub use self::item_use::{ItemUse, ItemUseComponent};
 pub use self::label::Label;
@@ -141,9 +149,8 @@ macro_rules! decl_tokens {
                 pub token: Token,
             }
 
-            impl $parser {
-                /// Access the span of the token.
-                pub fn span(&self) -> Span {
+            impl crate::Spanned for $parser {
+                fn span(&self) -> Span {
                     self.token.span
                 }
             }
@@ -196,6 +203,7 @@ decl_tokens! {
     (Not, "The `!` operator.", Kind::Not),
     (Enum, "The `enum` keyword.", Kind::Enum),
     (Struct, "The `struct` keyword.", Kind::Struct),
+    (Static, "The `static` keyword.", Kind::Static),
     (If, "The `if` keyword.", Kind::If),
     (Match, "The `match` keyword.", Kind::Match),
     (Else, "The `else` keyword.", Kind::Else),
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser, Peek};
+use crate::{Parse, ParseError, Parser, Peek, Spanned};
 use runestick::Span;
 
 /// A pattern match.
@@ -41,22 +41,6 @@ into_tokens_enum!(Pat {
 });
 
 impl Pat {
-    /// Get the span of the pattern.
-    pub fn span(&self) -> Span {
-        match self {
-            Self::PatUnit(pat) => pat.span(),
-            Self::PatByte(pat) => pat.span(),
-            Self::PatChar(pat) => pat.span(),
-            Self::PatNumber(pat) => pat.span(),
-            Self::PatString(pat) => pat.span(),
-            Self::PatPath(pat) => pat.span(),
-            Self::PatIgnore(pat) => pat.span(),
-            Self::PatVec(pat) => pat.span(),
-            Self::PatTuple(pat) => pat.span(),
-            Self::PatObject(pat) => pat.span(),
-        }
-    }
-
     /// Parse a pattern with a starting identifier.
     pub fn parse_ident(parser: &mut Parser) -> Result<Self, ParseError> {
         let first = parser.parse()?;
@@ -102,6 +86,23 @@ impl Pat {
     }
 }
 
+impl Spanned for Pat {
+    fn span(&self) -> Span {
+        match self {
+            Self::PatUnit(pat) => pat.span(),
+            Self::PatByte(pat) => pat.span(),
+            Self::PatChar(pat) => pat.span(),
+            Self::PatNumber(pat) => pat.span(),
+            Self::PatString(pat) => pat.span(),
+            Self::PatPath(pat) => pat.span(),
+            Self::PatIgnore(pat) => pat.span(),
+            Self::PatVec(pat) => pat.span(),
+            Self::PatTuple(pat) => pat.span(),
+            Self::PatObject(pat) => pat.span(),
+        }
+    }
+}
+
 /// Parsing a block expression.
 ///
 /// # Examples
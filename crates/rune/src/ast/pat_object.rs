@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, ParseError, Parser};
+use crate::{Parse, ParseError, Parser, Spanned};
 use runestick::Span;
 
 /// An object pattern.
@@ -26,11 +26,6 @@ into_tokens!(PatObject {
 });
 
 impl PatObject {
-    /// Get the span of the pattern.
-    pub fn span(&self) -> Span {
-        self.ident.span().join(self.close.span())
-    }
-
     /// Parse the object with an opening path.
     pub fn parse_with_ident(
         parser: &mut Parser<'_>,
@@ -76,6 +71,12 @@ impl PatObject {
     }
 }
 
+impl Spanned for PatObject {
+    fn span(&self) -> Span {
+        self.ident.span().join(self.close.span())
+    }
+}
+
 impl Parse for PatObject {
     fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
         let ident = parser.parse()?;
@@ -94,9 +95,8 @@ pub struct PatObjectItem {
 
 into_tokens!(PatObjectItem { key, binding });
 
-impl PatObjectItem {
-    /// The span of the expression.
-    pub fn span(&self) -> Span {
+impl Spanned for PatObjectItem {
+    fn span(&self) -> Span {
         if let Some((_, pat)) = &self.binding {
             self.key.span().join(pat.span())
         } else {
@@ -2,7 +2,7 @@ use crate::ast;
 use crate::ast::{Kind, Token};
 use crate::error::ParseError;
 use crate::parser::Parser;
-use crate::{IntoTokens, Parse, Peek, Resolve, Storage};
+use crate::{IntoTokens, Parse, Peek, Resolve, Spanned, Storage};
 use runestick::{Source, Span};
 use std::borrow::Cow;
 
@@ -38,14 +38,6 @@ impl Path {
         Some(&self.first)
     }
 
-    /// Calculate the full span of the path.
-    pub fn span(&self) -> Span {
-        match self.rest.last() {
-            Some((_, ident)) => self.first.span().join(ident.span()),
-            None => self.first.span(),
-        }
-    }
-
     /// Parse with the first identifier already parsed.
     pub fn parse_with_first(parser: &mut Parser, first: ast::Ident) -> Result<Self, ParseError> {
         Ok(Self {
@@ -69,6 +61,15 @@ impl Path {
     }
 }
 
+impl Spanned for Path {
+    fn span(&self) -> Span {
+        match self.rest.last() {
+            Some((_, ident)) => self.first.span().join(ident.span()),
+            None => self.first.span(),
+        }
+    }
+}
+
 impl Peek for Path {
     fn peek(t1: Option<Token>, _: Option<Token>) -> bool {
         let t1 = match t1 {
@@ -1,4 +1,4 @@
-use crate::MacroContext;
+use crate::{MacroContext, Spanned};
 use runestick::Span;
 use std::fmt;
 
@@ -11,6 +11,12 @@ pub struct Token {
     pub kind: Kind,
 }
 
+impl Spanned for Token {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl crate::IntoTokens for Token {
     fn into_tokens(&self, _: &mut MacroContext, stream: &mut crate::TokenStream) {
         stream.push(*self);
@@ -277,6 +283,7 @@ kinds! {
     Proc, "The `proc` keyword.",
     Pure, "The `pure` keyword.",
     QuestionMark, "`?`.",
+    QuestionQuestion, "`??`.",
     Ref, "The `ref` keyword.",
     Return, "The `return` keyword.",
     Rocket, "`=>`.",
@@ -437,6 +444,7 @@ impl fmt::Display for Kind {
             Self::Proc => write!(f, "proc")?,
             Self::Pure => write!(f, "pure")?,
             Self::QuestionMark => write!(f, "?")?,
+            Self::QuestionQuestion => write!(f, "??")?,
             Self::Ref => write!(f, "ref")?,
             Self::Return => write!(f, "return")?,
             Self::Rocket => write!(f, "=>")?,
@@ -4,6 +4,7 @@ use crate::error::CompileResult;
 use crate::traits::Compile;
 use crate::worker::Expanded;
 use crate::CompileError;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile an expression.
@@ -49,6 +50,14 @@ impl Compile<(&ast::Expr, Needs)> for Compiler<'_> {
             ast::Expr::ExprIndexGet(expr_index_get) => {
                 self.compile((expr_index_get, needs))?;
             }
+            ast::Expr::ExprSlice(expr_slice) => {
+                self.compile((expr_slice, needs))?;
+            }
+            ast::Expr::ExprSpread(expr_spread) => {
+                return Err(CompileError::UnsupportedSpread {
+                    span: expr_spread.span(),
+                });
+            }
             ast::Expr::ExprBreak(expr_break) => {
                 self.compile(expr_break)?;
             }
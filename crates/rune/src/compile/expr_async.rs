@@ -3,6 +3,7 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
 use crate::CompileError;
+use crate::Spanned;
 use runestick::{CompileMeta, Hash, Inst};
 
 /// Call an async block.
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile an `.await` expression.
@@ -1,8 +1,10 @@
 use crate::ast;
+use crate::compile::expr_if::check_if_without_else;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::{Compile, Resolve as _};
 use crate::CompileError;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a binary expression.
@@ -24,6 +26,35 @@ impl Compile<(&ast::ExprBinary, Needs)> for Compiler<'_> {
             return Ok(());
         }
 
+        // NB: with constant propagation enabled, operations on values that are
+        // known at compile time are folded into a single constant.
+        if let Some(constant) = self.const_eval_binary(expr_binary)? {
+            if needs.value() {
+                constant.push(&mut self.asm, span);
+            }
+
+            return Ok(());
+        }
+
+        // Logical operators which short-circuit and conditionally evaluate
+        // their right-hand side.
+        if let ast::BinOp::And | ast::BinOp::Or = expr_binary.op {
+            compile_conditional_binop(
+                self,
+                &*expr_binary.lhs,
+                &*expr_binary.rhs,
+                expr_binary.op,
+                needs,
+            )?;
+
+            return Ok(());
+        }
+
+        if let ast::BinOp::NullCoalesce = expr_binary.op {
+            compile_null_coalesce(self, &*expr_binary.lhs, &*expr_binary.rhs, needs)?;
+            return Ok(());
+        }
+
         // NB: need to declare these as anonymous local variables so that they
         // get cleaned up in case there is an early break (return, try, ...).
         self.compile((&*expr_binary.lhs, Needs::Value))?;
@@ -72,11 +103,8 @@ impl Compile<(&ast::ExprBinary, Needs)> for Compiler<'_> {
             ast::BinOp::IsNot { .. } => {
                 self.asm.push(Inst::IsNot, span);
             }
-            ast::BinOp::And { .. } => {
-                self.asm.push(Inst::And, span);
-            }
-            ast::BinOp::Or { .. } => {
-                self.asm.push(Inst::Or, span);
+            ast::BinOp::In { .. } => {
+                self.asm.push(Inst::In, span);
             }
             ast::BinOp::BitAnd { .. } => {
                 self.asm.push(Inst::BitAnd, span);
@@ -118,6 +146,96 @@ fn rhs_needs_of(op: ast::BinOp) -> Needs {
     }
 }
 
+/// Compile a logical binary operation which short-circuits, so that the
+/// right-hand side is only evaluated if the left-hand side doesn't determine
+/// the outcome.
+fn compile_conditional_binop(
+    compiler: &mut Compiler<'_>,
+    lhs: &ast::Expr,
+    rhs: &ast::Expr,
+    bin_op: ast::BinOp,
+    needs: Needs,
+) -> CompileResult<()> {
+    let span = lhs.span().join(rhs.span());
+
+    let end_label = compiler.asm.new_label("conditional_end");
+    let short_label = compiler.asm.new_label("conditional_short");
+
+    // NB: the conditional jumps consume the operands, so they don't have to
+    // be declared as anonymous variables.
+    compiler.compile((lhs, Needs::Value))?;
+
+    // The value the expression evaluates to if it short-circuits.
+    let short = match bin_op {
+        ast::BinOp::And => {
+            compiler.asm.jump_if_not(short_label, lhs.span());
+            compiler.compile((rhs, Needs::Value))?;
+            compiler.asm.jump_if_not(short_label, rhs.span());
+            false
+        }
+        ast::BinOp::Or => {
+            compiler.asm.jump_if(short_label, lhs.span());
+            compiler.compile((rhs, Needs::Value))?;
+            compiler.asm.jump_if(short_label, rhs.span());
+            true
+        }
+        op => {
+            return Err(CompileError::UnsupportedBinaryOp { span, op });
+        }
+    };
+
+    compiler.asm.push(Inst::Bool { value: !short }, span);
+    compiler.asm.jump(end_label, span);
+
+    compiler.asm.label(short_label)?;
+    compiler.asm.push(Inst::Bool { value: short }, span);
+
+    compiler.asm.label(end_label)?;
+
+    if !needs.value() {
+        compiler.asm.push(Inst::Pop, span);
+    }
+
+    Ok(())
+}
+
+/// Compile a null-coalescing operation, which only evaluates its right-hand
+/// side if the left-hand side is `None` or `Err`.
+fn compile_null_coalesce(
+    compiler: &mut Compiler<'_>,
+    lhs: &ast::Expr,
+    rhs: &ast::Expr,
+    needs: Needs,
+) -> CompileResult<()> {
+    let span = lhs.span().join(rhs.span());
+
+    let end_label = compiler.asm.new_label("coalesce_end");
+    let null_label = compiler.asm.new_label("coalesce_null");
+
+    // NB: the operand is consumed on both branches before anything else is
+    // compiled, so it doesn't have to be declared as an anonymous variable.
+    compiler.compile((lhs, Needs::Value))?;
+    compiler.asm.push(Inst::Dup, lhs.span());
+    compiler.asm.push(Inst::IsValue, lhs.span());
+    compiler.asm.jump_if_not(null_label, lhs.span());
+    compiler
+        .asm
+        .push(Inst::TupleIndexGet { index: 0 }, lhs.span());
+    compiler.asm.jump(end_label, span);
+
+    compiler.asm.label(null_label)?;
+    compiler.asm.push(Inst::Pop, lhs.span());
+    compiler.compile((rhs, Needs::Value))?;
+
+    compiler.asm.label(end_label)?;
+
+    if !needs.value() {
+        compiler.asm.push(Inst::Pop, span);
+    }
+
+    Ok(())
+}
+
 fn compile_assign_binop(
     compiler: &mut Compiler<'_>,
     lhs: &ast::Expr,
@@ -126,6 +244,7 @@ fn compile_assign_binop(
     needs: Needs,
 ) -> CompileResult<()> {
     let span = lhs.span().join(rhs.span());
+    check_if_without_else(rhs)?;
 
     // assignments
     if let ast::BinOp::Assign = bin_op {
@@ -134,7 +253,9 @@ fn compile_assign_binop(
             ast::Expr::Path(path) if path.rest.is_empty() => {
                 compiler.compile((rhs, Needs::Value))?;
 
-                let ident = path.first.resolve(compiler.storage, &*compiler.source)?;
+                let ident = path
+                    .first
+                    .resolve_local(compiler.storage, &*compiler.source)?;
                 let var = compiler.scopes.get_var(&*ident, span)?;
                 compiler
                     .asm
@@ -162,7 +283,7 @@ fn compile_assign_binop(
                         compiler.scopes.decl_anon(span)?;
 
                         compiler.compile((&*field_access.expr, Needs::Value))?;
-                        compiler.asm.push(Inst::IndexSet, span);
+                        compiler.asm.push(Inst::IndexSet { negative: false }, span);
                         compiler.scopes.undecl_anon(2, span)?;
                         true
                     }
@@ -193,7 +314,9 @@ fn compile_assign_binop(
         let supported = match lhs {
             // <var> <op> <expr>
             ast::Expr::Path(path) if path.rest.is_empty() => {
-                let ident = path.first.resolve(compiler.storage, &*compiler.source)?;
+                let ident = path
+                    .first
+                    .resolve_local(compiler.storage, &*compiler.source)?;
                 let var = compiler.scopes.get_var(&*ident, span)?;
                 Some(var.offset)
             }
@@ -1,7 +1,7 @@
 use crate::ast;
 use crate::compiler::Compiler;
 use crate::error::CompileResult;
-use crate::{traits::Compile, CompileError};
+use crate::{traits::Compile, CompileError, Spanned};
 use runestick::Inst;
 
 /// Compile a break expression.
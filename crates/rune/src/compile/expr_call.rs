@@ -1,9 +1,12 @@
 use crate::ast;
+use crate::compile::expr_if::check_if_without_else;
+use crate::compile::lit_vec::{compile_spread_vec, has_spread};
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::{Compile, Resolve as _};
 use crate::CompileError;
-use runestick::{CompileMeta, Hash, Inst};
+use crate::Spanned;
+use runestick::{CompileMeta, Hash, Inst, Span};
 
 /// Compile a call expression.
 impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
@@ -15,6 +18,7 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
         let guard = self.scopes.push(scope);
 
         let args = expr_call.args.items.len();
+        let spread = has_spread(expr_call.args.items.iter().map(|(expr, _)| expr));
 
         // NB: either handle a proper function call by resolving it's meta hash,
         // or expand the expression.
@@ -37,25 +41,33 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
 
                     self.compile((&**expr, Needs::Value))?;
 
-                    for (expr, _) in expr_call.args.items.iter() {
-                        self.compile((expr, Needs::Value))?;
+                    if spread {
+                        let items = expr_call.args.items.iter().map(|(expr, _)| expr);
+                        compile_spread_vec(self, 1, items, span)?;
                         self.scopes.decl_anon(span)?;
+                    } else {
+                        for (expr, _) in expr_call.args.items.iter() {
+                            check_if_without_else(expr)?;
+                            self.compile((expr, Needs::Value))?;
+                            self.scopes.decl_anon(span)?;
+                        }
                     }
 
                     let ident = ident.resolve(&self.storage, &*self.source)?;
                     let hash = Hash::of(ident);
-                    self.asm.push(Inst::CallInstance { hash, args }, span);
+
+                    if spread {
+                        self.asm.push(Inst::CallInstanceSpread { hash }, span);
+                    } else {
+                        self.asm.push(Inst::CallInstance { hash, args }, span);
+                    }
                 }
                 expr => {
                     log::trace!("ExprCall(Other) => {:?}", self.source.source(span));
 
-                    for (expr, _) in expr_call.args.items.iter() {
-                        self.compile((expr, Needs::Value))?;
-                        self.scopes.decl_anon(span)?;
-                    }
-
+                    compile_args(self, expr_call, spread, span)?;
                     self.compile((expr, Needs::Value))?;
-                    self.asm.push(Inst::CallFn { args }, span);
+                    self.asm.push(call_fn(args, spread), span);
                 }
             }
 
@@ -67,17 +79,16 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
             return Ok(());
         };
 
-        for (expr, _) in expr_call.args.items.iter() {
-            self.compile((expr, Needs::Value))?;
-            self.scopes.decl_anon(span)?;
-        }
+        compile_args(self, expr_call, spread, span)?;
 
         let item = self.convert_path_to_item(path)?;
 
-        if let Some(name) = item.as_local() {
-            if let Some(var) = self.scopes.try_get_var(name)? {
+        if let Some(name) = path.try_as_ident() {
+            let name = name.resolve_local(self.storage, &*self.source)?;
+
+            if let Some(var) = self.scopes.try_get_var(&name)? {
                 var.copy(&mut self.asm, span, format!("var `{}`", name));
-                self.asm.push(Inst::CallFn { args }, span);
+                self.asm.push(call_fn(args, spread), span);
 
                 if !needs.value() {
                     self.asm.push(Inst::Pop, span);
@@ -90,6 +101,27 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
 
         let meta = match self.lookup_meta(&item, path.span())? {
             Some(meta) => meta,
+            None if self.options.link_checks && !spread => {
+                // NB: defer reporting the missing function to the linker, so
+                // that all missing functions are reported at once.
+                let hash = Hash::type_hash(&item);
+                self.unit
+                    .borrow_mut()
+                    .insert_called_function(hash, item.clone());
+
+                self.asm.push_with_comment(
+                    Inst::Call { hash, args },
+                    span,
+                    format!("fn `{}`", item),
+                );
+
+                if !needs.value() {
+                    self.asm.push(Inst::Pop, span);
+                }
+
+                self.scopes.pop(guard, span)?;
+                return Ok(());
+            }
             None => {
                 return Err(CompileError::MissingFunction { span, item });
             }
@@ -97,7 +129,9 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
 
         let item = match &meta {
             CompileMeta::Tuple { tuple, .. } | CompileMeta::TupleVariant { tuple, .. } => {
-                if tuple.args != expr_call.args.items.len() {
+                // NB: the number of arguments is only known at runtime when
+                // they are spread.
+                if !spread && tuple.args != expr_call.args.items.len() {
                     return Err(CompileError::UnsupportedArgumentCount {
                         span,
                         meta: meta.clone(),
@@ -125,8 +159,19 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
         };
 
         let hash = Hash::type_hash(&item);
-        self.asm
-            .push_with_comment(Inst::Call { hash, args }, span, format!("fn `{}`", item));
+
+        self.unit
+            .borrow_mut()
+            .insert_called_function(hash, item.clone());
+
+        if spread {
+            self.asm
+                .push_with_comment(Inst::Type { hash }, span, format!("fn `{}`", item));
+            self.asm.push(Inst::CallFnSpread, span);
+        } else {
+            self.asm
+                .push_with_comment(Inst::Call { hash, args }, span, format!("fn `{}`", item));
+        }
 
         // NB: we put it here to preserve the call in case it has side effects.
         // But if we don't need the value, then pop it from the stack.
@@ -138,3 +183,36 @@ impl Compile<(&ast::ExprCall, Needs)> for Compiler<'_> {
         Ok(())
     }
 }
+
+/// Compile the arguments of a call, collecting them into a single vector if
+/// any of them are spread.
+fn compile_args(
+    compiler: &mut Compiler<'_>,
+    expr_call: &ast::ExprCall,
+    spread: bool,
+    span: Span,
+) -> CompileResult<()> {
+    if spread {
+        let items = expr_call.args.items.iter().map(|(expr, _)| expr);
+        compile_spread_vec(compiler, 0, items, span)?;
+        compiler.scopes.decl_anon(span)?;
+        return Ok(());
+    }
+
+    for (expr, _) in expr_call.args.items.iter() {
+        check_if_without_else(expr)?;
+        compiler.compile((expr, Needs::Value))?;
+        compiler.scopes.decl_anon(span)?;
+    }
+
+    Ok(())
+}
+
+/// Get the instruction used to call a function pointer.
+fn call_fn(args: usize, spread: bool) -> Inst {
+    if spread {
+        Inst::CallFnSpread
+    } else {
+        Inst::CallFn { args }
+    }
+}
@@ -2,7 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
-use crate::{traits::Resolve as _, CompileError};
+use crate::{CompileError, Spanned};
 use runestick::{CompileMeta, CompileMetaCapture, Hash, Inst};
 
 /// Compile the body of a closure function.
@@ -25,7 +25,7 @@ impl Compile<(ast::ExprClosure, &[CompileMetaCapture])> for Compiler<'_> {
                         return Err(CompileError::UnsupportedSelf { span: s.span() })
                     }
                     ast::FnArg::Ident(ident) => {
-                        let ident = ident.resolve(&self.storage, &*self.source)?;
+                        let ident = ident.resolve_local(&self.storage, &*self.source)?;
                         scope.new_var(ident.as_ref(), span)?;
                     }
                     ast::FnArg::Ignore(..) => {
@@ -3,6 +3,7 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::{Compile, Resolve as _};
 use crate::CompileError;
+use crate::Spanned;
 use runestick::{Inst, Span};
 use std::convert::TryFrom as _;
 
@@ -85,7 +86,7 @@ fn try_immediate_field_access_optimization(
         None => return Ok(false),
     };
 
-    let ident = ident.resolve(this.storage, &*this.source)?;
+    let ident = ident.resolve_local(this.storage, &*this.source)?;
 
     let index = match n.resolve(this.storage, &*this.source)? {
         ast::Number::Integer(n) => n,
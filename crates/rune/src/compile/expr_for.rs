@@ -2,7 +2,8 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::loops::Loop;
-use crate::traits::{Compile, Resolve as _};
+use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a for loop.
@@ -22,14 +23,18 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_> {
             self.compile((&*expr_for.iter, Needs::Value))?;
 
             let iter_offset = loop_scope.decl_anon(span);
-            self.asm.push_with_comment(
-                Inst::CallInstance {
-                    hash: *runestick::INTO_ITER,
-                    args: 0,
-                },
-                span,
-                format!("into_iter (offset: {})", iter_offset),
-            );
+
+            // NB: streams are iterated over directly.
+            if expr_for.await_.is_none() {
+                self.asm.push_with_comment(
+                    Inst::CallInstance {
+                        hash: *runestick::INTO_ITER,
+                        args: 0,
+                    },
+                    span,
+                    format!("into_iter (offset: {})", iter_offset),
+                );
+            }
 
             let loop_scope_expected = self.scopes.push(loop_scope);
             (iter_offset, loop_scope_expected)
@@ -46,13 +51,19 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_> {
         // Declare named loop variable.
         let binding_offset = {
             self.asm.push(Inst::Unit, expr_for.iter.span());
-            let name = expr_for.var.resolve(&self.storage, &*self.source)?;
+            let name = expr_for.var.resolve_local(&self.storage, &*self.source)?;
             self.scopes
                 .last_mut(span)?
                 .decl_var(name.as_ref(), expr_for.var.span())
         };
 
         // Declare storage for memoized `next` instance fn.
+        //
+        // NB: this is only correct because the receiver is the iterator in
+        // the anonymous `iter_offset` slot, which can't be reassigned from
+        // within the loop. So its type is stable across iterations. The
+        // instance fn is looked up again from the receiver every time the
+        // loop is entered.
         let next_offset = if self.options.memoize_instance_fn {
             let span = expr_for.iter.span();
 
@@ -102,6 +113,10 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_> {
 
             self.asm.push(Inst::CallFn { args: 1 }, span);
 
+            if let Some(await_) = &expr_for.await_ {
+                self.asm.push(Inst::Await, await_.span());
+            }
+
             self.asm.push(
                 Inst::Replace {
                     offset: binding_offset,
@@ -126,6 +141,11 @@ impl Compile<(&ast::ExprFor, Needs)> for Compiler<'_> {
                 span,
                 "next",
             );
+
+            if let Some(await_) = &expr_for.await_ {
+                self.asm.push(Inst::Await, await_.span());
+            }
+
             self.asm.push(
                 Inst::Replace {
                     offset: binding_offset,
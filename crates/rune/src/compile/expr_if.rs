@@ -2,6 +2,8 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::CompileError;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile an if expression.
@@ -25,12 +27,8 @@ impl Compile<(&ast::ExprIf, Needs)> for Compiler<'_> {
         // use fallback as fall through.
         if let Some(fallback) = &expr_if.expr_else {
             self.compile((&*fallback.block, needs))?;
-        } else {
-            // NB: if we must produce a value and there is no fallback branch,
-            // encode the result of the statement as a unit.
-            if needs.value() {
-                self.asm.push(Inst::Unit, span);
-            }
+        } else if needs.value() {
+            self.asm.push(Inst::Unit, span);
         }
 
         self.asm.jump(end_label, span);
@@ -65,3 +63,30 @@ impl Compile<(&ast::ExprIf, Needs)> for Compiler<'_> {
         Ok(())
     }
 }
+
+/// Check that an expression whose value is consumed, like the initializer of a
+/// `let`, the right-hand side of an assignment or a call argument, is not an
+/// if expression missing an else branch while its other branches produce a
+/// value.
+///
+/// In tail or statement position the missing branch simply evaluates to `()`.
+pub(crate) fn check_if_without_else(expr: &ast::Expr) -> CompileResult<()> {
+    let expr_if = match expr {
+        ast::Expr::ExprIf(expr_if) if expr_if.expr_else.is_none() => expr_if,
+        _ => return Ok(()),
+    };
+
+    let produces_value = !expr_if.block.produces_nothing()
+        || expr_if
+            .expr_else_ifs
+            .iter()
+            .any(|branch| !branch.block.produces_nothing());
+
+    if produces_value {
+        return Err(CompileError::IfWithoutElse {
+            span: expr_if.span(),
+        });
+    }
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile an expression.
@@ -22,7 +23,12 @@ impl Compile<(&ast::ExprIndexGet, Needs)> for Compiler<'_> {
         self.compile((&*expr_index_get.target, Needs::Value))?;
         self.scopes.decl_anon(span)?;
 
-        self.asm.push(Inst::IndexGet, span);
+        self.asm.push(
+            Inst::IndexGet {
+                negative: self.options.negative_index,
+            },
+            span,
+        );
 
         // NB: we still need to perform the operation since it might have side
         // effects, but pop the result in case a value is not needed.
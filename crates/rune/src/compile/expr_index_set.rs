@@ -2,6 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// An expr index set operation.
@@ -16,7 +17,12 @@ impl Compile<(&ast::ExprIndexSet, Needs)> for Compiler<'_> {
         self.compile((&*expr_index_set.value, Needs::Value))?;
         self.compile((&*expr_index_set.index, Needs::Value))?;
         self.compile((&*expr_index_set.target, Needs::Value))?;
-        self.asm.push(Inst::IndexSet, span);
+        self.asm.push(
+            Inst::IndexSet {
+                negative: self.options.negative_index,
+            },
+            span,
+        );
 
         // Encode a unit in case a value is needed.
         if needs.value() {
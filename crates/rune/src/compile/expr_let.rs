@@ -1,8 +1,10 @@
 use crate::assembly::Assembly;
 use crate::ast;
+use crate::compile::expr_if::check_if_without_else;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a let expression.
@@ -11,6 +13,9 @@ impl Compile<(&ast::ExprLet, Needs)> for Compiler<'_> {
         let span = expr_let.span();
         log::trace!("ExprLet => {:?}", self.source.source(span));
 
+        check_if_without_else(&*expr_let.expr)?;
+        let constant = self.const_eval(&*expr_let.expr)?;
+
         // NB: assignments "move" the value being assigned.
         self.compile((&*expr_let.expr, Needs::Value))?;
 
@@ -37,6 +42,20 @@ impl Compile<(&ast::ExprLet, Needs)> for Compiler<'_> {
             self.asm.label(ok_label)?;
         }
 
+        // A binding which is never reassigned keeps the constant it was
+        // initialized with, so that it can be propagated where it's used.
+        if let (Some(constant), ast::Pat::PatPath(path)) = (constant, &expr_let.pat) {
+            if let Some(ident) = path.path.try_as_ident() {
+                let ident = ident.resolve_local(self.storage, &*self.source)?;
+
+                if let Some(var) = scope.get_mut(&ident) {
+                    if self.is_immutable(var.span()) {
+                        var.constant = Some(constant);
+                    }
+                }
+            }
+        }
+
         let _ = self.scopes.push(scope);
 
         // If a value is needed for a let expression, it is evaluated as a unit.
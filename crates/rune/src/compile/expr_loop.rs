@@ -3,6 +3,7 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::loops::Loop;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a loop.
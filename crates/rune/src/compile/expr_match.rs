@@ -3,6 +3,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 impl Compile<(&ast::ExprMatch, Needs)> for Compiler<'_> {
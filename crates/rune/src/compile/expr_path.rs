@@ -1,7 +1,7 @@
 use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
-use crate::{traits::Compile, CompileError};
+use crate::{traits::Compile, CompileError, Spanned};
 
 /// Compile `self`.
 impl Compile<(&ast::Path, Needs)> for Compiler<'_> {
@@ -18,8 +18,10 @@ impl Compile<(&ast::Path, Needs)> for Compiler<'_> {
         let item = self.convert_path_to_item(path)?;
 
         if let Needs::Value = needs {
-            if let Some(local) = item.as_local() {
-                if let Some(var) = self.scopes.try_get_var(local)? {
+            if let Some(local) = path.try_as_ident() {
+                let local = local.resolve_local(self.storage, &*self.source)?;
+
+                if let Some(var) = self.scopes.try_get_var(&local)? {
                     var.copy(&mut self.asm, span, format!("var `{}`", local));
                     return Ok(());
                 }
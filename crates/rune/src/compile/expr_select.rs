@@ -3,6 +3,7 @@ use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
 use crate::CompileError;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a select expression.
@@ -27,7 +28,13 @@ impl Compile<(&ast::ExprSelect, Needs)> for Compiler<'_> {
             self.compile((&*branch.expr, Needs::Value))?;
         }
 
-        self.asm.push(Inst::Select { len }, span);
+        self.asm.push(
+            Inst::Select {
+                len,
+                default: expr_select.default_branch.is_some(),
+            },
+            span,
+        );
 
         for (branch, (label, _)) in branches.iter().enumerate() {
             self.asm.jump_if_branch(branch as i64, *label, span);
@@ -55,10 +62,9 @@ impl Compile<(&ast::ExprSelect, Needs)> for Compiler<'_> {
             loop {
                 match &branch.pat {
                     ast::Pat::PatPath(path) => {
-                        let item = self.convert_path_to_item(&path.path)?;
-
-                        if let Some(local) = item.as_local() {
-                            scope.decl_var(local, span);
+                        if let Some(local) = path.path.try_as_ident() {
+                            let local = local.resolve_local(self.storage, &*self.source)?;
+                            scope.decl_var(&local, span);
                             break;
                         }
                     }
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 
 /// Compile `self`.
 impl Compile<(&ast::Self_, Needs)> for Compiler<'_> {
@@ -0,0 +1,47 @@
+use crate::ast;
+use crate::compiler::{Compiler, Needs};
+use crate::error::CompileResult;
+use crate::traits::Compile;
+use crate::Spanned;
+use runestick::Inst;
+
+/// Compile a slice expression.
+impl Compile<(&ast::ExprSlice, Needs)> for Compiler<'_> {
+    fn compile(&mut self, (expr_slice, needs): (&ast::ExprSlice, Needs)) -> CompileResult<()> {
+        let span = expr_slice.span();
+        log::trace!("ExprSlice => {:?}", self.source.source(span));
+
+        let scope = self.scopes.last(span)?.child();
+        let guard = self.scopes.push(scope);
+
+        if let Some(from) = &expr_slice.from {
+            self.compile((&**from, Needs::Value))?;
+            self.scopes.decl_anon(span)?;
+        }
+
+        if let Some(to) = &expr_slice.to {
+            self.compile((&**to, Needs::Value))?;
+            self.scopes.decl_anon(span)?;
+        }
+
+        self.compile((&*expr_slice.target, Needs::Value))?;
+        self.scopes.decl_anon(span)?;
+
+        self.asm.push(
+            Inst::Slice {
+                from: expr_slice.from.is_some(),
+                to: expr_slice.to.is_some(),
+            },
+            span,
+        );
+
+        // NB: we still need to perform the operation since it might have side
+        // effects, but pop the result in case a value is not needed.
+        if !needs.value() {
+            self.asm.push(Inst::Pop, span);
+        }
+
+        self.scopes.pop(guard, span)?;
+        Ok(())
+    }
+}
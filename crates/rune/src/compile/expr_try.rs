@@ -2,9 +2,13 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
+use crate::Spanned;
 use runestick::Inst;
 
 /// Compile a try expression.
+///
+/// This returns early from the current function if the operand is an `Err` or
+/// `None`, and unwraps it otherwise.
 impl Compile<(&ast::ExprTry, Needs)> for Compiler<'_> {
     fn compile(&mut self, (expr_try, needs): (&ast::ExprTry, Needs)) -> CompileResult<()> {
         let span = expr_try.span();
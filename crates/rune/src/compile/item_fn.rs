@@ -2,7 +2,7 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
-use crate::{traits::Resolve as _, CompileError};
+use crate::{CompileError, Spanned};
 use runestick::Inst;
 
 impl Compile<(ast::ItemFn, bool)> for Compiler<'_> {
@@ -26,7 +26,7 @@ impl Compile<(ast::ItemFn, bool)> for Compiler<'_> {
                 }
                 ast::FnArg::Ident(ident) => {
                     let span = ident.span();
-                    let name = ident.resolve(&self.storage, &*self.source)?;
+                    let name = ident.resolve_local(&self.storage, &*self.source)?;
                     self.scopes.last_mut(span)?.new_var(name.as_ref(), span)?;
                 }
                 ast::FnArg::Ignore(ignore) => {
@@ -0,0 +1,25 @@
+use crate::ast;
+use crate::compiler::{Compiler, Needs};
+use crate::error::CompileResult;
+use crate::traits::Compile;
+use crate::Spanned;
+use runestick::{Hash, Inst};
+
+/// Compile the initializer of a static.
+impl Compile<&ast::ItemStatic> for Compiler<'_> {
+    fn compile(&mut self, item_static: &ast::ItemStatic) -> CompileResult<()> {
+        let span = item_static.span();
+        log::trace!("ItemStatic => {:?}", self.source.source(span));
+
+        let scope = self.scopes.last(span)?.child();
+        let guard = self.scopes.push(scope);
+
+        self.compile((&*item_static.expr, Needs::Value))?;
+        self.clean_last_scope(span, guard, Needs::Value)?;
+
+        let hash = Hash::type_hash(&self.items.item());
+        self.asm.push(Inst::StaticStore { hash }, span);
+        self.asm.push(Inst::Return, span);
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use crate::ast;
 use crate::collections::{HashMap, HashSet};
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
+use crate::Spanned;
 use crate::{
     traits::{Compile, Resolve as _},
     CompileError,
@@ -23,7 +24,7 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_> {
         let mut check_keys = Vec::new();
         let mut keys_dup = HashMap::new();
 
-        for assign in &lit_object.assignments {
+        for (assign, _) in &lit_object.assignments {
             let span = assign.span();
             let key = assign
                 .key
@@ -41,7 +42,7 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_> {
             }
         }
 
-        for assign in lit_object.assignments.iter() {
+        for (assign, _) in lit_object.assignments.iter() {
             let span = assign.span();
 
             if let Some((_, expr)) = &assign.assign {
@@ -53,7 +54,13 @@ impl Compile<(&ast::LitObject, Needs)> for Compiler<'_> {
                     self.asm.push(Inst::Pop, span);
                 }
             } else {
-                let key = assign.key.resolve(&self.storage, &*self.source)?;
+                let key = match &assign.key {
+                    ast::LitObjectKey::Ident(ident) => {
+                        ident.resolve_local(self.storage, &*self.source)?
+                    }
+                    key => key.resolve(&self.storage, &*self.source)?,
+                };
+
                 let var = self.scopes.get_var(&*key, span)?;
 
                 if needs.value() {
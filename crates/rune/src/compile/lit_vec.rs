@@ -2,7 +2,8 @@ use crate::ast;
 use crate::compiler::{Compiler, Needs};
 use crate::error::CompileResult;
 use crate::traits::Compile;
-use runestick::Inst;
+use crate::Spanned;
+use runestick::{Inst, Span};
 
 /// Compile a literal vector.
 impl Compile<(&ast::LitVec, Needs)> for Compiler<'_> {
@@ -15,9 +16,20 @@ impl Compile<(&ast::LitVec, Needs)> for Compiler<'_> {
             return Ok(());
         }
 
+        if has_spread(lit_vec.items.iter().map(|(expr, _)| expr)) {
+            compile_spread_vec(self, 0, lit_vec.items.iter().map(|(expr, _)| expr), span)?;
+
+            if !needs.value() {
+                self.warnings.not_used(self.source_id, span, self.context());
+                self.asm.push(Inst::Pop, span);
+            }
+
+            return Ok(());
+        }
+
         let count = lit_vec.items.len();
 
-        for expr in lit_vec.items.iter() {
+        for (expr, _) in lit_vec.items.iter() {
             self.compile((expr, Needs::Value))?;
 
             // Evaluate the expressions one by one, then pop them to cause any
@@ -37,3 +49,60 @@ impl Compile<(&ast::LitVec, Needs)> for Compiler<'_> {
         Ok(())
     }
 }
+
+/// Test if any of the given expressions are spread expressions.
+pub(crate) fn has_spread<'a, I>(items: I) -> bool
+where
+    I: IntoIterator<Item = &'a ast::Expr>,
+{
+    items
+        .into_iter()
+        .any(|expr| matches!(expr, ast::Expr::ExprSpread(..)))
+}
+
+/// Compile a sequence of expressions which might include spread expressions
+/// into a single vector.
+///
+/// `pending` is the number of values which have already been pushed on the
+/// stack that should be included first in the vector.
+///
+/// Consecutive regular expressions are collected into vectors, which are then
+/// concatenated with the spread vectors.
+pub(crate) fn compile_spread_vec<'a, I>(
+    compiler: &mut Compiler<'_>,
+    mut pending: usize,
+    items: I,
+    span: Span,
+) -> CompileResult<()>
+where
+    I: IntoIterator<Item = &'a ast::Expr>,
+{
+    let mut count = 0;
+
+    for expr in items {
+        match expr {
+            ast::Expr::ExprSpread(expr_spread) => {
+                if pending > 0 {
+                    compiler.asm.push(Inst::Vec { count: pending }, span);
+                    pending = 0;
+                    count += 1;
+                }
+
+                compiler.compile((&*expr_spread.expr, Needs::Value))?;
+                count += 1;
+            }
+            expr => {
+                compiler.compile((expr, Needs::Value))?;
+                pending += 1;
+            }
+        }
+    }
+
+    if pending > 0 || count == 0 {
+        compiler.asm.push(Inst::Vec { count: pending }, span);
+        count += 1;
+    }
+
+    compiler.asm.push(Inst::VecConcat { count }, span);
+    Ok(())
+}
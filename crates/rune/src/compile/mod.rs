@@ -19,11 +19,13 @@ mod expr_path;
 mod expr_return;
 mod expr_select;
 mod expr_self;
+mod expr_slice;
 mod expr_try;
 mod expr_unary;
 mod expr_while;
 mod expr_yield;
 mod item_fn;
+mod item_static;
 mod lit_bool;
 mod lit_byte;
 mod lit_byte_str;
@@ -0,0 +1,349 @@
+use crate::assembly::AssemblyInst;
+use crate::ast;
+use crate::collections::HashMap;
+use crate::compiler::CompiledFunction;
+use crate::error::CompileResult;
+use crate::query::{BuildEntry, Query};
+use crate::{CompileError, Options, ParseError, Sources, UnitBuilder, Warning, Warnings};
+use runestick::{CompileMeta, Component, Inst, Item, Source, Span, Unit};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash as _, Hasher as _};
+use std::sync::Arc;
+
+/// A cache used to avoid redundant work when a collection of sources is
+/// loaded repeatedly through [load_sources_cached][crate::load_sources_cached].
+///
+/// The cache keeps the parsed syntax tree of every source keyed by a hash of
+/// its name and content, so that only sources which have changed are parsed
+/// again. The last compiled unit is also kept, and is reused as-is as long as
+/// none of the sources it was compiled from have changed.
+///
+/// Otherwise a new unit is built, reusing the compiled functions of sources
+/// which haven't changed. Each function is stored along with every item and
+/// import it looked up while it was compiled. A function is only reused if
+/// all of these lookups still produce the same result, so functions which
+/// depend on something declared in a changed source are compiled again. This
+/// includes lookups which previously found nothing, in case the changed
+/// source now declares the item being looked up.
+///
+/// A cache must only be used with a single [Context][runestick::Context]. Use
+/// [CompileCache::clear] if the context changes.
+#[derive(Debug, Default)]
+pub struct CompileCache {
+    /// Parsed files, keyed by the hash of their source.
+    files: HashMap<u64, ast::File>,
+    /// The last compiled unit.
+    unit: Option<CachedUnit>,
+    /// Functions compiled for the unit which is currently being built.
+    functions: HashMap<Item, CachedFunction>,
+    /// The number of functions reused by the last build.
+    reused: usize,
+}
+
+impl CompileCache {
+    /// Construct a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear everything stored in the cache.
+    pub fn clear(&mut self) {
+        self.files.clear();
+        self.unit = None;
+        self.functions.clear();
+        self.reused = 0;
+    }
+
+    /// The number of functions which were reused instead of being compiled
+    /// again the last time a new unit was built using this cache.
+    pub fn reused_functions(&self) -> usize {
+        self.reused
+    }
+
+    /// Prepare the cache for building a new unit.
+    pub(crate) fn begin(&mut self) {
+        self.functions.clear();
+        self.reused = 0;
+    }
+
+    /// Calculate the fingerprint for the given sources and options.
+    pub(crate) fn fingerprint(options: &Options, sources: &Sources) -> Fingerprint {
+        Fingerprint {
+            options: *options,
+            sources: sources.iter().map(hash_source).collect(),
+        }
+    }
+
+    /// Lookup the unit and warnings compiled for the given fingerprint, if
+    /// they are cached.
+    pub(crate) fn lookup(&self, fingerprint: &Fingerprint) -> Option<(Arc<Unit>, &Warnings)> {
+        match &self.unit {
+            Some(cached) if cached.fingerprint == *fingerprint => {
+                Some((cached.unit.clone(), &cached.warnings))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse the given source, reusing the syntax tree from an earlier parse
+    /// if the source hasn't changed.
+    pub(crate) fn parse(&mut self, source: &Source) -> Result<ast::File, ParseError> {
+        let hash = hash_source(source);
+
+        if let Some(file) = self.files.get(&hash) {
+            return Ok(file.clone());
+        }
+
+        let file = crate::parse_all::<ast::File>(source.as_str())?;
+        self.files.insert(hash, file.clone());
+        Ok(file)
+    }
+
+    /// Try to reuse the function compiled for the given entry when the last
+    /// unit was built, declaring it in the unit being built.
+    ///
+    /// Returns `false` if the entry has to be compiled again.
+    pub(crate) fn reuse(
+        &mut self,
+        options: &Options,
+        entry: &BuildEntry,
+        query: &mut Query,
+        warnings: &mut Warnings,
+    ) -> CompileResult<bool> {
+        let previous = match &self.unit {
+            Some(previous) if previous.fingerprint.options == *options => previous,
+            _ => return Ok(false),
+        };
+
+        let cached = match previous.functions.get(&entry.item) {
+            Some(cached) if cached.source == hash_source(&entry.source) => cached,
+            _ => return Ok(false),
+        };
+
+        // NB: replaying the lookups also queues up the items they depend on to
+        // be built, just like compiling the function would. Compilation is
+        // deterministic, so if the lookups are replayed up until the first one
+        // which differs, the same items are queued up as would have been had
+        // the entry been compiled again.
+        for lookup in &cached.lookups {
+            if !lookup.replay(query, cached.function.span)? {
+                return Ok(false);
+            }
+        }
+
+        let mut function = cached.function.clone();
+        let mut unit = query.unit.borrow_mut();
+        relocate(&mut function, entry.source_id, &previous.unit, &mut *unit)?;
+
+        let replayed = cached.warnings.iter().map(|warning| Warning {
+            source_id: entry.source_id,
+            kind: warning.kind,
+        });
+
+        warnings.extend(replayed);
+
+        self.functions.insert(
+            entry.item.clone(),
+            CachedFunction {
+                source: cached.source,
+                function: function.clone(),
+                lookups: cached.lookups.clone(),
+                warnings: cached.warnings.clone(),
+            },
+        );
+
+        function.declare(&mut *unit)?;
+        self.reused += 1;
+        Ok(true)
+    }
+
+    /// Insert a function which was compiled for the unit being built.
+    pub(crate) fn insert(
+        &mut self,
+        source: &Source,
+        function: &CompiledFunction,
+        lookups: Vec<Lookup>,
+        warnings: Vec<Warning>,
+    ) {
+        self.functions.insert(
+            function.item.clone(),
+            CachedFunction {
+                source: hash_source(source),
+                function: function.clone(),
+                lookups,
+                warnings,
+            },
+        );
+    }
+
+    /// Store the compiled unit for the given sources, and discard parsed files
+    /// which are no longer used by them.
+    pub(crate) fn store(
+        &mut self,
+        options: &Options,
+        sources: &Sources,
+        unit: Arc<Unit>,
+        warnings: Warnings,
+    ) {
+        // NB: the fingerprint is calculated after compilation, so that it
+        // covers any sources which were loaded while compiling.
+        let fingerprint = Self::fingerprint(options, sources);
+
+        let used = &fingerprint.sources;
+        self.files.retain(|hash, _| used.contains(hash));
+
+        self.unit = Some(CachedUnit {
+            fingerprint,
+            unit,
+            warnings,
+            functions: std::mem::take(&mut self.functions),
+        });
+    }
+}
+
+/// The fingerprint of a collection of sources and the options they were
+/// compiled with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Fingerprint {
+    options: Options,
+    sources: Vec<u64>,
+}
+
+/// A compiled unit stored in the cache.
+#[derive(Debug)]
+struct CachedUnit {
+    fingerprint: Fingerprint,
+    unit: Arc<Unit>,
+    warnings: Warnings,
+    /// Functions compiled for the unit, keyed by their item.
+    functions: HashMap<Item, CachedFunction>,
+}
+
+/// A function stored in the cache.
+#[derive(Debug)]
+struct CachedFunction {
+    /// Hash of the source the function was compiled from.
+    source: u64,
+    /// The compiled function, which refers to the static slots of the unit it
+    /// was compiled for.
+    function: CompiledFunction,
+    /// Lookups performed while compiling the function.
+    lookups: Vec<Lookup>,
+    /// Warnings emitted while compiling the function.
+    warnings: Vec<Warning>,
+}
+
+/// A lookup performed while compiling a function, which it depends on.
+#[derive(Debug, Clone)]
+pub(crate) enum Lookup {
+    /// The meta queried for an item.
+    Meta {
+        item: Item,
+        meta: Option<CompileMeta>,
+    },
+    /// An import resolved by name from a base item.
+    Import {
+        base: Item,
+        local: Component,
+        item: Option<Item>,
+    },
+}
+
+impl Lookup {
+    /// Perform the lookup again, testing if it produces the same result.
+    fn replay(&self, query: &mut Query, span: Span) -> CompileResult<bool> {
+        Ok(match self {
+            Self::Meta { item, meta } => query.query_meta(item, span)? == *meta,
+            Self::Import { base, local, item } => {
+                query.unit.borrow().lookup_import_by_name(base, local) == *item
+            }
+        })
+    }
+}
+
+/// Relocate a function compiled for the previous unit into the given unit
+/// being built, where its source has the given id.
+fn relocate(
+    function: &mut CompiledFunction,
+    source_id: usize,
+    previous: &Unit,
+    unit: &mut UnitBuilder,
+) -> CompileResult<()> {
+    let span = function.span;
+    function.source_id = source_id;
+
+    let assembly = &mut function.assembly;
+    assembly.source_id = source_id;
+
+    for (hash, spans) in &mut assembly.required_functions {
+        for (_, id) in spans.iter_mut() {
+            *id = source_id;
+        }
+
+        let called = previous
+            .debug_info()
+            .and_then(|debug| debug.called_functions.get(hash));
+
+        if let Some(item) = called {
+            unit.insert_called_function(*hash, item.clone());
+        }
+    }
+
+    for (inst, _) in &mut assembly.instructions {
+        if let AssemblyInst::Raw { raw } = inst {
+            relocate_inst(raw, previous, unit, span)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Move the static value referenced by the given instruction from the
+/// previous unit into the given unit being built.
+fn relocate_inst(
+    inst: &mut Inst,
+    previous: &Unit,
+    unit: &mut UnitBuilder,
+    span: Span,
+) -> CompileResult<()> {
+    match inst {
+        Inst::String { slot }
+        | Inst::EqStaticString { slot }
+        | Inst::ObjectSlotIndexGet { slot }
+        | Inst::ObjectSlotIndexGetAt { slot, .. } => {
+            let string = previous
+                .lookup_string(*slot)
+                .map_err(|_| CompileError::internal("missing cached static string", span))?;
+
+            *slot = unit.new_static_string(string.as_str())?;
+        }
+        Inst::Bytes { slot } => {
+            let bytes = previous
+                .lookup_bytes(*slot)
+                .map_err(|_| CompileError::internal("missing cached static bytes", span))?;
+
+            *slot = unit.new_static_bytes(bytes)?;
+        }
+        Inst::Object { slot }
+        | Inst::TypedObject { slot, .. }
+        | Inst::VariantObject { slot, .. }
+        | Inst::MatchObject { slot, .. } => {
+            let keys = previous
+                .lookup_object_keys(*slot)
+                .ok_or_else(|| CompileError::internal("missing cached object keys", span))?;
+
+            *slot = unit.new_static_object_keys(keys)?;
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Hash the name and content of a source.
+fn hash_source(source: &Source) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.name().hash(&mut hasher);
+    source.as_str().hash(&mut hasher);
+    hasher.finish()
+}
@@ -1,5 +1,6 @@
 use crate::ast;
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
+use crate::compile_cache::Lookup;
 use crate::compile_visitor::NoopCompileVisitor;
 use crate::error::CompileError;
 use crate::error::CompileResult;
@@ -7,14 +8,18 @@ use crate::index_scopes::IndexScopes;
 use crate::items::Items;
 use crate::loops::Loops;
 use crate::query::{Build, BuildEntry, Query};
-use crate::scopes::{Scope, ScopeGuard, Scopes};
+use crate::scopes::{Constant, Scope, ScopeGuard, Scopes};
 use crate::traits::Compile as _;
+use crate::unit_builder::UnitBuilderError;
 use crate::worker::{Expanded, IndexAst, Task, Worker};
+use crate::Spanned;
 use crate::{
-    Assembly, CompileVisitor, LoadError, LoadErrorKind, Options, Resolve as _, Sources, Storage,
-    UnitBuilder, Warnings,
+    Assembly, CompileCache, CompileVisitor, LoadError, LoadErrorKind, Options, Resolve as _,
+    Sources, Storage, UnitBuilder, Warnings,
+};
+use runestick::{
+    Call, CompileMeta, Component, Context, Hash, Inst, Item, Label, Source, Span, Type, TypeCheck,
 };
-use runestick::{CompileMeta, Context, Inst, Item, Label, Source, Span, TypeCheck};
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::rc::Rc;
@@ -64,6 +69,20 @@ pub fn compile_with_options(
     warnings: &mut Warnings,
     options: &Options,
     visitor: &mut dyn CompileVisitor,
+) -> Result<(), LoadError> {
+    compile_with_cache(context, sources, unit, warnings, options, visitor, None)
+}
+
+/// Encode the given object into a collection of asm, reusing what's stored in
+/// the given cache if one is provided.
+pub(crate) fn compile_with_cache(
+    context: &Context,
+    sources: &mut Sources,
+    unit: &Rc<RefCell<UnitBuilder>>,
+    warnings: &mut Warnings,
+    options: &Options,
+    visitor: &mut dyn CompileVisitor,
+    mut cache: Option<&mut CompileCache>,
 ) -> Result<(), LoadError> {
     // Global storage.
     let storage = Storage::new();
@@ -76,7 +95,12 @@ pub fn compile_with_options(
             None => return Err(LoadError::internal("missing queued source by id")),
         };
 
-        let file = match crate::parse_all::<ast::File>(source.as_str()) {
+        let parsed = match cache.as_deref_mut() {
+            Some(cache) => cache.parse(&*source),
+            None => crate::parse_all::<ast::File>(source.as_str()),
+        };
+
+        let file = match parsed {
             Ok(file) => file,
             Err(error) => {
                 return Err(LoadError::from(LoadErrorKind::ParseError {
@@ -126,6 +150,7 @@ pub fn compile_with_options(
             entry,
             expanded: &worker.expanded,
             visitor,
+            cache: cache.as_deref_mut(),
         }) {
             return Err(LoadError::from(LoadErrorKind::CompileError {
                 source_id,
@@ -147,6 +172,7 @@ struct CompileEntryArgs<'a> {
     entry: BuildEntry,
     expanded: &'a HashMap<Item, Expanded>,
     visitor: &'a mut dyn CompileVisitor,
+    cache: Option<&'a mut CompileCache>,
 }
 
 fn compile_entry(args: CompileEntryArgs<'_>) -> Result<(), CompileError> {
@@ -160,8 +186,19 @@ fn compile_entry(args: CompileEntryArgs<'_>) -> Result<(), CompileError> {
         entry,
         expanded,
         visitor,
+        mut cache,
     } = args;
 
+    if let Some(cache) = cache.as_deref_mut() {
+        if cache.reuse(options, &entry, query, warnings)? {
+            return Ok(());
+        }
+
+        query.lookups = Some(Vec::new());
+    }
+
+    let warnings_start = warnings.len();
+
     let BuildEntry {
         item,
         build,
@@ -187,19 +224,37 @@ fn compile_entry(args: CompileEntryArgs<'_>) -> Result<(), CompileError> {
         warnings,
         expanded,
         visitor,
+        reassigned: None,
     };
 
-    match build {
+    let function = match build {
         Build::Function(f) => {
             let args = format_fn_args(storage, &*source, f.ast.args.items.iter().map(|(a, _)| a))?;
 
             let span = f.ast.span();
             let count = f.ast.args.items.len();
             compiler.contexts.push(span);
+            compiler.reassigned = f.reassigned;
             compiler.compile((f.ast, false))?;
 
-            unit.borrow_mut()
-                .new_function(source_id, item, count, asm, f.call, args)?;
+            // NB: only immediate functions run in the frame of their caller,
+            // generators and async functions are suspended when called.
+            if options.tail_calls && matches!(f.call, Call::Immediate) {
+                asm.tail_calls(Hash::type_hash(&item), count)?;
+            }
+
+            asm.optimize(options);
+
+            CompiledFunction {
+                source_id,
+                span,
+                item,
+                instance: None,
+                args: count,
+                assembly: asm,
+                call: f.call,
+                debug_args: args,
+            }
         }
         Build::InstanceFunction(f) => {
             let args = format_fn_args(storage, &*source, f.ast.args.items.iter().map(|(a, _)| a))?;
@@ -225,18 +280,20 @@ fn compile_entry(args: CompileEntryArgs<'_>) -> Result<(), CompileError> {
                         span,
                     })?;
 
+            compiler.reassigned = f.reassigned;
             compiler.compile((f.ast, true))?;
+            asm.optimize(options);
 
-            unit.borrow_mut().new_instance_function(
+            CompiledFunction {
                 source_id,
+                span,
                 item,
-                value_type,
-                name.as_ref(),
-                count,
-                asm,
-                f.call,
-                args,
-            )?;
+                instance: Some((value_type, name.to_string())),
+                args: count,
+                assembly: asm,
+                call: f.call,
+                debug_args: args,
+            }
         }
         Build::Closure(c) => {
             let args = format_fn_args(
@@ -249,30 +306,144 @@ fn compile_entry(args: CompileEntryArgs<'_>) -> Result<(), CompileError> {
             let count = c.ast.args.len();
             compiler.contexts.push(span);
             compiler.compile((c.ast, &c.captures[..]))?;
+            asm.optimize(options);
 
-            unit.borrow_mut()
-                .new_function(source_id, item, count, asm, c.call, args)?;
+            CompiledFunction {
+                source_id,
+                span,
+                item,
+                instance: None,
+                args: count,
+                assembly: asm,
+                call: c.call,
+                debug_args: args,
+            }
         }
         Build::AsyncBlock(async_block) => {
             let span = async_block.ast.span();
             let args = async_block.captures.len();
             compiler.contexts.push(span);
             compiler.compile((&async_block.ast, &async_block.captures[..]))?;
+            asm.optimize(options);
 
-            unit.borrow_mut().new_function(
+            CompiledFunction {
                 source_id,
+                span,
                 item,
+                instance: None,
                 args,
-                asm,
-                async_block.call,
-                Vec::new(),
-            )?;
+                assembly: asm,
+                call: async_block.call,
+                debug_args: Vec::new(),
+            }
+        }
+        Build::Static(s) => {
+            let span = s.ast.span();
+            compiler.contexts.push(span);
+            compiler.compile(&s.ast)?;
+            asm.optimize(options);
+
+            CompiledFunction {
+                source_id,
+                span,
+                item,
+                instance: None,
+                args: 0,
+                assembly: asm,
+                call: Call::Immediate,
+                debug_args: Vec::new(),
+            }
         }
+    };
+
+    if let Some(cache) = cache {
+        let lookups = query.lookups.take().unwrap_or_default();
+        let warnings = warnings.iter().skip(warnings_start).copied().collect();
+        cache.insert(&source, &function, lookups, warnings);
     }
 
+    function.declare(&mut *unit.borrow_mut())?;
     Ok(())
 }
 
+/// A function compiled from a build entry, which is declared in the unit once
+/// it's complete.
+#[derive(Debug, Clone)]
+pub(crate) struct CompiledFunction {
+    pub(crate) source_id: usize,
+    pub(crate) span: Span,
+    pub(crate) item: Item,
+    /// The type and name of the function, if it's an instance function.
+    pub(crate) instance: Option<(Type, String)>,
+    pub(crate) args: usize,
+    pub(crate) assembly: Assembly,
+    pub(crate) call: Call,
+    pub(crate) debug_args: Vec<String>,
+}
+
+impl CompiledFunction {
+    /// Declare the function in the given unit.
+    pub(crate) fn declare(self, unit: &mut UnitBuilder) -> Result<(), UnitBuilderError> {
+        match self.instance {
+            Some((value_type, name)) => unit.new_instance_function(
+                self.source_id,
+                self.span,
+                self.item,
+                value_type,
+                &name,
+                self.args,
+                self.assembly,
+                self.call,
+                self.debug_args,
+            ),
+            None => unit.new_function(
+                self.source_id,
+                self.span,
+                self.item,
+                self.args,
+                self.assembly,
+                self.call,
+                self.debug_args,
+            ),
+        }
+    }
+}
+
+/// Evaluate a binary operation over two constants.
+fn const_binop(op: ast::BinOp, lhs: Constant, rhs: Constant) -> Option<Constant> {
+    use Constant::{Bool, Float, Integer};
+
+    Some(match (op, lhs, rhs) {
+        (ast::BinOp::Add, Integer(a), Integer(b)) => Integer(a.checked_add(b)?),
+        (ast::BinOp::Sub, Integer(a), Integer(b)) => Integer(a.checked_sub(b)?),
+        (ast::BinOp::Mul, Integer(a), Integer(b)) => Integer(a.checked_mul(b)?),
+        (ast::BinOp::Div, Integer(a), Integer(b)) => Integer(a.checked_div(b)?),
+        (ast::BinOp::Rem, Integer(a), Integer(b)) => Integer(a.checked_rem(b)?),
+        (ast::BinOp::Add, Float(a), Float(b)) => Float(a + b),
+        (ast::BinOp::Sub, Float(a), Float(b)) => Float(a - b),
+        (ast::BinOp::Mul, Float(a), Float(b)) => Float(a * b),
+        (ast::BinOp::Div, Float(a), Float(b)) => Float(a / b),
+        (ast::BinOp::Rem, Float(a), Float(b)) => Float(a % b),
+        (ast::BinOp::Lt, Integer(a), Integer(b)) => Bool(a < b),
+        (ast::BinOp::Gt, Integer(a), Integer(b)) => Bool(a > b),
+        (ast::BinOp::Lte, Integer(a), Integer(b)) => Bool(a <= b),
+        (ast::BinOp::Gte, Integer(a), Integer(b)) => Bool(a >= b),
+        (ast::BinOp::Lt, Float(a), Float(b)) => Bool(a < b),
+        (ast::BinOp::Gt, Float(a), Float(b)) => Bool(a > b),
+        (ast::BinOp::Lte, Float(a), Float(b)) => Bool(a <= b),
+        (ast::BinOp::Gte, Float(a), Float(b)) => Bool(a >= b),
+        (ast::BinOp::Eq, Integer(a), Integer(b)) => Bool(a == b),
+        (ast::BinOp::Neq, Integer(a), Integer(b)) => Bool(a != b),
+        (ast::BinOp::Eq, Float(a), Float(b)) => Bool(a == b),
+        (ast::BinOp::Neq, Float(a), Float(b)) => Bool(a != b),
+        (ast::BinOp::Eq, Bool(a), Bool(b)) => Bool(a == b),
+        (ast::BinOp::Neq, Bool(a), Bool(b)) => Bool(a != b),
+        (ast::BinOp::And, Bool(a), Bool(b)) => Bool(a && b),
+        (ast::BinOp::Or, Bool(a), Bool(b)) => Bool(a || b),
+        _ => return None,
+    })
+}
+
 fn format_fn_args<'a, I>(
     storage: &Storage,
     source: &Source,
@@ -358,6 +529,9 @@ pub(crate) struct Compiler<'a> {
     pub(crate) warnings: &'a mut Warnings,
     /// Compiler visitor.
     pub(crate) visitor: &'a mut dyn CompileVisitor,
+    /// Declarations of local variables which are reassigned in the function
+    /// being compiled. Constants are only propagated if this is known.
+    pub(crate) reassigned: Option<HashSet<Span>>,
 }
 
 impl<'a> Compiler<'a> {
@@ -473,6 +647,14 @@ impl<'a> Compiler<'a> {
                     self.asm
                         .push_with_comment(Inst::Fn { hash }, span, format!("fn `{}`", item));
                 }
+                CompileMeta::Static { item } => {
+                    let hash = Hash::type_hash(item);
+                    self.asm.push_with_comment(
+                        Inst::Static { hash },
+                        span,
+                        format!("static `{}`", item),
+                    );
+                }
                 meta => {
                     return Err(CompileError::UnsupportedValue {
                         span,
@@ -497,11 +679,22 @@ impl<'a> Compiler<'a> {
     }
 
     /// Convert a path to an item.
-    pub(crate) fn convert_path_to_item(&self, path: &ast::Path) -> CompileResult<Item> {
+    pub(crate) fn convert_path_to_item(&mut self, path: &ast::Path) -> CompileResult<Item> {
         let base = self.items.item();
-        self.unit
-            .borrow()
-            .convert_path(&base, path, &self.storage, &*self.source)
+        let unit = self.unit.borrow();
+
+        if self.query.lookups.is_some() {
+            let local = Component::from(path.first.resolve(self.storage, &*self.source)?.as_ref());
+            let item = unit.lookup_import_by_name(&base, &local);
+
+            self.query.record(Lookup::Import {
+                base: base.clone(),
+                local,
+                item,
+            });
+        }
+
+        unit.convert_path(&base, path, &self.storage, &*self.source)
     }
 
     pub(crate) fn compile_condition(
@@ -805,7 +998,7 @@ impl<'a> Compiler<'a> {
             };
 
             load(&mut self.asm);
-            let name = ident.resolve(&self.storage, &*self.source)?;
+            let name = ident.resolve_local(self.storage, &*self.source)?;
             scope.decl_var(name.as_ref(), span);
         }
 
@@ -852,6 +1045,75 @@ impl<'a> Compiler<'a> {
         Ok(true)
     }
 
+    /// Test if the local variable declared at the given span is never
+    /// reassigned, so that a constant can be propagated through it.
+    pub(crate) fn is_immutable(&self, span: Span) -> bool {
+        if !self.options.const_propagation {
+            return false;
+        }
+
+        match &self.reassigned {
+            Some(reassigned) => !reassigned.contains(&span),
+            None => false,
+        }
+    }
+
+    /// Try to evaluate the given expression at compile time.
+    ///
+    /// Only literals, variables holding a constant, and arithmetic or
+    /// comparisons over them are evaluated. Operations which would fail at
+    /// runtime, like an overflow or a division by zero, are left for the
+    /// virtual machine to report.
+    pub(crate) fn const_eval(&self, expr: &ast::Expr) -> CompileResult<Option<Constant>> {
+        if !self.options.const_propagation {
+            return Ok(None);
+        }
+
+        let constant = match expr {
+            ast::Expr::LitBool(lit_bool) => Constant::Bool(lit_bool.value),
+            ast::Expr::LitNumber(lit_number) => {
+                match lit_number.resolve(self.storage, &*self.source)? {
+                    ast::Number::Integer(number) => Constant::Integer(number),
+                    ast::Number::Float(number) => Constant::Float(number),
+                }
+            }
+            ast::Expr::ExprGroup(expr_group) => return self.const_eval(&*expr_group.expr),
+            ast::Expr::Path(path) => {
+                let ident = match path.try_as_ident() {
+                    Some(ident) => ident.resolve_local(self.storage, &*self.source)?,
+                    None => return Ok(None),
+                };
+
+                return Ok(self
+                    .scopes
+                    .try_get_var(&ident)?
+                    .and_then(|var| var.constant));
+            }
+            ast::Expr::ExprBinary(expr_binary) => return self.const_eval_binary(expr_binary),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(constant))
+    }
+
+    /// Try to evaluate the given binary expression at compile time.
+    pub(crate) fn const_eval_binary(
+        &self,
+        expr_binary: &ast::ExprBinary,
+    ) -> CompileResult<Option<Constant>> {
+        let lhs = match self.const_eval(&*expr_binary.lhs)? {
+            Some(lhs) => lhs,
+            None => return Ok(None),
+        };
+
+        let rhs = match self.const_eval(&*expr_binary.rhs)? {
+            Some(rhs) => rhs,
+            None => return Ok(None),
+        };
+
+        Ok(const_binop(expr_binary.op, lhs, rhs))
+    }
+
     /// Encode a pattern.
     ///
     /// Patterns will clean up their own locals and execute a jump to
@@ -880,8 +1142,8 @@ impl<'a> Compiler<'a> {
                     }
                 }
 
-                let ident = match item.as_local() {
-                    Some(ident) => ident,
+                let ident = match path.path.try_as_ident() {
+                    Some(ident) => ident.resolve_local(self.storage, &*self.source)?,
                     None => {
                         return Err(CompileError::UnsupportedBinding { span });
                     }
@@ -0,0 +1,212 @@
+use crate::collections::HashSet;
+use crate::error::ConfigurationError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A group of native functions which can be denied when building a context
+/// through [ContextBuilder].
+///
+/// Packages are only installed if rune is built with the `modules` feature,
+/// denying a package which isn't available has no effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Package {
+    /// The `http` package.
+    Http,
+    /// The `json` package.
+    Json,
+    /// The `toml` package.
+    Toml,
+    /// The `time` package.
+    Time,
+    /// The `process` package, which can spawn processes.
+    Process,
+    /// The `fs` package, which can access the filesystem.
+    Fs,
+    /// The `signal` package.
+    Signal,
+    /// The `env` package, which can access the environment of the process.
+    Env,
+}
+
+impl Package {
+    /// All available packages.
+    pub const ALL: [Package; 8] = [
+        Package::Http,
+        Package::Json,
+        Package::Toml,
+        Package::Time,
+        Package::Process,
+        Package::Fs,
+        Package::Signal,
+        Package::Env,
+    ];
+
+    /// Get the name of the package.
+    pub fn name(self) -> &'static str {
+        match self {
+            Package::Http => "http",
+            Package::Json => "json",
+            Package::Toml => "toml",
+            Package::Time => "time",
+            Package::Process => "process",
+            Package::Fs => "fs",
+            Package::Signal => "signal",
+            Package::Env => "env",
+        }
+    }
+
+    #[cfg(feature = "modules")]
+    fn module(self) -> Result<runestick::Module, runestick::ContextError> {
+        match self {
+            Package::Http => rune_modules::http::module(),
+            Package::Json => rune_modules::json::module(),
+            Package::Toml => rune_modules::toml::module(),
+            Package::Time => rune_modules::time::module(),
+            Package::Process => rune_modules::process::module(),
+            Package::Fs => rune_modules::fs::module(),
+            Package::Signal => rune_modules::signal::module(),
+            Package::Env => rune_modules::env::module(),
+        }
+    }
+}
+
+impl fmt::Display for Package {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Package {
+    type Err = ConfigurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Package::ALL.iter().find(|p| p.name() == s) {
+            Some(package) => Ok(*package),
+            None => Err(ConfigurationError::UnsupportedPackage {
+                package: s.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Builder for a runestick context, which allows for denying access to
+/// packages before the context is built.
+///
+/// This makes it possible to run untrusted scripts without access to the
+/// filesystem, processes, or the environment.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{ContextBuilder, Package};
+///
+/// # fn main() -> runestick::Result<()> {
+/// let context = ContextBuilder::new()
+///     .deny(Package::Fs)
+///     .deny(Package::Process)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ContextBuilder {
+    denied: HashSet<Package>,
+}
+
+impl ContextBuilder {
+    /// Construct a new context builder, which allows all packages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deny the given package from being installed.
+    pub fn deny(&mut self, package: Package) -> &mut Self {
+        self.denied.insert(package);
+        self
+    }
+
+    /// Parse and deny a comma-separated list of packages, like `fs,process`.
+    ///
+    /// This is used by among other things the Rune CLI with the
+    /// `--deny <package>[,<package>]` option.
+    pub fn deny_list(&mut self, packages: &str) -> Result<&mut Self, ConfigurationError> {
+        for package in packages.split(',').filter(|p| !p.is_empty()) {
+            self.deny(package.trim().parse()?);
+        }
+
+        Ok(self)
+    }
+
+    /// Test if the given package is allowed.
+    pub fn is_allowed(&self, package: Package) -> bool {
+        !self.denied.contains(&package)
+    }
+
+    /// Build the context, installing every package which hasn't been
+    /// denied.
+    pub fn build(&self) -> Result<runestick::Context, runestick::ContextError> {
+        #[allow(unused_mut)]
+        let mut context = runestick::Context::with_default_modules()?;
+
+        #[cfg(feature = "modules")]
+        {
+            for package in Package::ALL.iter().copied() {
+                if self.is_allowed(package) {
+                    context.install(&package.module()?)?;
+                }
+            }
+        }
+
+        Ok(context)
+    }
+}
+
+#[cfg(all(test, feature = "modules"))]
+mod tests {
+    use super::{ContextBuilder, Package};
+    use crate::{LinkerError, LoadErrorKind, Options, Sources, Warnings};
+    use runestick::{Item, Source};
+
+    fn load(context: &runestick::Context) -> Result<runestick::Unit, crate::LoadError> {
+        let mut sources = Sources::new();
+        let mut warnings = Warnings::new();
+
+        sources.insert_default(Source::new(
+            "main",
+            r#"fn main() { fs::read_to_string("Cargo.toml") }"#,
+        ));
+
+        crate::load_sources(context, &Options::default(), &mut sources, &mut warnings)
+    }
+
+    #[test]
+    fn test_denied_function_is_not_resolvable() {
+        let context = ContextBuilder::new().build().unwrap();
+        assert!(load(&context).is_ok());
+
+        let context = ContextBuilder::new()
+            .deny_list("fs,process")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        match load(&context).unwrap_err().into_kind() {
+            LoadErrorKind::LinkError { errors } => {
+                let items = errors
+                    .errors()
+                    .map(|error| match error {
+                        LinkerError::MissingFunction { item, .. } => item,
+                    })
+                    .collect::<Vec<_>>();
+
+                assert_eq!(items, vec![Some(Item::of(&["fs", "read_to_string"]))]);
+            }
+            kind => panic!("expected missing function but was `{:?}`", kind),
+        }
+
+        assert!(!ContextBuilder::new()
+            .deny(Package::Env)
+            .is_allowed(Package::Env));
+        assert!("network".parse::<Package>().is_err());
+    }
+}
@@ -1,8 +1,9 @@
 //! Runtime helpers for loading code and emitting diagnostics.
 
+use crate::collections::HashMap;
 use crate::unit_builder::LinkerError;
 use crate::{CompileError, LoadError, LoadErrorKind, ParseError, Sources, WarningKind, Warnings};
-use runestick::{Span, VmError};
+use runestick::{Span, Unit, VmError};
 use std::error::Error as _;
 use std::fmt;
 use std::fmt::Write as _;
@@ -148,12 +149,6 @@ impl EmitDiagnostics for VmError {
     where
         O: WriteColor,
     {
-        let mut files = SimpleFiles::new();
-
-        for source in sources.iter() {
-            files.add(source.name(), source.as_str());
-        }
-
         let (error, unwound) = self.into_unwound();
 
         let (unit, ip) = match unwound {
@@ -190,6 +185,18 @@ impl EmitDiagnostics for VmError {
             }
         };
 
+        let mut files = SimpleFiles::new();
+
+        // NB: sources which are missing are taken from the ones embedded in
+        // the unit, if any.
+        for source_id in 0.. {
+            match (sources.get(source_id), debug_info.source_at(source_id)) {
+                (Some(source), _) => files.add(source.name(), source.as_str()),
+                (None, Some(source)) => files.add(source.name.as_str(), source.source.as_str()),
+                (None, None) => break,
+            };
+        }
+
         let config = codespan_reporting::term::Config::default();
 
         let mut labels = Vec::new();
@@ -237,7 +244,7 @@ impl EmitDiagnostics for LoadError {
             LoadErrorKind::LinkError { errors } => {
                 for error in errors {
                     match error {
-                        LinkerError::MissingFunction { hash, spans } => {
+                        LinkerError::MissingFunction { hash, item, spans } => {
                             let mut labels = Vec::new();
 
                             for (span, source_id) in spans {
@@ -247,11 +254,15 @@ impl EmitDiagnostics for LoadError {
                                 );
                             }
 
+                            let message = match item {
+                                Some(item) => format!("linker error: missing function `{}`", item),
+                                None => {
+                                    format!("linker error: missing function with hash `{}`", hash)
+                                }
+                            };
+
                             let diagnostic = Diagnostic::error()
-                                .with_message(format!(
-                                    "linker error: missing function with hash `{}`",
-                                    hash
-                                ))
+                                .with_message(message)
                                 .with_labels(labels);
 
                             term::emit(out, &config, &files, &diagnostic)?;
@@ -369,6 +380,10 @@ impl EmitDiagnostics for LoadError {
 }
 
 /// Get the line number and source line for the given source and span.
+///
+/// The line number is zero-based. A span pointing at the very end of the
+/// source, like the one of an unexpected end-of-file, belongs to the last
+/// line.
 pub fn line_for(source: &str, span: Span) -> Option<(usize, &str)> {
     let mut it = codespan_reporting::files::line_starts(source)
         .enumerate()
@@ -376,13 +391,159 @@ pub fn line_for(source: &str, span: Span) -> Option<(usize, &str)> {
 
     while let Some((line, start)) = it.next() {
         if let Some((_, end)) = it.peek().copied() {
-            if span.start > start && span.start <= end {
+            if span.start >= start && span.start < end {
                 return Some((line, &source[start..end]));
             }
-        } else if span.start < source.len() {
+        } else if span.start >= start && span.start <= source.len() {
             return Some((line, &source[start..]));
         }
     }
 
     None
 }
+
+/// Get the zero-based line and column of the start of the given span.
+///
+/// The column is counted in characters from the start of the line.
+pub fn line_column_for(source: &str, span: Span) -> Option<(usize, usize)> {
+    let (line, text) = line_for(source, span)?;
+    let line_start = text.as_ptr() as usize - source.as_ptr() as usize;
+    let column = source.get(line_start..span.start)?.chars().count();
+    Some((line, column))
+}
+
+/// Build a table mapping every instruction in the unit to the source id and
+/// zero-based line it was compiled from, as used to construct
+/// [Breakpoints][runestick::Breakpoints].
+///
+/// Instructions without debug information, or whose source is missing, map
+/// to `None`.
+pub fn instruction_lines(unit: &Unit, sources: &Sources) -> Vec<Option<(usize, usize)>> {
+    let debug = match unit.debug_info() {
+        Some(debug) => debug,
+        None => return Vec::new(),
+    };
+
+    let mut line_starts = HashMap::<usize, Option<Vec<usize>>>::new();
+    let mut lines = Vec::with_capacity(debug.instructions.len());
+
+    for inst in &debug.instructions {
+        let starts = line_starts.entry(inst.source_id).or_insert_with(|| {
+            let source = sources.get(inst.source_id)?;
+            Some(codespan_reporting::files::line_starts(source.as_str()).collect())
+        });
+
+        let line = starts.as_ref().map(|starts| {
+            let (source_id, start) = (inst.source_id, inst.span.start);
+
+            match starts.binary_search(&start) {
+                Ok(line) => (source_id, line),
+                Err(line) => (source_id, line.saturating_sub(1)),
+            }
+        });
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{line_column_for, line_for, EmitDiagnostics as _};
+    use crate::{Options, Sources, Warnings};
+    use runestick::{Context, Item, Source, Span, Unit, Vm};
+    use std::sync::Arc;
+
+    fn render(source: &str) -> String {
+        let context = Context::with_default_modules().unwrap();
+        let mut sources = Sources::new();
+        sources.insert_default(Source::new("script", source));
+
+        let error = crate::load_sources(
+            &context,
+            &Options::default(),
+            &mut sources,
+            &mut Warnings::disabled(),
+        )
+        .unwrap_err();
+
+        let mut out = super::termcolor::NoColor::new(Vec::new());
+        error.emit_diagnostics(&mut out, &sources).unwrap();
+        String::from_utf8(out.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_line_for() {
+        let source = "a\nbc\nd";
+        assert_eq!(line_for(source, Span::new(0, 1)), Some((0, "a\n")));
+        assert_eq!(line_for(source, Span::new(2, 3)), Some((1, "bc\n")));
+        assert_eq!(line_for(source, Span::new(5, 6)), Some((2, "d")));
+        assert_eq!(line_for(source, Span::point(6)), Some((2, "d")));
+        assert_eq!(line_column_for(source, Span::new(3, 4)), Some((1, 1)));
+        assert_eq!(line_column_for(source, Span::point(6)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_render_unexpected_eof() {
+        let output = render("fn main() {\n    let a = 1;\n");
+        assert!(output.contains("script:3:1"), "{}", output);
+        assert!(output.contains("unexpected end-of-file"), "{}", output);
+    }
+
+    #[test]
+    fn test_render_expected_expr() {
+        let output = render("fn main() {\n    let a = ;\n}\n");
+        assert!(output.contains("script:2:13"), "{}", output);
+        assert!(output.contains("2 │     let a = ;"), "{}", output);
+        assert!(
+            output.contains("^ expected start of expression but got `;`"),
+            "{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_render_unterminated_string() {
+        let output = render("fn main() {\n    \"abc\n}\n");
+        assert!(output.contains("script:2:5"), "{}", output);
+        assert!(output.contains("unterminated string literal"), "{}", output);
+    }
+
+    #[test]
+    fn test_render_embedded_sources() {
+        let context = Context::with_default_modules().unwrap();
+        let mut sources = Sources::new();
+        sources.insert_default(Source::new(
+            "script",
+            "fn main() {\n    let a = 1;\n    a / 0\n}\n",
+        ));
+
+        let mut options = Options::default();
+        options.embed_sources(true);
+
+        let unit = crate::load_sources(&context, &options, &mut sources, &mut Warnings::disabled())
+            .unwrap();
+
+        // NB: round-trip the unit the same way the CLI caches it, so that only
+        // the embedded sources survive.
+        let mut cached = Vec::new();
+        bincode::serialize_into(&mut cached, &unit).unwrap();
+        let unit = bincode::deserialize_from::<_, Unit>(&cached[..]).unwrap();
+
+        let vm = Vm::new(Arc::new(context), Arc::new(unit));
+        let error = vm
+            .call(&Item::of(&["main"]), ())
+            .unwrap()
+            .complete()
+            .unwrap_err();
+
+        // NB: the unit is rendered without its original sources.
+        let mut out = super::termcolor::NoColor::new(Vec::new());
+        error.emit_diagnostics(&mut out, &Sources::new()).unwrap();
+        let output = String::from_utf8(out.into_inner()).unwrap();
+
+        assert!(output.contains("script:3:5"), "{}", output);
+        assert!(output.contains("3 │     a / 0"), "{}", output);
+    }
+}
@@ -18,6 +18,18 @@ pub enum ConfigurationError {
         /// The unsupported option.
         option: String,
     },
+    /// Tried to use an optimization level which doesn't exist.
+    #[error("unsupported optimization level `{level}`, expected one of 0, 1, 2, or 3")]
+    UnsupportedOptLevel {
+        /// The unsupported level.
+        level: String,
+    },
+    /// Tried to deny a package which doesn't exist.
+    #[error("unsupported package `{package}`")]
+    UnsupportedPackage {
+        /// The unsupported package.
+        package: String,
+    },
 }
 
 /// Error when parsing.
@@ -259,6 +271,12 @@ pub enum ParseError {
         /// Span of the illegal number literal.
         span: Span,
     },
+    /// Tried to resolve a number literal with an unsupported type suffix.
+    #[error("number literal has an unsupported suffix, expected `i64` or `f64`")]
+    BadNumberSuffix {
+        /// Span of the number literal.
+        span: Span,
+    },
     /// Number out of bounds.
     #[error("number literal out of bounds `-9223372036854775808` to `9223372036854775807`")]
     BadNumberOutOfBounds {
@@ -404,6 +422,7 @@ impl ParseError {
             Self::BadSyntheticId { span, .. } => span,
             Self::BadEscapeSequence { span, .. } => span,
             Self::BadNumberLiteral { span, .. } => span,
+            Self::BadNumberSuffix { span, .. } => span,
             Self::BadNumberOutOfBounds { span, .. } => span,
             Self::BadCharLiteral { span, .. } => span,
             Self::BadByteLiteral { span, .. } => span,
@@ -463,6 +482,17 @@ pub enum CompileError {
         #[source]
         error: io::Error,
     },
+    /// Failed to read a file included with `include_str!`.
+    #[error("failed to include `{path}`: {error}")]
+    IncludeFileError {
+        /// Path of the file which failed to be included.
+        path: PathBuf,
+        /// The span of the path in the `include_str!` call.
+        span: Span,
+        /// The underlying error.
+        #[source]
+        error: io::Error,
+    },
     /// A module that has already been loaded.
     #[error("module `{item}` has already been loaded")]
     ModAlreadyLoaded {
@@ -596,6 +626,13 @@ pub enum CompileError {
         /// Where it occured.
         span: Span,
     },
+    /// An `if` expression without an `else` branch was used to produce a
+    /// value.
+    #[error("`if` expression producing a value is missing an `else` branch")]
+    IfWithoutElse {
+        /// The span of the `if` expression.
+        span: Span,
+    },
     /// Encountered a unary operator we can't encode.
     #[error("unsupported unary operator `{op}`")]
     UnsupportedUnaryOp {
@@ -604,6 +641,12 @@ pub enum CompileError {
         /// The operator.
         op: ast::UnaryOp,
     },
+    /// Tried to use a spread expression somewhere it's not supported.
+    #[error("spread expressions are only supported in vector literals and call arguments")]
+    UnsupportedSpread {
+        /// The span of the spread expression.
+        span: Span,
+    },
     /// Encountered a binary operator we can't encode.
     #[error("unsupported binary operator `{op}`")]
     UnsupportedBinaryOp {
@@ -795,6 +838,18 @@ pub enum CompileError {
         /// The span where the error happened.
         span: Span,
     },
+    /// Trying to include a file relative to an in-memory source.
+    #[error("cannot include files relative to in-memory sources")]
+    UnsupportedFileInclude {
+        /// The span where the error happened.
+        span: Span,
+    },
+    /// Trying to use an attribute which isn't supported.
+    #[error("unsupported attribute")]
+    UnsupportedAttribute {
+        /// The span of the unsupported attribute.
+        span: Span,
+    },
     /// Trying to use a number as a tuple index for which it is not suported.
     #[error("unsupported tuple index `{number}`")]
     UnsupportedTupleIndex {
@@ -832,6 +887,7 @@ impl CompileError {
             Self::Experimental { span, .. } => span,
             Self::ModNotFound { span, .. } => span,
             Self::ModFileError { span, .. } => span,
+            Self::IncludeFileError { span, .. } => span,
             Self::ModAlreadyLoaded { span, .. } => span,
             Self::ParseError { error, .. } => error.span(),
             Self::ItemConflict { span, .. } => span,
@@ -850,7 +906,9 @@ impl CompileError {
             Self::UnsupportedValue { span, .. } => span,
             Self::UnsupportedType { span, .. } => span,
             Self::UnsupportedSelf { span, .. } => span,
+            Self::IfWithoutElse { span, .. } => span,
             Self::UnsupportedUnaryOp { span, .. } => span,
+            Self::UnsupportedSpread { span, .. } => span,
             Self::UnsupportedBinaryOp { span, .. } => span,
             Self::UnsupportedLitObject { span, .. } => span,
             Self::UnsupportedAssignExpr { span, .. } => span,
@@ -875,6 +933,8 @@ impl CompileError {
             Self::MissingPreludeModule { .. } => Span::empty(),
             Self::UnsupportedAsyncExpr { span, .. } => span,
             Self::UnsupportedFileMod { span, .. } => span,
+            Self::UnsupportedFileInclude { span, .. } => span,
+            Self::UnsupportedAttribute { span, .. } => span,
             Self::UnsupportedTupleIndex { span, .. } => span,
         }
     }
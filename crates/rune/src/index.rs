@@ -3,13 +3,26 @@ use crate::collections::HashMap;
 use crate::error::{CompileError, CompileResult};
 use crate::index_scopes::IndexScopes;
 use crate::items::Items;
-use crate::query::{Build, BuildEntry, Function, Indexed, IndexedEntry, InstanceFunction, Query};
+use crate::query::{
+    Build, BuildEntry, Function, Indexed, IndexedEntry, InstanceFunction, Query, Static,
+};
 use crate::worker::{Import, Macro, MacroKind, Task};
-use crate::{Resolve as _, SourceId, Sources, Storage, Warnings};
+use crate::{Options, Resolve as _, SourceId, Sources, Spanned, Storage, Warnings};
 use runestick::{Call, CompileMeta, Hash, Item, Source, Span, Type};
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+/// An attribute understood by the compiler.
+enum Attribute {
+    /// `#[test]`, marking a function as a test.
+    Test,
+    /// `#[bench]`, marking a function as a benchmark.
+    Bench,
+    /// `#[export]`, marking a function as an entry point which is always
+    /// built.
+    Export,
+}
+
 pub(crate) struct Indexer<'a> {
     pub(crate) storage: Storage,
     pub(crate) loaded: &'a mut HashMap<Item, (SourceId, Span)>,
@@ -26,6 +39,8 @@ pub(crate) struct Indexer<'a> {
     pub(crate) scopes: IndexScopes,
     /// Set if we are inside of an impl block.
     pub(crate) impl_items: Vec<Item>,
+    /// Compiler options.
+    pub(crate) options: &'a Options,
 }
 
 impl<'a> Indexer<'a> {
@@ -44,6 +59,23 @@ impl<'a> Indexer<'a> {
         }
     }
 
+    /// Resolve an attribute into one understood by the compiler.
+    fn resolve_attribute(&self, attribute: &ast::Attribute) -> CompileResult<Attribute> {
+        let span = attribute.span();
+
+        let ident = match attribute.path.try_as_ident() {
+            Some(ident) => ident,
+            None => return Err(CompileError::UnsupportedAttribute { span }),
+        };
+
+        match ident.resolve(&self.storage, &*self.source)?.as_ref() {
+            "test" => Ok(Attribute::Test),
+            "bench" => Ok(Attribute::Bench),
+            "export" => Ok(Attribute::Export),
+            _ => Err(CompileError::UnsupportedAttribute { span }),
+        }
+    }
+
     /// Handle a filesystem module.
     pub(crate) fn handle_file_mod(&mut self, item_mod: &ast::ItemMod) -> CompileResult<()> {
         let span = item_mod.span();
@@ -137,7 +169,28 @@ impl Index<ast::ItemFn> for Indexer<'_> {
     fn index(&mut self, decl_fn: &ast::ItemFn) -> CompileResult<()> {
         let span = decl_fn.span();
         let is_toplevel = self.items.is_empty();
+        let mut is_test = false;
+        let mut is_bench = false;
+        let mut is_export = false;
+
+        for attribute in &decl_fn.attributes {
+            match self.resolve_attribute(attribute)? {
+                Attribute::Test => is_test = true,
+                Attribute::Bench => is_bench = true,
+                Attribute::Export => is_export = true,
+            }
+        }
+
         let name = decl_fn.name.resolve(&self.storage, &*self.source)?;
+
+        // NB: with dead code elimination, toplevel functions other than
+        // `main` are only built if they're referenced from an entry point.
+        let is_entry = (is_toplevel
+            && (!self.options.dead_code_elimination || name.as_ref() == "main"))
+            || is_export
+            || is_test
+            || is_bench;
+
         let _guard = self.items.push_name(name.as_ref());
 
         let item = self.items.item();
@@ -152,7 +205,7 @@ impl Index<ast::ItemFn> for Indexer<'_> {
                 }
                 ast::FnArg::Ident(ident) => {
                     let span = ident.span();
-                    let ident = ident.resolve(&self.storage, &*self.source)?;
+                    let ident = ident.resolve_local(&self.storage, &*self.source)?;
                     self.scopes.declare(ident.as_ref(), span)?;
                 }
                 _ => (),
@@ -164,9 +217,18 @@ impl Index<ast::ItemFn> for Indexer<'_> {
         let f = guard.into_function(span)?;
         let call = Self::call(f.generator, f.is_async);
 
+        // NB: assignments in macro expansions are not seen while indexing the
+        // function, so we can't tell which of its variables are immutable.
+        let reassigned = if f.has_macros {
+            None
+        } else {
+            Some(f.reassigned)
+        };
+
         let fun = Function {
             ast: decl_fn.clone(),
             call,
+            reassigned,
         };
 
         if decl_fn.is_instance() {
@@ -180,6 +242,7 @@ impl Index<ast::ItemFn> for Indexer<'_> {
                 impl_item: impl_item.clone(),
                 instance_span: span,
                 call: fun.call,
+                reassigned: fun.reassigned,
             };
 
             // NB: all instance functions must be pre-emptively built,
@@ -198,8 +261,18 @@ impl Index<ast::ItemFn> for Indexer<'_> {
             };
 
             self.query.unit.borrow_mut().insert_meta(meta)?;
-        } else if is_toplevel {
-            // NB: immediately compile all toplevel functions.
+        } else if is_entry {
+            // NB: immediately compile all entry points, since they are looked
+            // up by name. This includes tests and benchmarks which are looked
+            // up by the runner.
+            if is_test {
+                self.query.unit.borrow_mut().insert_test(item.clone());
+            }
+
+            if is_bench {
+                self.query.unit.borrow_mut().insert_bench(item.clone());
+            }
+
             self.query.queue.push_back(BuildEntry {
                 item: item.clone(),
                 build: Build::Function(fun),
@@ -289,8 +362,10 @@ impl Index<ast::Stmt> for Indexer<'_> {
 
 impl Index<ast::ExprLet> for Indexer<'_> {
     fn index(&mut self, expr_let: &ast::ExprLet) -> CompileResult<()> {
-        self.index(&expr_let.pat)?;
+        // NB: the expression is evaluated before the pattern is bound, so any
+        // variables it refers to are the ones that are already in scope.
         self.index(&*expr_let.expr)?;
+        self.index(&expr_let.pat)?;
         Ok(())
     }
 }
@@ -298,7 +373,7 @@ impl Index<ast::ExprLet> for Indexer<'_> {
 impl Index<ast::Ident> for Indexer<'_> {
     fn index(&mut self, ident: &ast::Ident) -> CompileResult<()> {
         let span = ident.span();
-        let ident = ident.resolve(&self.storage, &*self.source)?;
+        let ident = ident.resolve_local(&self.storage, &*self.source)?;
         self.scopes.declare(ident.as_ref(), span)?;
         Ok(())
     }
@@ -429,6 +504,12 @@ impl Index<ast::Expr> for Indexer<'_> {
             ast::Expr::ExprIndexGet(expr_index_get) => {
                 self.index(expr_index_get)?;
             }
+            ast::Expr::ExprSlice(expr_slice) => {
+                self.index(expr_slice)?;
+            }
+            ast::Expr::ExprSpread(expr_spread) => {
+                self.index(&*expr_spread.expr)?;
+            }
             ast::Expr::ExprBreak(expr_break) => {
                 self.index(expr_break)?;
             }
@@ -469,6 +550,7 @@ impl Index<ast::Expr> for Indexer<'_> {
             // NB: macros have nothing to index, they don't export language
             // items.
             ast::Expr::MacroCall(expr_call_macro) => {
+                self.scopes.mark_macro();
                 let _guard = self.items.push_macro();
 
                 self.queue.push_back(Task::ExpandMacro(Macro {
@@ -507,6 +589,15 @@ impl Index<ast::ExprIf> for Indexer<'_> {
 
 impl Index<ast::ExprBinary> for Indexer<'_> {
     fn index(&mut self, expr_binary: &ast::ExprBinary) -> CompileResult<()> {
+        if expr_binary.op.is_assign() {
+            if let ast::Expr::Path(path) = &*expr_binary.lhs {
+                if let Some(ident) = path.try_as_ident() {
+                    let ident = ident.resolve_local(&self.storage, &*self.source)?;
+                    self.scopes.mark_assign(ident.as_ref());
+                }
+            }
+        }
+
         self.index(&*expr_binary.lhs)?;
         self.index(&*expr_binary.rhs)?;
         Ok(())
@@ -601,6 +692,33 @@ impl Index<ast::Item> for Indexer<'_> {
             ast::Item::ItemFn(decl_fn) => {
                 self.index(decl_fn)?;
             }
+            ast::Item::ItemStatic(item_static) => {
+                let span = item_static.span();
+                let name = item_static.name.resolve(&self.storage, &*self.source)?;
+                let _guard = self.items.push_name(name.as_ref());
+
+                let guard = self.scopes.push_function(false);
+                self.index(&*item_static.expr)?;
+                let f = guard.into_function(span)?;
+
+                // NB: the initializer is called like a regular function, so it
+                // can't be a generator.
+                if f.generator {
+                    return Err(CompileError::YieldOutsideFunction { span });
+                }
+
+                self.query.index(
+                    self.items.item(),
+                    IndexedEntry {
+                        indexed: Indexed::Static(Static {
+                            ast: item_static.clone(),
+                        }),
+                        source: self.source.clone(),
+                        source_id: self.source_id,
+                    },
+                    span,
+                )?;
+            }
             ast::Item::ItemImpl(decl_impl) => {
                 let mut guards = Vec::new();
 
@@ -628,6 +746,7 @@ impl Index<ast::Item> for Indexer<'_> {
                 }
             },
             ast::Item::MacroCall(expr_call_macro) => {
+                self.scopes.mark_macro();
                 let _guard = self.items.push_macro();
 
                 self.queue.push_back(Task::ExpandMacro(Macro {
@@ -649,7 +768,7 @@ impl Index<ast::Item> for Indexer<'_> {
 impl Index<ast::Path> for Indexer<'_> {
     fn index(&mut self, path: &ast::Path) -> CompileResult<()> {
         if let Some(ident) = path.try_as_ident() {
-            let ident = ident.resolve(&self.storage, &*self.source)?;
+            let ident = ident.resolve_local(&self.storage, &*self.source)?;
             self.scopes.mark_use(ident.as_ref());
         }
 
@@ -679,6 +798,10 @@ impl Index<ast::ExprFor> for Indexer<'_> {
         // NB: creating the iterator is evaluated in the parent scope.
         self.index(&*expr_for.iter)?;
 
+        if let Some(await_) = &expr_for.await_ {
+            self.scopes.mark_await(await_.span())?;
+        }
+
         let _guard = self.scopes.push_scope();
         self.index(&expr_for.var)?;
         self.index(&*expr_for.body)?;
@@ -698,7 +821,7 @@ impl Index<ast::ExprClosure> for Indexer<'_> {
                     return Err(CompileError::UnsupportedSelf { span: s.span() });
                 }
                 ast::FnArg::Ident(ident) => {
-                    let ident = ident.resolve(&self.storage, &*self.source)?;
+                    let ident = ident.resolve_local(&self.storage, &*self.source)?;
                     self.scopes.declare(ident.as_ref(), span)?;
                 }
                 ast::FnArg::Ignore(..) => (),
@@ -756,6 +879,21 @@ impl Index<ast::ExprIndexGet> for Indexer<'_> {
     }
 }
 
+impl Index<ast::ExprSlice> for Indexer<'_> {
+    fn index(&mut self, expr_slice: &ast::ExprSlice) -> CompileResult<()> {
+        if let Some(from) = &expr_slice.from {
+            self.index(&**from)?;
+        }
+
+        if let Some(to) = &expr_slice.to {
+            self.index(&**to)?;
+        }
+
+        self.index(&*expr_slice.target)?;
+        Ok(())
+    }
+}
+
 impl Index<ast::ExprBreak> for Indexer<'_> {
     fn index(&mut self, expr_break: &ast::ExprBreak) -> CompileResult<()> {
         if let Some(expr) = &expr_break.expr {
@@ -834,6 +972,23 @@ impl Index<ast::ExprSelect> for Indexer<'_> {
 
 impl Index<ast::ExprCall> for Indexer<'_> {
     fn index(&mut self, expr_call: &ast::ExprCall) -> CompileResult<()> {
+        // NB: instance calls compile their receiver before their arguments, so
+        // they must be indexed in the same order for closures to line up.
+        if let ast::Expr::ExprFieldAccess(ast::ExprFieldAccess {
+            expr,
+            expr_field: ast::ExprField::Ident(..),
+            ..
+        }) = &*expr_call.expr
+        {
+            self.index(&**expr)?;
+
+            for (expr, _) in expr_call.args.items.iter() {
+                self.index(expr)?;
+            }
+
+            return Ok(());
+        }
+
         for (expr, _) in expr_call.args.items.iter() {
             self.index(expr)?;
         }
@@ -48,6 +48,8 @@ impl IndexScopeGuard {
                 generator: fun.generator,
                 is_async: fun.is_async,
                 has_await: fun.has_await,
+                reassigned: fun.reassigned,
+                has_macros: fun.has_macros,
             }),
             _ => Err(CompileError::internal("expected function", span)),
         }
@@ -106,6 +108,12 @@ pub(crate) struct Function {
     pub(crate) is_async: bool,
     #[allow(dead_code)]
     pub(crate) has_await: bool,
+    /// The declarations of local variables which are assigned to after they
+    /// have been declared, identified by their span.
+    pub(crate) reassigned: HashSet<Span>,
+    /// If the function contains macro calls. These are expanded separately,
+    /// so any assignments in them are not part of `reassigned`.
+    pub(crate) has_macros: bool,
 }
 
 pub(crate) struct Closure {
@@ -122,6 +130,8 @@ pub struct IndexFunction {
     scope: IndexScope,
     generator: bool,
     has_await: bool,
+    reassigned: HashSet<Span>,
+    has_macros: bool,
 }
 
 impl IndexFunction {
@@ -132,6 +142,8 @@ impl IndexFunction {
             scope: IndexScope::new(),
             generator: false,
             has_await: false,
+            reassigned: HashSet::new(),
+            has_macros: false,
         }
     }
 }
@@ -242,6 +254,57 @@ impl IndexScopes {
         }
     }
 
+    /// Mark that the given variable is assigned to.
+    ///
+    /// The declaration of the variable is recorded as reassigned in the
+    /// encapsulating function. If the variable is captured by a closure, this
+    /// conservatively marks the declaration it was captured from.
+    pub fn mark_assign(&mut self, var: &str) {
+        let mut levels = self.levels.borrow_mut();
+
+        let mut declaration = None;
+
+        for level in levels.iter().rev() {
+            let span = match level {
+                IndexScopeLevel::IndexScope(scope) => scope.locals.get(var),
+                IndexScopeLevel::IndexClosure(closure) => closure.scope.locals.get(var),
+                IndexScopeLevel::IndexFunction(fun) => {
+                    declaration = fun.scope.locals.get(var).copied();
+                    break;
+                }
+            };
+
+            if let Some(span) = span {
+                declaration = Some(*span);
+                break;
+            }
+        }
+
+        let declaration = match declaration {
+            Some(declaration) => declaration,
+            None => return,
+        };
+
+        for level in levels.iter_mut().rev() {
+            if let IndexScopeLevel::IndexFunction(fun) = level {
+                fun.reassigned.insert(declaration);
+                return;
+            }
+        }
+    }
+
+    /// Mark that a macro was called in the encapsulating function.
+    pub fn mark_macro(&mut self) {
+        let mut levels = self.levels.borrow_mut();
+
+        for level in levels.iter_mut().rev() {
+            if let IndexScopeLevel::IndexFunction(fun) = level {
+                fun.has_macros = true;
+                return;
+            }
+        }
+    }
+
     /// Mark that a yield was used, meaning the encapsulating function is a
     /// generator.
     pub fn mark_yield(&mut self, span: Span) -> Result<(), CompileError> {
@@ -93,6 +93,13 @@ impl<'a> Lexer<'a> {
         Ok(Some(ast::Token { kind, span }))
     }
 
+    /// Test if the character before `start` is a single dot, as in a field
+    /// access. A range like `0..1.5` is not considered to be one.
+    fn is_field_access(&self, start: usize) -> bool {
+        let mut it = self.source[..start].chars().rev();
+        matches!((it.next(), it.next()), (Some('.'), Some(c)) if c != '.')
+    }
+
     /// Consume a number literal.
     fn next_number_literal<I>(
         &mut self,
@@ -105,6 +112,9 @@ impl<'a> Lexer<'a> {
         I: Clone + Iterator<Item = (usize, char)>,
     {
         let mut is_fractional = false;
+        // A number immediately following a field access like `tuple.0.1` is a
+        // tuple index, and must not swallow the next dot as a fraction.
+        let allow_fractional = !self.is_field_access(start);
 
         let base = if let ('0', Some((_, m))) = (c, it.clone().next()) {
             // This loop is useful.
@@ -132,14 +142,14 @@ impl<'a> Lexer<'a> {
             };
 
             match c {
-                c if char::is_alphanumeric(c) => (),
-                '.' if !is_fractional => {
-                    is_fractional = true;
-
+                c if char::is_alphanumeric(c) || c == '_' => (),
+                '.' if allow_fractional && !is_fractional => {
                     // char immediately following a dot should be numerical.
                     if !it.next().map(|(_, c)| c.is_numeric()).unwrap_or_default() {
                         break self.cursor + n;
                     }
+
+                    is_fractional = true;
                 }
                 _ => break self.cursor + n,
             }
@@ -552,6 +562,10 @@ impl<'a> Lexer<'a> {
                             it.next();
                             break ast::Kind::PipePipe;
                         }
+                        ('?', '?') => {
+                            it.next();
+                            break ast::Kind::QuestionQuestion;
+                        }
                         ('<', '<') => {
                             it.next();
 
@@ -855,4 +869,51 @@ mod tests {
             },
         };
     }
+
+    #[test]
+    fn test_nested_tuple_index() {
+        let number = |is_fractional| {
+            ast::Kind::LitNumber(ast::NumberSource::Text(ast::NumberSourceText {
+                is_fractional,
+                is_negative: false,
+                base: ast::NumberBase::Decimal,
+            }))
+        };
+
+        test_lexer! {
+            "a.0.1",
+            ast::Token {
+                span: Span::new(0, 1),
+                kind: ast::Kind::Ident(ast::StringSource::Text),
+            },
+            ast::Token {
+                span: Span::new(1, 2),
+                kind: ast::Kind::Dot,
+            },
+            ast::Token {
+                span: Span::new(2, 3),
+                kind: number(false),
+            },
+            ast::Token {
+                span: Span::new(3, 4),
+                kind: ast::Kind::Dot,
+            },
+            ast::Token {
+                span: Span::new(4, 5),
+                kind: number(false),
+            },
+        };
+
+        test_lexer! {
+            "..1.5",
+            ast::Token {
+                span: Span::new(0, 2),
+                kind: ast::Kind::DotDot,
+            },
+            ast::Token {
+                span: Span::new(2, 5),
+                kind: number(true),
+            },
+        };
+    }
 }
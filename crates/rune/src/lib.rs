@@ -168,8 +168,10 @@ mod util_macros;
 mod assembly;
 pub mod ast;
 mod compile;
+mod compile_cache;
 mod compile_visitor;
 mod compiler;
+mod context_builder;
 #[cfg(feature = "diagnostics")]
 pub mod diagnostics;
 mod error;
@@ -205,10 +207,12 @@ mod collections {
 }
 
 pub use crate::assembly::Assembly;
+pub use crate::compile_cache::CompileCache;
 pub use crate::compile_visitor::{CompileVisitor, NoopCompileVisitor};
+pub use crate::context_builder::{ContextBuilder, Package};
 pub use crate::error::{CompileError, ParseError};
 pub use crate::lexer::Lexer;
-pub use crate::load::{load_path, load_sources, load_sources_with_visitor};
+pub use crate::load::{load_path, load_sources, load_sources_cached, load_sources_with_visitor};
 pub use crate::load_error::{LoadError, LoadErrorKind};
 pub use crate::macro_context::MacroContext;
 pub use crate::options::Options;
@@ -216,7 +220,7 @@ pub use crate::parser::Parser;
 pub use crate::sources::Sources;
 pub use crate::storage::Storage;
 pub use crate::token_stream::{IntoTokens, TokenStream, TokenStreamIter};
-pub use crate::traits::{Parse, Peek, Resolve};
+pub use crate::traits::{Parse, Peek, Resolve, Spanned};
 pub use crate::warning::{Warning, WarningKind, Warnings};
 pub use compiler::compile;
 pub use unit_builder::{ImportEntry, ImportKey, LinkerError, LinkerErrors, UnitBuilder};
@@ -227,25 +231,11 @@ pub use diagnostics::{termcolor, DiagnosticsError, EmitDiagnostics};
 /// Construct a a default context runestick context.
 ///
 /// If built with the `modules` feature, this includes all available native
-/// modules.
+/// modules. Use [ContextBuilder] to deny access to some of them.
 ///
 /// See [load_path](crate::load_path) for how to use.
 pub fn default_context() -> Result<runestick::Context, runestick::ContextError> {
-    #[allow(unused_mut)]
-    let mut context = runestick::Context::with_default_modules()?;
-
-    #[cfg(feature = "modules")]
-    {
-        context.install(&rune_modules::http::module()?)?;
-        context.install(&rune_modules::json::module()?)?;
-        context.install(&rune_modules::toml::module()?)?;
-        context.install(&rune_modules::time::module()?)?;
-        context.install(&rune_modules::process::module()?)?;
-        context.install(&rune_modules::fs::module()?)?;
-        context.install(&rune_modules::signal::module()?)?;
-    }
-
-    Ok(context)
+    ContextBuilder::new().build()
 }
 
 /// Parse the given input as the given type that implements
@@ -259,3 +249,28 @@ where
     parser.parse_eof()?;
     Ok(ast)
 }
+
+/// Parse the given input as a file, recovering from errors so that all of
+/// them can be reported in one pass instead of only the first one.
+///
+/// Parsing resumes at the next item boundary after an error. Items which
+/// failed to parse are left out of the returned file.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::ParseError;
+///
+/// let (file, errors) = rune::parse_all_with_recovery(r#"
+/// fn a() { let x = ; }
+/// fn b() { 1 + }
+/// fn c() { 42 }
+/// "#);
+///
+/// assert_eq!(file.items.len(), 1);
+/// assert_eq!(errors.len(), 2);
+/// assert!(matches!(errors[0], ParseError::ExpectedExpr { .. }));
+/// ```
+pub fn parse_all_with_recovery(source: &str) -> (ast::File, Vec<ParseError>) {
+    ast::File::parse_with_recovery(source)
+}
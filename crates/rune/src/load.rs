@@ -1,11 +1,12 @@
 use crate::unit_builder::LinkerErrors;
 use crate::unit_builder::UnitBuilder;
-use crate::{compiler, CompileVisitor};
+use crate::{compiler, CompileCache, CompileVisitor};
 use crate::{LoadError, LoadErrorKind, NoopCompileVisitor, Options, Sources, Warnings};
 use runestick::{Context, Source, Unit};
 use std::cell::RefCell;
 use std::path::Path;
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// Load the given path.
 ///
@@ -141,6 +142,91 @@ pub fn load_sources_with_visitor(
     sources: &mut Sources,
     warnings: &mut Warnings,
     visitor: &mut dyn CompileVisitor,
+) -> Result<Unit, LoadError> {
+    load_sources_with_cache(context, options, sources, warnings, visitor, None)
+}
+
+/// Load the given sources, reusing what is stored in the given
+/// [CompileCache].
+///
+/// If none of the sources, or the options, have changed since the cache was
+/// last used the previously compiled unit and its warnings are returned as-is.
+/// Otherwise only sources which have changed are parsed again, and a new unit
+/// is built and stored in the cache. Functions from unchanged sources are
+/// reused when building it, unless they depend on something declared in a
+/// changed source. See [CompileCache] for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::Source;
+/// use std::sync::Arc;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = rune::default_context()?;
+/// let options = rune::Options::default();
+/// let mut cache = rune::CompileCache::new();
+///
+/// let mut load = |source: &str| {
+///     let mut sources = rune::Sources::new();
+///     let mut warnings = rune::Warnings::new();
+///     sources.insert_default(Source::new("entry", source));
+///     rune::load_sources_cached(&context, &options, &mut sources, &mut warnings, &mut cache)
+/// };
+///
+/// let a = load("fn main() { 42 }")?;
+/// let b = load("fn main() { 42 }")?;
+/// assert!(Arc::ptr_eq(&a, &b));
+///
+/// let c = load("fn main() { 43 }")?;
+/// assert!(!Arc::ptr_eq(&a, &c));
+/// # Ok(())
+/// # }
+/// ```
+pub fn load_sources_cached(
+    context: &Context,
+    options: &Options,
+    sources: &mut Sources,
+    warnings: &mut Warnings,
+    cache: &mut CompileCache,
+) -> Result<Arc<Unit>, LoadError> {
+    let fingerprint = CompileCache::fingerprint(options, sources);
+
+    if let Some((unit, cached)) = cache.lookup(&fingerprint) {
+        sources.clear_queue();
+        warnings.extend(cached.iter().copied());
+        return Ok(unit);
+    }
+
+    let mut visitor = NoopCompileVisitor::new();
+    let mut local = Warnings::new();
+
+    cache.begin();
+
+    let unit = load_sources_with_cache(
+        context,
+        options,
+        sources,
+        &mut local,
+        &mut visitor,
+        Some(&mut *cache),
+    )?;
+
+    let unit = Arc::new(unit);
+    warnings.extend(local.iter().copied());
+    cache.store(options, sources, unit.clone(), local);
+    Ok(unit)
+}
+
+/// Load the specified sources with a visitor, reusing what's stored in the
+/// given cache if one is provided.
+fn load_sources_with_cache(
+    context: &Context,
+    options: &Options,
+    sources: &mut Sources,
+    warnings: &mut Warnings,
+    visitor: &mut dyn CompileVisitor,
+    cache: Option<&mut CompileCache>,
 ) -> Result<Unit, LoadError> {
     let unit = if context.has_default_modules() {
         UnitBuilder::with_default_prelude()
@@ -149,9 +235,9 @@ pub fn load_sources_with_visitor(
     };
 
     let unit = Rc::new(RefCell::new(unit));
-    compiler::compile_with_options(&*context, sources, &unit, warnings, &options, visitor)?;
+    compiler::compile_with_cache(context, sources, &unit, warnings, options, visitor, cache)?;
 
-    let unit = match Rc::try_unwrap(unit) {
+    let mut unit = match Rc::try_unwrap(unit) {
         Ok(unit) => unit.into_inner(),
         Err(..) => {
             return Err(LoadError::from(LoadErrorKind::Internal {
@@ -160,6 +246,10 @@ pub fn load_sources_with_visitor(
         }
     };
 
+    if options.debug_info && options.embed_sources {
+        unit.embed_sources(sources);
+    }
+
     if options.link_checks {
         let mut errors = LinkerErrors::new();
 
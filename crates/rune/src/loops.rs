@@ -1,5 +1,6 @@
 use crate::ast;
 use crate::compiler::Needs;
+use crate::Spanned;
 use crate::{
     error::{CompileError, CompileResult},
     Storage,
@@ -11,10 +11,14 @@ pub struct MacroContext {
     source: Arc<Source>,
     /// Temporary recorded default span.
     pub(crate) default_span: Span,
+    /// The span of the macro call being expanded.
+    pub(crate) call_site: Span,
     /// End point of the span.
     pub(crate) end: Span,
     /// Storage used in macro context.
     pub(crate) storage: Storage,
+    /// The hygiene mark of the macro expansion currently running, if any.
+    pub(crate) hygiene: Option<usize>,
 }
 
 impl MacroContext {
@@ -23,8 +27,10 @@ impl MacroContext {
         Self {
             source: Arc::new(Source::default()),
             default_span: Span::empty(),
+            call_site: Span::empty(),
             end: Span::empty(),
             storage: Storage::default(),
+            hygiene: None,
         }
     }
 
@@ -33,16 +39,59 @@ impl MacroContext {
         Self {
             source,
             default_span: Span::empty(),
+            call_site: Span::empty(),
             end: Span::empty(),
             storage,
+            hygiene: None,
         }
     }
 
     /// Access the default span of the context.
+    ///
+    /// This is the span given to all tokens constructed through the context.
+    /// It is the span of the [call site][MacroContext::call_site] unless it has
+    /// been overridden through [with_span][MacroContext::with_span].
     pub fn default_span(&self) -> Span {
         self.default_span
     }
 
+    /// Access the span of the macro call being expanded.
+    pub fn call_site(&self) -> Span {
+        self.call_site
+    }
+
+    /// Construct tokens with the given span inside of the given closure.
+    ///
+    /// Any tokens constructed through the context in the closure, like the
+    /// ones produced by [quote!][crate::quote], are given the specified span
+    /// instead of the span of the call site. This can be used to attribute the
+    /// code generated for a particular input to the tokens of that input, so
+    /// that errors in the expanded code point to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{quote, MacroContext, Spanned as _};
+    /// use runestick::Span;
+    ///
+    /// let mut ctx = MacroContext::empty();
+    ///
+    /// let stream = ctx.with_span(Span::new(4, 8), |ctx| quote!(ctx => value + 1));
+    ///
+    /// for token in &stream {
+    ///     assert_eq!(token.span(), Span::new(4, 8));
+    /// }
+    /// ```
+    pub fn with_span<F, T>(&mut self, span: Span, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let previous = std::mem::replace(&mut self.default_span, span);
+        let output = f(self);
+        self.default_span = previous;
+        output
+    }
+
     /// Construct a new token stream.
     pub fn token_stream(&self) -> TokenStream {
         TokenStream::new(Vec::new(), self.end)
@@ -68,8 +117,17 @@ impl MacroContext {
     }
 
     /// Construct a new identifier from the given string.
+    ///
+    /// Identifiers constructed while a macro is being expanded are
+    /// *hygienic*. When used as local variables they never refer to variables
+    /// at the call site of the macro, or to variables introduced by any other
+    /// macro expansion, even if they have the same name. They still refer to
+    /// items, fields, and object keys by name as usual.
     pub fn ident(&self, ident: &str) -> ast::Token {
-        let id = self.storage.insert_string(ident);
+        let id = match self.hygiene {
+            Some(mark) => self.storage.insert_hygienic_string(ident, mark),
+            None => self.storage.insert_string(ident),
+        };
 
         ast::Token {
             span: self.default_span,
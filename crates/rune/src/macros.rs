@@ -1,12 +1,15 @@
 //! Macro compiler.
 
 use crate::error::CompileResult;
+use crate::Spanned;
 use crate::{
-    ast, CompileError, MacroContext, Options, Parse, ParseError, Parser, Storage, TokenStream,
-    UnitBuilder,
+    ast, CompileError, MacroContext, Options, Parse, ParseError, Parser, Resolve as _, Storage,
+    TokenStream, UnitBuilder,
 };
 use runestick::{Context, Hash, Item, Source, Span};
 use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -28,6 +31,14 @@ impl MacroCompiler<'_> {
     {
         let span = expr_call_macro.span();
 
+        // NB: `include_str!` is built into the compiler, and is available
+        // even if macros aren't enabled.
+        if let Some(ident) = expr_call_macro.path.try_as_ident() {
+            if ident.resolve(&self.storage, &*self.source)? == "include_str" {
+                return self.eval_include_str(expr_call_macro);
+            }
+        }
+
         if !self.options.macros {
             return Err(CompileError::experimental(
                 "macros must be enabled with `-O macros=true`",
@@ -53,13 +64,17 @@ impl MacroCompiler<'_> {
         let input_stream = &expr_call_macro.stream;
 
         self.macro_context.default_span = span;
+        self.macro_context.call_site = span;
         self.macro_context.end = Span::point(span.end);
+        self.macro_context.hygiene = Some(self.storage.new_hygiene_mark());
 
         let result = handler(self.macro_context, input_stream);
 
         // reset to default spans.
         self.macro_context.default_span = Span::default();
+        self.macro_context.call_site = Span::default();
         self.macro_context.end = Span::default();
+        self.macro_context.hygiene = None;
 
         let output = match result {
             Ok(output) => output,
@@ -89,4 +104,51 @@ impl MacroCompiler<'_> {
         parser.parse_eof()?;
         Ok(output)
     }
+
+    /// Evaluate the built-in `include_str!` macro, which expands into a string
+    /// literal with the content of the given file.
+    ///
+    /// Relative paths are resolved relative to the directory of the source
+    /// file containing the call.
+    fn eval_include_str<T>(&mut self, expr_call_macro: ast::MacroCall) -> CompileResult<T>
+    where
+        T: Parse,
+    {
+        let span = expr_call_macro.span();
+
+        let mut parser = Parser::from_token_stream(&expr_call_macro.stream);
+        let lit_str = parser.parse::<ast::LitStr>()?;
+        parser.parse_eof()?;
+
+        let path = lit_str.resolve(&self.storage, &*self.source)?;
+        let path = Path::new(path.as_ref());
+
+        let path = if path.is_absolute() {
+            path.to_owned()
+        } else {
+            match self.source.path().and_then(Path::parent) {
+                Some(parent) => parent.join(path),
+                None => return Err(CompileError::UnsupportedFileInclude { span }),
+            }
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                return Err(CompileError::IncludeFileError {
+                    path,
+                    span: lit_str.span(),
+                    error,
+                });
+            }
+        };
+
+        let token = self.macro_context.with_span(span, |ctx| ctx.lit(&contents));
+        let token_stream = TokenStream::new(vec![token], Span::point(span.end));
+
+        let mut parser = Parser::from_token_stream(&token_stream);
+        let output = parser.parse::<T>()?;
+        parser.parse_eof()?;
+        Ok(output)
+    }
 }
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use crate::error::ConfigurationError;
 
 /// Compiler options.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Options {
     /// Perform link-time checks.
     pub(crate) link_checks: bool,
@@ -15,6 +15,18 @@ pub struct Options {
     pub(crate) macros: bool,
     /// Support (experimental) bytecode caching.
     pub bytecode: bool,
+    /// Perform peephole optimizations on the generated instructions.
+    pub(crate) peephole: bool,
+    /// Interpret negative integer indices as offsets from the end.
+    pub(crate) negative_index: bool,
+    /// Propagate constants through `let` bindings which are never reassigned.
+    pub(crate) const_propagation: bool,
+    /// Only build toplevel functions which are reachable from an entry point.
+    pub(crate) dead_code_elimination: bool,
+    /// Turn self-recursive calls in tail position into jumps.
+    pub(crate) tail_calls: bool,
+    /// Embed the sources in the debug information of the unit.
+    pub(crate) embed_sources: bool,
 }
 
 impl FromStr for Options {
@@ -56,6 +68,36 @@ impl Options {
             Some("bytecode") => {
                 self.bytecode = it.next() != Some("false");
             }
+            Some("peephole") => {
+                self.peephole = it.next() != Some("false");
+            }
+            Some("negative-index") => {
+                self.negative_index = it.next() != Some("false");
+            }
+            Some("const-propagation") => {
+                self.const_propagation = it.next() != Some("false");
+            }
+            Some("dead-code-elimination") => {
+                self.dead_code_elimination = it.next() != Some("false");
+            }
+            Some("tail-calls") => {
+                self.tail_calls = it.next() != Some("false");
+            }
+            Some("embed-sources") => {
+                self.embed_sources = it.next() != Some("false");
+            }
+            Some("opt-level") => {
+                let level = it.next().unwrap_or_default();
+
+                match level.parse() {
+                    Ok(level) => self.opt_level(level)?,
+                    Err(..) => {
+                        return Err(ConfigurationError::UnsupportedOptLevel {
+                            level: level.to_owned(),
+                        });
+                    }
+                }
+            }
             _ => {
                 return Err(ConfigurationError::UnsupportedOptimizationOption {
                     option: option.to_owned(),
@@ -73,7 +115,8 @@ impl Options {
 
     /// Set if link checks are enabled or not. Defaults to `true`. This will
     /// cause compilation to fail if an instruction references a function which
-    /// does not exist.
+    /// does not exist. All missing functions are reported at once as a
+    /// linker error.
     pub fn link_checks(&mut self, enabled: bool) {
         self.link_checks = enabled;
     }
@@ -88,10 +131,107 @@ impl Options {
         self.bytecode = enabled;
     }
 
-    /// Memoize the instance function in a loop. Defaults to `false`.
+    /// Set if peephole optimizations are enabled or not. Defaults to `false`.
+    /// This removes redundant instructions, like values which are pushed only
+    /// to be immediately popped.
+    pub fn peephole(&mut self, enabled: bool) {
+        self.peephole = enabled;
+    }
+
+    /// Set if negative indices are interpreted as offsets from the end of the
+    /// indexed vector or tuple, so that `v[-1]` is the last element. Defaults
+    /// to `false`.
+    pub fn negative_index(&mut self, enabled: bool) {
+        self.negative_index = enabled;
+    }
+
+    /// Set if constants are propagated through `let` bindings or not. Defaults
+    /// to `false`. A binding which is initialized with a constant and never
+    /// reassigned is substituted where it is used, so that arithmetic on it
+    /// can be folded at compile time.
+    pub fn const_propagation(&mut self, enabled: bool) {
+        self.const_propagation = enabled;
+    }
+
+    /// Set if dead code elimination is enabled or not. Defaults to `false`.
+    ///
+    /// Normally every toplevel function is built into the unit. With this
+    /// enabled, only entry points and the functions they reference, directly
+    /// or transitively, are built. Entry points are `main`, functions marked
+    /// with `#[export]`, `#[test]`, or `#[bench]`, and instance functions.
+    pub fn dead_code_elimination(&mut self, enabled: bool) {
+        self.dead_code_elimination = enabled;
+    }
+
+    /// Set if tail calls are optimized or not. Defaults to `false`. A function
+    /// which calls itself in tail position reuses its current call frame by
+    /// jumping back to its start, so that deep self-recursion doesn't grow the
+    /// stack.
+    pub fn tail_calls(&mut self, enabled: bool) {
+        self.tail_calls = enabled;
+    }
+
+    /// Set if the sources are embedded in the debug information of the unit.
+    /// Defaults to `false`. This makes it possible to render diagnostics for a
+    /// unit which is serialized and later loaded without its sources, at the
+    /// cost of a larger unit. It has no effect without debug information.
+    pub fn embed_sources(&mut self, enabled: bool) {
+        self.embed_sources = enabled;
+    }
+
+    /// Memoize the `next` instance function of the iterator in a `for` loop,
+    /// so that it is only looked up once each time the loop is entered.
+    /// Defaults to `true`.
     pub fn memoize_instance_fn(&mut self, enabled: bool) {
         self.memoize_instance_fn = enabled;
     }
+
+    /// Configure all optimization related options at once according to the
+    /// given level. This only toggles `memoize-instance-fn`, `peephole`, and
+    /// `debug-info`, other options are left as they are.
+    ///
+    /// * `0` - disables `memoize-instance-fn` and `peephole`, enables
+    ///   `debug-info`.
+    /// * `1` - enables `memoize-instance-fn` and `debug-info`, disables
+    ///   `peephole`. This is the same as the defaults.
+    /// * `2` - enables `memoize-instance-fn`, `peephole`, and `debug-info`.
+    /// * `3` - enables `memoize-instance-fn` and `peephole`, disables
+    ///   `debug-info`.
+    ///
+    /// This is available through the `opt-level=<level>` option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut options = rune::Options::default();
+    /// options.opt_level(2).unwrap();
+    /// assert_eq!(options, "peephole".parse().unwrap());
+    ///
+    /// assert!(options.opt_level(4).is_err());
+    ///
+    /// let options: rune::Options = "opt-level=3".parse().unwrap();
+    /// assert_eq!(options, "peephole,debug-info=false".parse().unwrap());
+    ///
+    /// assert!("opt-level=fast".parse::<rune::Options>().is_err());
+    /// ```
+    pub fn opt_level(&mut self, level: u8) -> Result<(), ConfigurationError> {
+        let (memoize_instance_fn, peephole, debug_info) = match level {
+            0 => (false, false, true),
+            1 => (true, false, true),
+            2 => (true, true, true),
+            3 => (true, true, false),
+            _ => {
+                return Err(ConfigurationError::UnsupportedOptLevel {
+                    level: level.to_string(),
+                })
+            }
+        };
+
+        self.memoize_instance_fn = memoize_instance_fn;
+        self.peephole = peephole;
+        self.debug_info = debug_info;
+        Ok(())
+    }
 }
 
 impl Default for Options {
@@ -102,6 +242,12 @@ impl Default for Options {
             debug_info: true,
             macros: false,
             bytecode: false,
+            peephole: false,
+            negative_index: false,
+            const_propagation: false,
+            dead_code_elimination: false,
+            tail_calls: false,
+            embed_sources: false,
         }
     }
 }
@@ -123,7 +123,7 @@ impl<'a> Parser<'a> {
 
     /// Assert that the parser has reached its end-of-file.
     pub fn parse_eof(&mut self) -> Result<(), ParseError> {
-        if let Some(token) = self.source.next()? {
+        if let Some(token) = self.p1? {
             return Err(ParseError::ExpectedEof {
                 actual: token.kind,
                 span: token.span,
@@ -2,8 +2,9 @@
 
 use crate::ast;
 use crate::collections::{HashMap, HashSet};
+use crate::compile_cache::Lookup;
 use crate::error::CompileResult;
-use crate::{CompileError, Resolve as _, Storage, UnitBuilder};
+use crate::{CompileError, Resolve as _, Spanned, Storage, UnitBuilder};
 use runestick::{
     Call, CompileMeta, CompileMetaCapture, CompileMetaStruct, CompileMetaTuple, Hash, Item, Source,
     Span, Type,
@@ -20,6 +21,7 @@ pub(crate) enum Indexed {
     Function(Function),
     Closure(Closure),
     AsyncBlock(AsyncBlock),
+    Static(Static),
 }
 
 pub struct Struct {
@@ -51,6 +53,9 @@ pub(crate) struct Function {
     /// Ast for declaration.
     pub(crate) ast: ast::ItemFn,
     pub(crate) call: Call,
+    /// Declarations of local variables which are reassigned, or `None` if
+    /// this isn't known.
+    pub(crate) reassigned: Option<HashSet<Span>>,
 }
 
 pub(crate) struct InstanceFunction {
@@ -61,6 +66,9 @@ pub(crate) struct InstanceFunction {
     /// The span of the instance function.
     pub(crate) instance_span: Span,
     pub(crate) call: Call,
+    /// Declarations of local variables which are reassigned, or `None` if
+    /// this isn't known.
+    pub(crate) reassigned: Option<HashSet<Span>>,
 }
 
 pub(crate) struct Closure {
@@ -81,12 +89,18 @@ pub(crate) struct AsyncBlock {
     pub(crate) call: Call,
 }
 
+pub(crate) struct Static {
+    /// Ast for the static declaration.
+    pub(crate) ast: ast::ItemStatic,
+}
+
 /// An entry in the build queue.
 pub(crate) enum Build {
     Function(Function),
     InstanceFunction(InstanceFunction),
     Closure(Closure),
     AsyncBlock(AsyncBlock),
+    Static(Static),
 }
 
 /// An entry in the build queue.
@@ -108,6 +122,9 @@ pub(crate) struct Query {
     pub(crate) unit: Rc<RefCell<UnitBuilder>>,
     pub(crate) queue: VecDeque<BuildEntry>,
     pub(crate) indexed: HashMap<Item, IndexedEntry>,
+    /// Lookups performed while compiling a build entry, if they are being
+    /// recorded for a [CompileCache][crate::CompileCache].
+    pub(crate) lookups: Option<Vec<Lookup>>,
 }
 
 impl Query {
@@ -118,6 +135,14 @@ impl Query {
             unit,
             queue: VecDeque::new(),
             indexed: HashMap::new(),
+            lookups: None,
+        }
+    }
+
+    /// Record the given lookup, if lookups are being recorded.
+    pub(crate) fn record(&mut self, lookup: Lookup) {
+        if let Some(lookups) = &mut self.lookups {
+            lookups.push(lookup);
         }
     }
 
@@ -274,6 +299,19 @@ impl Query {
         item: &Item,
         span: Span,
     ) -> Result<Option<CompileMeta>, CompileError> {
+        let meta = self.build_meta(item, span)?;
+
+        self.record(Lookup::Meta {
+            item: item.clone(),
+            meta: meta.clone(),
+        });
+
+        Ok(meta)
+    }
+
+    /// Build the meta for the given item, queueing it up to be built if
+    /// necessary.
+    fn build_meta(&mut self, item: &Item, span: Span) -> Result<Option<CompileMeta>, CompileError> {
         let item = Item::of(item);
 
         if let Some(meta) = self.unit.borrow().lookup_meta(&item) {
@@ -297,7 +335,7 @@ impl Query {
             },
             Indexed::Variant(variant) => {
                 // Assert that everything is built for the enum.
-                self.query_meta(&variant.enum_item, span)?;
+                self.build_meta(&variant.enum_item, span)?;
                 self.variant_into_item_decl(&item, variant.ast, Some(variant.enum_item), &*source)?
             }
             Indexed::Struct(st) => {
@@ -346,6 +384,16 @@ impl Query {
                     captures,
                 }
             }
+            Indexed::Static(s) => {
+                self.queue.push_back(BuildEntry {
+                    item: item.clone(),
+                    build: Build::Static(s),
+                    source,
+                    source_id,
+                });
+
+                CompileMeta::Static { item: item.clone() }
+            }
         };
 
         self.unit.borrow_mut().insert_meta(meta)?;
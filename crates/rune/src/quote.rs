@@ -7,6 +7,13 @@
 /// * Labels, which must be created using [crate::MacroContext::label].
 /// * Template strings, which must be created using [crate::MacroContext::template_string].
 ///
+/// ## Spans
+///
+/// Quoted tokens are given the [default span][crate::MacroContext::default_span]
+/// of the context, which is the span of the macro call unless overridden with
+/// [MacroContext::with_span][crate::MacroContext::with_span]. Interpolated
+/// values keep their own spans.
+///
 /// ## Interpolating values
 ///
 /// Values are interpolated with `#value`, or `#(value + 1)` for expressions.
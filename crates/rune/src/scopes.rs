@@ -3,6 +3,30 @@ use crate::collections::HashMap;
 use crate::error::{CompileError, CompileResult};
 use runestick::{Inst, Span};
 
+/// A value which is known at compile time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Constant {
+    /// A boolean constant.
+    Bool(bool),
+    /// An integer constant.
+    Integer(i64),
+    /// A float constant.
+    Float(f64),
+}
+
+impl Constant {
+    /// Push the constant onto the stack.
+    pub(crate) fn push(self, asm: &mut Assembly, span: Span) {
+        let inst = match self {
+            Self::Bool(value) => Inst::Bool { value },
+            Self::Integer(number) => Inst::Integer { number },
+            Self::Float(number) => Inst::Float { number },
+        };
+
+        asm.push(inst, span);
+    }
+}
+
 /// A locally declared variable.
 #[derive(Debug, Clone)]
 pub(crate) struct Var {
@@ -10,6 +34,9 @@ pub(crate) struct Var {
     pub(crate) offset: usize,
     /// Token assocaited with the variable.
     span: Span,
+    /// The constant value of the variable, if it's never reassigned and was
+    /// initialized with a constant.
+    pub(crate) constant: Option<Constant>,
 }
 
 impl Var {
@@ -79,7 +106,11 @@ impl Scope {
     pub(crate) fn new_var(&mut self, name: &str, span: Span) -> CompileResult<usize> {
         let offset = self.total_var_count;
 
-        let local = Var { offset, span };
+        let local = Var {
+            offset,
+            span,
+            constant: None,
+        };
 
         self.total_var_count += 1;
         self.local_var_count += 1;
@@ -101,13 +132,25 @@ impl Scope {
 
         log::trace!("decl {} => {}", name, offset);
 
-        self.locals.insert(name.to_owned(), Var { offset, span });
+        self.locals.insert(
+            name.to_owned(),
+            Var {
+                offset,
+                span,
+                constant: None,
+            },
+        );
 
         self.total_var_count += 1;
         self.local_var_count += 1;
         offset
     }
 
+    /// Get the variable with the given name in this scope.
+    pub(crate) fn get_mut(&mut self, name: &str) -> Option<&mut Var> {
+        self.locals.get_mut(name)
+    }
+
     /// Declare an anonymous variable.
     ///
     /// This is used if cleanup is required in the middle of an expression.
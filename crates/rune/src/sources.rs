@@ -46,6 +46,12 @@ impl Sources {
         self.queue.pop_front()
     }
 
+    /// Clear the queue of sources to compile, used when the sources are
+    /// known to already have been compiled.
+    pub(crate) fn clear_queue(&mut self) {
+        self.queue.clear();
+    }
+
     /// Iterate over all sources in order by index.
     pub(crate) fn iter(&self) -> impl Iterator<Item = &Source> {
         self.sources.iter().map(|s| &**s)
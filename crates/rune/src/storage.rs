@@ -46,6 +46,43 @@ impl Storage {
         id
     }
 
+    /// Insert the given identifier into storage with the given hygiene mark
+    /// and return its id.
+    ///
+    /// Identifiers with a hygiene mark resolve to the same text as ordinary
+    /// identifiers, but are treated as distinct local variables from any
+    /// identifier which doesn't have the same mark. See
+    /// [Ident::resolve_local][crate::ast::Ident::resolve_local].
+    pub fn insert_hygienic_string(&self, string: &str, mark: usize) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        let key = (mark, string.to_string());
+
+        if let Some(id) = inner.hygienic_rev.get(&key).copied() {
+            return id;
+        }
+
+        let id = inner.strings.len();
+        inner.strings.push(string.to_string());
+        inner.hygienic_rev.insert(key, id);
+        inner.hygiene.insert(id, mark);
+        id
+    }
+
+    /// Allocate a new unique hygiene mark, used to distinguish identifiers
+    /// introduced by one macro expansion from all others.
+    pub(crate) fn new_hygiene_mark(&self) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        inner.hygiene_marks += 1;
+        inner.hygiene_marks
+    }
+
+    /// Get the hygiene mark of the string with the specified id, if it has
+    /// one.
+    pub fn get_hygiene_mark(&self, id: usize) -> Option<usize> {
+        let inner = self.inner.borrow();
+        inner.hygiene.get(&id).copied()
+    }
+
     /// Insert the given text into storage and return its id.
     ///
     /// This will reuse old storage slots that already contains the given
@@ -88,6 +125,12 @@ struct Inner {
     strings: Vec<String>,
     /// Reverse lookup for existing strings.
     strings_rev: HashMap<String, usize>,
+    /// Reverse lookup for existing hygienic strings.
+    hygienic_rev: HashMap<(usize, String), usize>,
+    /// Hygiene marks for strings which have them.
+    hygiene: HashMap<usize, usize>,
+    /// The last allocated hygiene mark.
+    hygiene_marks: usize,
     /// Stored byte strings.
     byte_strings: Vec<Vec<u8>>,
     /// Reverse lookup for existing byte strings.
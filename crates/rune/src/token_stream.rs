@@ -85,6 +85,10 @@ impl IntoIterator for TokenStream {
 }
 
 /// Trait for things that can be turned into tokens.
+///
+/// Implementations for AST nodes must emit every token that was consumed when
+/// parsing the node, including separators like commas, so that parsing the
+/// produced tokens again yields an equivalent node.
 pub trait IntoTokens {
     /// Turn the current item into tokens.
     fn into_tokens(&self, context: &mut MacroContext, stream: &mut TokenStream);
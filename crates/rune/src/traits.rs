@@ -3,7 +3,7 @@ use crate::error::CompileResult;
 use crate::error::ParseError;
 use crate::parser::Parser;
 use crate::{MacroContext, Storage};
-use runestick::Source;
+use runestick::{Source, Span};
 
 /// The parse trait, implemented by items that can be parsed.
 pub trait Parse
@@ -91,6 +91,44 @@ pub trait Peek {
     fn peek(t1: Option<Token>, t2: Option<Token>) -> bool;
 }
 
+/// Implemented by AST nodes and tokens which have a span in the source they
+/// were parsed from.
+///
+/// For nodes made up of multiple tokens, the span covers all of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{ast, parse_all, Spanned as _};
+/// use runestick::Span;
+///
+/// let expr = parse_all::<ast::Expr>("foo(1, 2)").unwrap();
+/// assert_eq!(expr.span(), Span::new(0, 9));
+/// ```
+pub trait Spanned {
+    /// Get the span of the node.
+    fn span(&self) -> Span;
+}
+
+impl<T> Spanned for Box<T>
+where
+    T: Spanned,
+{
+    fn span(&self) -> Span {
+        (**self).span()
+    }
+}
+
+impl<A, B> Spanned for (A, B)
+where
+    A: Spanned,
+    B: Spanned,
+{
+    fn span(&self) -> Span {
+        self.0.span().join(self.1.span())
+    }
+}
+
 /// A type that can be resolved to an internal value based on a source.
 pub trait Resolve<'a> {
     /// The output type being resolved.
@@ -7,11 +7,11 @@ use crate::assembly::{Assembly, AssemblyInst};
 use crate::ast;
 use crate::collections::HashMap;
 use crate::error::CompileResult;
-use crate::{Resolve as _, Storage};
+use crate::{Resolve as _, Sources, Storage};
 use runestick::debug::{DebugArgs, DebugSignature};
 use runestick::{
-    Call, CompileMeta, Component, Context, DebugInfo, DebugInst, Hash, Inst, Item, Label, Names,
-    Source, Span, StaticString, Type, Unit, UnitFn, UnitTypeInfo,
+    Call, CompileMeta, Component, Context, DebugInfo, DebugInst, DebugSource, Hash, Inst, Item,
+    Label, Names, Source, Span, StaticString, Type, Unit, UnitFn, UnitTypeInfo,
 };
 use std::sync::Arc;
 use thiserror::Error;
@@ -222,6 +222,10 @@ pub struct UnitBuilder {
     required_functions: HashMap<Hash, Vec<(Span, usize)>>,
     /// All available names in the context.
     names: Names,
+    /// Functions marked with `#[test]`.
+    tests: Vec<Item>,
+    /// Functions marked with `#[bench]`.
+    benches: Vec<Item>,
     /// Debug info if available for unit.
     debug: Option<Box<DebugInfo>>,
 }
@@ -256,6 +260,10 @@ impl UnitBuilder {
             ImportKey::component("panic"),
             ImportEntry::of(&["std", "panic"]),
         );
+        this.imports.insert(
+            ImportKey::component("partial"),
+            ImportEntry::of(&["std", "partial"]),
+        );
         this.imports.insert(
             ImportKey::component("print"),
             ImportEntry::of(&["std", "print"]),
@@ -264,6 +272,14 @@ impl UnitBuilder {
             ImportKey::component("println"),
             ImportEntry::of(&["std", "println"]),
         );
+        this.imports.insert(
+            ImportKey::component("eprint"),
+            ImportEntry::of(&["std", "eprint"]),
+        );
+        this.imports.insert(
+            ImportKey::component("eprintln"),
+            ImportEntry::of(&["std", "eprintln"]),
+        );
         this.imports.insert(
             ImportKey::component("unit"),
             ImportEntry::of(&["std", "unit"]),
@@ -324,6 +340,18 @@ impl UnitBuilder {
             ImportKey::component("None"),
             ImportEntry::of(&["std", "option", "Option", "None"]),
         );
+        this.imports.insert(
+            ImportKey::component("Iterable"),
+            ImportEntry::of(&["std", "Iterable"]),
+        );
+        this.imports.insert(
+            ImportKey::component("Callable"),
+            ImportEntry::of(&["std", "Callable"]),
+        );
+        this.imports.insert(
+            ImportKey::component("Indexable"),
+            ImportEntry::of(&["std", "Indexable"]),
+        );
 
         this
     }
@@ -343,6 +371,24 @@ impl UnitBuilder {
             self.static_object_keys,
             self.debug,
         )
+        .with_tests(self.tests)
+        .with_benches(self.benches)
+    }
+
+    /// Register the name of a function called from the unit, so that it can
+    /// be reported by name if it's missing when linking.
+    pub(crate) fn insert_called_function(&mut self, hash: Hash, item: Item) {
+        self.debug_info_mut().called_functions.insert(hash, item);
+    }
+
+    /// Register a function marked with `#[test]`.
+    pub(crate) fn insert_test(&mut self, item: Item) {
+        self.tests.push(item);
+    }
+
+    /// Register a function marked with `#[bench]`.
+    pub(crate) fn insert_bench(&mut self, item: Item) {
+        self.benches.push(item);
     }
 
     /// Insert and access debug information.
@@ -350,6 +396,17 @@ impl UnitBuilder {
         self.debug.get_or_insert_with(Default::default)
     }
 
+    /// Embed the given sources in the debug information of the unit.
+    pub(crate) fn embed_sources(&mut self, sources: &Sources) {
+        self.debug_info_mut().sources = sources
+            .iter()
+            .map(|source| DebugSource {
+                name: source.name().to_owned(),
+                source: source.as_str().to_owned(),
+            })
+            .collect();
+    }
+
     /// Check if unit contains the given name by prefix.
     pub(crate) fn contains_prefix(&self, item: &Item) -> bool {
         self.names.contains_prefix(item)
@@ -475,7 +532,9 @@ impl UnitBuilder {
         Ok(new_slot)
     }
 
-    fn lookup_import_by_name(&self, base: &Item, local: &Component) -> Option<Item> {
+    /// Look up the item imported under the given name, as seen from the given
+    /// base item.
+    pub(crate) fn lookup_import_by_name(&self, base: &Item, local: &Component) -> Option<Item> {
         let mut base = base.clone();
 
         loop {
@@ -569,6 +628,8 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: tuple.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    source_id: None,
+                    span: None,
                 };
 
                 if self.functions.insert(tuple.hash, info).is_some() {
@@ -608,6 +669,8 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: tuple.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    source_id: None,
+                    span: None,
                 };
 
                 if self.functions.insert(tuple.hash, info).is_some() {
@@ -687,6 +750,7 @@ impl UnitBuilder {
             CompileMeta::Function { item, .. } => item.clone(),
             CompileMeta::Closure { item, .. } => item.clone(),
             CompileMeta::AsyncBlock { item, .. } => item.clone(),
+            CompileMeta::Static { item, .. } => item.clone(),
             CompileMeta::Macro { item, .. } => item.clone(),
         };
 
@@ -709,6 +773,7 @@ impl UnitBuilder {
     pub(crate) fn new_function(
         &mut self,
         source_id: usize,
+        span: Span,
         path: Item,
         args: usize,
         assembly: Assembly,
@@ -720,7 +785,7 @@ impl UnitBuilder {
 
         self.functions_rev.insert(offset, hash);
         let info = UnitFn::Offset { offset, call, args };
-        let signature = DebugSignature::new(path, debug_args);
+        let signature = DebugSignature::with_span(path, debug_args, source_id, span);
 
         if self.functions.insert(hash, info).is_some() {
             return Err(UnitBuilderError::FunctionConflict {
@@ -737,6 +802,7 @@ impl UnitBuilder {
     pub(crate) fn new_instance_function(
         &mut self,
         source_id: usize,
+        span: Span,
         path: Item,
         value_type: Type,
         name: &str,
@@ -753,7 +819,7 @@ impl UnitBuilder {
         let hash = Hash::type_hash(&path);
 
         let info = UnitFn::Offset { offset, call, args };
-        let signature = DebugSignature::new(path, debug_args);
+        let signature = DebugSignature::with_span(path, debug_args, source_id, span);
 
         if self.functions.insert(instance_fn, info).is_some() {
             return Err(UnitBuilderError::FunctionConflict {
@@ -783,7 +849,12 @@ impl UnitBuilder {
     ) -> Result<(), UnitBuilderError> {
         self.label_count = assembly.label_count;
 
-        self.required_functions.extend(assembly.required_functions);
+        for (hash, spans) in assembly.required_functions {
+            self.required_functions
+                .entry(hash)
+                .or_default()
+                .extend(spans);
+        }
 
         for (pos, (inst, span)) in assembly.instructions.into_iter().enumerate() {
             let mut comment = None;
@@ -870,15 +941,30 @@ impl UnitBuilder {
     ///
     /// This can prevent a number of runtime errors, like missing functions.
     pub(crate) fn link(&self, context: &Context, errors: &mut LinkerErrors) -> bool {
+        let start = errors.errors.len();
+
         for (hash, spans) in &self.required_functions {
             if self.functions.get(hash).is_none() && context.lookup(*hash).is_none() {
                 errors.errors.push(LinkerError::MissingFunction {
                     hash: *hash,
+                    item: self
+                        .debug
+                        .as_ref()
+                        .and_then(|debug| debug.called_functions.get(hash))
+                        .cloned(),
                     spans: spans.clone(),
                 });
             }
         }
 
+        // NB: report missing functions in the order they are first used.
+        errors.errors[start..].sort_by_key(|error| match error {
+            LinkerError::MissingFunction { spans, .. } => spans
+                .iter()
+                .map(|(span, source_id)| (*source_id, span.start))
+                .min(),
+        });
+
         errors.errors.is_empty()
     }
 }
@@ -890,6 +976,8 @@ pub enum LinkerError {
     MissingFunction {
         /// Hash of the function.
         hash: Hash,
+        /// The name of the function, if it is known.
+        item: Option<Item>,
         /// Spans where the function is used.
         spans: Vec<(Span, usize)>,
     },
@@ -1,4 +1,5 @@
 use runestick::Span;
+use std::fmt;
 use thiserror::Error;
 
 /// Compilation warning.
@@ -11,11 +12,28 @@ pub struct Warning {
 }
 
 impl Warning {
+    /// The id of the source where the warning happened.
+    pub fn source_id(&self) -> usize {
+        self.source_id
+    }
+
     /// Access the kind of the warning.
     pub fn kind(&self) -> &WarningKind {
         &self.kind
     }
 
+    /// Get the span of the context in which the warning happened, if
+    /// available.
+    pub fn context(&self) -> Option<Span> {
+        match &self.kind {
+            WarningKind::NotUsed { context, .. } => *context,
+            WarningKind::LetPatternMightPanic { context, .. } => *context,
+            WarningKind::TemplateWithoutExpansions { context, .. } => *context,
+            WarningKind::RemoveTupleCallParams { context, .. } => *context,
+            WarningKind::UnecessarySemiColon { .. } => None,
+        }
+    }
+
     /// Get the span of the warning.
     pub fn span(&self) -> Span {
         match &self.kind {
@@ -28,6 +46,12 @@ impl Warning {
     }
 }
 
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
 /// Compilation warning kind.
 #[derive(Debug, Clone, Copy, Error)]
 pub enum WarningKind {
@@ -73,6 +97,7 @@ pub enum WarningKind {
         span: Span,
     },
 }
+
 /// Compilation warnings.
 #[derive(Debug, Clone, Default)]
 pub struct Warnings {
@@ -123,7 +148,34 @@ impl Warnings {
         self.warnings.as_ref().map(Vec::is_empty).unwrap_or(true)
     }
 
+    /// Get the number of warnings.
+    pub fn len(&self) -> usize {
+        self.warnings.as_ref().map(Vec::len).unwrap_or_default()
+    }
+
     /// Get an iterator over all the warnings.
+    ///
+    /// Each warning carries the id of the source it happened in, its kind,
+    /// its span and a message through its [Display][fmt::Display]
+    /// implementation. This can be used to integrate warnings with other
+    /// diagnostics systems.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{Warnings, WarningKind};
+    /// use runestick::Span;
+    ///
+    /// let mut warnings = Warnings::new();
+    /// warnings.not_used(1, Span::new(4, 8), None);
+    ///
+    /// let collected = warnings
+    ///     .iter()
+    ///     .map(|w| (w.source_id(), w.span(), w.to_string()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(collected, vec![(1, Span::new(4, 8), String::from("not used"))]);
+    /// ```
     pub fn iter(&self) -> impl Iterator<Item = &'_ Warning> {
         self.into_iter()
     }
@@ -202,6 +254,17 @@ impl Warnings {
     }
 }
 
+impl Extend<Warning> for Warnings {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Warning>,
+    {
+        if let Some(w) = &mut self.warnings {
+            w.extend(iter);
+        }
+    }
+}
+
 impl<'a> IntoIterator for &'a Warnings {
     type IntoIter = std::slice::Iter<'a, Warning>;
     type Item = &'a Warning;
@@ -214,3 +277,12 @@ impl<'a> IntoIterator for &'a Warnings {
         }
     }
 }
+
+impl IntoIterator for Warnings {
+    type IntoIter = std::vec::IntoIter<Warning>;
+    type Item = Warning;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.warnings.unwrap_or_default().into_iter()
+    }
+}
@@ -10,6 +10,7 @@ use crate::index_scopes::IndexScopes;
 use crate::items::Items;
 use crate::macros::MacroCompiler;
 use crate::query::Query;
+use crate::Spanned;
 use crate::{
     CompileError, LoadError, LoadErrorKind, MacroContext, Options, Parse, Resolve as _, SourceId,
     Sources, Storage, UnitBuilder, Warnings,
@@ -113,6 +114,7 @@ impl<'a> Worker<'a> {
                         items,
                         scopes,
                         impl_items,
+                        options: self.options,
                     };
 
                     let result = match ast {
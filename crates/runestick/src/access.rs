@@ -47,22 +47,84 @@ pub enum AccessError {
 /// Error raised when tried to access for shared access but it was not
 /// accessible.
 #[derive(Debug, Error)]
-#[error("cannot read, value is {0}")]
-pub struct NotAccessibleRef(Snapshot);
+#[error("cannot read, {1} is {0}")]
+pub struct NotAccessibleRef(Snapshot, Accessed);
 
 /// Error raised when tried to access for exclusive access but it was not
 /// accessible.
 #[derive(Debug, Error)]
-#[error("cannot write, value is {0}")]
-pub struct NotAccessibleMut(Snapshot);
+#[error("cannot write, {1} is {0}")]
+pub struct NotAccessibleMut(Snapshot, Accessed);
 
 /// Error raised when tried to access the guarded data for taking.
 ///
 /// This requires exclusive access, but it's a scenario we structure separately
 /// for diagnostics purposes.
 #[derive(Debug, Error)]
-#[error("cannot take, value is {0}")]
-pub struct NotAccessibleTake(Snapshot);
+#[error("cannot take, {1} is {0}")]
+pub struct NotAccessibleTake(Snapshot, Accessed);
+
+macro_rules! impl_accessed {
+    ($($ty:ident),*) => {
+        $(
+            impl $ty {
+                /// The name of the type of the value which wasn't accessible,
+                /// if known.
+                pub fn type_name(&self) -> Option<&'static str> {
+                    (self.1).0
+                }
+
+                /// Associate the name of the type of the value with the error.
+                pub(crate) fn with_type_name(mut self, type_name: &'static str) -> Self {
+                    (self.1).0 = Some(type_name);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_accessed!(NotAccessibleRef, NotAccessibleMut, NotAccessibleTake);
+
+/// Describes the value which was accessed, including the name of its type if
+/// it's known.
+#[derive(Debug, Default)]
+struct Accessed(Option<&'static str>);
+
+impl fmt::Display for Accessed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(type_name) => write!(f, "value of type `{}`", ShortTypeName(type_name)),
+            None => write!(f, "value"),
+        }
+    }
+}
+
+/// Formats a type name without the module paths of the types in it, like
+/// `Vec<Value>` instead of `alloc::vec::Vec<runestick::value::Value>`.
+struct ShortTypeName(&'static str);
+
+impl fmt::Display for ShortTypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut start = 0;
+
+        for (n, c) in self.0.char_indices() {
+            if c.is_alphanumeric() || c == '_' || c == ':' {
+                continue;
+            }
+
+            write_path_name(f, &self.0[start..n])?;
+            write!(f, "{}", c)?;
+            start = n + c.len_utf8();
+        }
+
+        return write_path_name(f, &self.0[start..]);
+
+        fn write_path_name(f: &mut fmt::Formatter<'_>, path: &str) -> fmt::Result {
+            f.write_str(path.rsplit("::").next().unwrap_or(path))
+        }
+    }
+}
 
 /// Snapshot that can be used to indicate how the value was being accessed at
 /// the time of an error.
@@ -116,7 +178,7 @@ impl Access {
         let n = state.wrapping_sub(1);
 
         if n >= 0 {
-            return Err(NotAccessibleRef(Snapshot(state)));
+            return Err(NotAccessibleRef(Snapshot(state), Accessed::default()));
         }
 
         self.0.set(n);
@@ -130,7 +192,7 @@ impl Access {
         let n = state.wrapping_add(1);
 
         if n != 1 {
-            return Err(NotAccessibleMut(Snapshot(state)));
+            return Err(NotAccessibleMut(Snapshot(state), Accessed::default()));
         }
 
         self.0.set(n);
@@ -145,7 +207,7 @@ impl Access {
         let state = self.0.get();
 
         if state != 0 {
-            return Err(NotAccessibleTake(Snapshot(state)));
+            return Err(NotAccessibleTake(Snapshot(state), Accessed::default()));
         }
 
         self.0.set(isize::max_value());
@@ -352,3 +414,24 @@ where
         Pin::new(&mut **this).poll(cx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ShortTypeName;
+
+    #[test]
+    fn test_short_type_name() {
+        let name = |name| ShortTypeName(name).to_string();
+
+        assert_eq!(name("u32"), "u32");
+        assert_eq!(name("alloc::string::String"), "String");
+        assert_eq!(
+            name("alloc::vec::Vec<runestick::value::Value>"),
+            "Vec<Value>"
+        );
+        assert_eq!(
+            name("core::result::Result<(), std::io::error::Error>"),
+            "Result<(), Error>"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Restricting which native functions a virtual machine may call.
+//!
+//! When [AllowedFunctions] is associated with a [Vm][crate::Vm], calling any
+//! native function which hasn't been explicitly allowed errors with
+//! [VmErrorKind::DeniedFunction].
+
+use crate::collections::HashSet;
+use crate::{Hash, IntoHash, VmError, VmErrorKind};
+use std::cell::RefCell;
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+thread_local! {
+    /// The allowed functions installed for the currently running virtual
+    /// machine, if any.
+    static CURRENT: RefCell<Option<AllowedFunctions>> = RefCell::new(None);
+}
+
+/// A set of native functions which a virtual machine is allowed to call.
+///
+/// Functions are identified by the same hash they are registered with in the
+/// [Context][crate::Context]. Free functions use the hash of their path, like
+/// `Hash::type_hash(&["std", "int", "parse"])`, while instance functions use
+/// [Hash::instance_function].
+///
+/// This only restricts native functions. Functions declared in the unit being
+/// executed, and operations implemented directly by the virtual machine like
+/// arithmetic or indexing into vectors, are always allowed. Note that some
+/// operations are implemented through native functions, like iterating over a
+/// vector through the [INTO_ITER][crate::INTO_ITER] protocol, and must be
+/// allowed to be used.
+///
+/// Virtual machines spawned by the one the set is associated with, like the
+/// ones backing generators and closures called from native functions, share
+/// the same set.
+#[derive(Clone, Default)]
+pub struct AllowedFunctions {
+    inner: Rc<HashSet<Hash>>,
+}
+
+impl AllowedFunctions {
+    /// Construct a new set which doesn't allow any native functions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{AllowedFunctions, Hash};
+    ///
+    /// let mut allowed = AllowedFunctions::new();
+    /// assert!(!allowed.is_allowed(Hash::type_hash(&["std", "dbg"])));
+    ///
+    /// allowed.allow(Hash::type_hash(&["std", "dbg"]));
+    /// assert!(allowed.is_allowed(Hash::type_hash(&["std", "dbg"])));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow the function with the given hash to be called.
+    pub fn allow<H>(&mut self, hash: H) -> &mut Self
+    where
+        H: IntoHash,
+    {
+        Rc::make_mut(&mut self.inner).insert(hash.into_hash());
+        self
+    }
+
+    /// Test if the function with the given hash is allowed to be called.
+    pub fn is_allowed(&self, hash: Hash) -> bool {
+        self.inner.contains(&hash)
+    }
+
+    /// Check that the function with the given hash is allowed to be called,
+    /// erroring with [VmErrorKind::DeniedFunction] otherwise.
+    pub(crate) fn check(&self, hash: Hash) -> Result<(), VmError> {
+        if !self.is_allowed(hash) {
+            return Err(VmError::from(VmErrorKind::DeniedFunction { hash }));
+        }
+
+        Ok(())
+    }
+
+    /// Get the allowed functions installed for the currently running virtual
+    /// machine, if any.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Install this set for the current thread until the returned guard is
+    /// dropped.
+    pub(crate) fn install(&self) -> AllowedFunctionsGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        AllowedFunctionsGuard { previous }
+    }
+}
+
+impl FromIterator<Hash> for AllowedFunctions {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Hash>,
+    {
+        Self {
+            inner: Rc::new(iter.into_iter().collect()),
+        }
+    }
+}
+
+impl fmt::Debug for AllowedFunctions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.inner.iter()).finish()
+    }
+}
+
+/// Guard which restores the previously installed allowed functions when
+/// dropped.
+pub(crate) struct AllowedFunctionsGuard {
+    previous: Option<AllowedFunctions>,
+}
+
+impl Drop for AllowedFunctionsGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
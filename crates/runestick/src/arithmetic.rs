@@ -0,0 +1,118 @@
+//! Selection of how integer arithmetic behaves on overflow.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// How the integer arithmetic instructions of a [Vm][crate::Vm] behave when
+/// the result of an operation doesn't fit in an integer.
+///
+/// This applies to `+`, `-`, `*`, `/`, and `%`, and their assign variants
+/// like `+=`. Division or remainder by zero always errors with
+/// [VmErrorKind::DivideByZero][crate::VmErrorKind::DivideByZero] regardless
+/// of the selected mode.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::Arithmetic;
+///
+/// assert_eq!(Arithmetic::default(), Arithmetic::Checked);
+/// assert_eq!("wrapping".parse::<Arithmetic>().unwrap(), Arithmetic::Wrapping);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Error with [VmErrorKind::Overflow][crate::VmErrorKind::Overflow] or
+    /// [VmErrorKind::Underflow][crate::VmErrorKind::Underflow]. This is the
+    /// default.
+    Checked,
+    /// Wrap around at the boundary of the integer type, like
+    /// [i64::wrapping_add].
+    Wrapping,
+    /// Saturate at the numeric bounds of the integer type, like
+    /// [i64::saturating_add].
+    Saturating,
+}
+
+impl Arithmetic {
+    /// Add two integers.
+    pub(crate) fn add(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Self::Checked => a.checked_add(b),
+            Self::Wrapping => Some(a.wrapping_add(b)),
+            Self::Saturating => Some(a.saturating_add(b)),
+        }
+    }
+
+    /// Subtract two integers.
+    pub(crate) fn sub(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Self::Checked => a.checked_sub(b),
+            Self::Wrapping => Some(a.wrapping_sub(b)),
+            Self::Saturating => Some(a.saturating_sub(b)),
+        }
+    }
+
+    /// Multiply two integers.
+    pub(crate) fn mul(self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Self::Checked => a.checked_mul(b),
+            Self::Wrapping => Some(a.wrapping_mul(b)),
+            Self::Saturating => Some(a.saturating_mul(b)),
+        }
+    }
+
+    /// Divide two integers.
+    pub(crate) fn div(self, a: i64, b: i64) -> Option<i64> {
+        if b == 0 {
+            return None;
+        }
+
+        // NB: the only overflowing division is `i64::MIN / -1`.
+        match self {
+            Self::Checked => a.checked_div(b),
+            Self::Wrapping => Some(a.wrapping_div(b)),
+            Self::Saturating => Some(a.checked_div(b).unwrap_or(i64::MAX)),
+        }
+    }
+
+    /// Calculate the remainder of dividing two integers.
+    pub(crate) fn rem(self, a: i64, b: i64) -> Option<i64> {
+        if b == 0 {
+            return None;
+        }
+
+        match self {
+            Self::Checked => a.checked_rem(b),
+            Self::Wrapping | Self::Saturating => Some(a.wrapping_rem(b)),
+        }
+    }
+}
+
+impl Default for Arithmetic {
+    fn default() -> Self {
+        Self::Checked
+    }
+}
+
+impl fmt::Display for Arithmetic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Checked => write!(f, "checked"),
+            Self::Wrapping => write!(f, "wrapping"),
+            Self::Saturating => write!(f, "saturating"),
+        }
+    }
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checked" => Ok(Self::Checked),
+            "wrapping" => Ok(Self::Wrapping),
+            "saturating" => Ok(Self::Saturating),
+            other => Err(format!("unsupported arithmetic mode `{}`", other)),
+        }
+    }
+}
@@ -12,16 +12,26 @@ pub enum Awaited {
 impl Awaited {
     /// Wait for the given awaited into the specified virtual machine.
     pub(crate) async fn into_vm(self, vm: &mut Vm) -> Result<(), VmError> {
+        let host_access = vm.context().host_access();
+
         match self {
             Self::Future(future) => {
-                let value = future.borrow_mut()?.await?;
+                let value = host_access.scope(future.borrow_mut()?).await?;
                 vm.stack_mut().push(value);
                 vm.advance();
             }
             Self::Select(select) => {
-                let (branch, value) = select.await?;
-                vm.stack_mut().push(value);
-                vm.stack_mut().push(ToValue::to_value(branch)?);
+                match host_access.scope(select).await? {
+                    Some((branch, value)) => {
+                        vm.stack_mut().push(value);
+                        vm.stack_mut().push(ToValue::to_value(branch)?);
+                    }
+                    // NB: no branch is ready, so the default branch is taken.
+                    None => {
+                        vm.stack_mut().push(());
+                    }
+                }
+
                 vm.advance();
             }
         }
@@ -0,0 +1,92 @@
+//! Breakpoints which pause the execution of a virtual machine.
+
+use crate::collections::HashSet;
+
+/// Breakpoints keyed by source line.
+///
+/// Since the virtual machine doesn't have access to the sources a unit was
+/// compiled from, this is constructed from a line table which maps every
+/// instruction in the unit to the source id and zero-based line it was
+/// compiled from. See `rune::diagnostics::instruction_lines`.
+///
+/// A breakpoint is hit once every time the execution of its line starts. A
+/// line is not considered to start again when a call made from it returns,
+/// but it does each time a loop it's part of starts a new iteration from a
+/// different line.
+#[derive(Debug, Clone, Default)]
+pub struct Breakpoints {
+    /// The source id and line of each instruction, by instruction pointer.
+    lines: Vec<Option<(usize, usize)>>,
+    /// Registered breakpoints by source id and line.
+    breakpoints: HashSet<(usize, usize)>,
+    /// The line last executed in each call frame.
+    frames: Vec<Option<(usize, usize)>>,
+    /// The line of the breakpoint which was last hit.
+    hit: Option<(usize, usize)>,
+}
+
+impl Breakpoints {
+    /// Construct breakpoints from a line table, without any breakpoints
+    /// registered.
+    pub fn new(lines: Vec<Option<(usize, usize)>>) -> Self {
+        Self {
+            lines,
+            breakpoints: HashSet::new(),
+            frames: Vec::new(),
+            hit: None,
+        }
+    }
+
+    /// Register a breakpoint on the given zero-based line of the source with
+    /// the given id. Returns `false` if it was already registered.
+    pub fn insert(&mut self, source_id: usize, line: usize) -> bool {
+        self.breakpoints.insert((source_id, line))
+    }
+
+    /// Remove the breakpoint on the given line. Returns `false` if there was
+    /// no such breakpoint.
+    pub fn remove(&mut self, source_id: usize, line: usize) -> bool {
+        self.breakpoints.remove(&(source_id, line))
+    }
+
+    /// Test if there are no registered breakpoints.
+    pub fn is_empty(&self) -> bool {
+        self.breakpoints.is_empty()
+    }
+
+    /// The source id and line of the breakpoint which was last hit, if any.
+    pub fn hit(&self) -> Option<(usize, usize)> {
+        self.hit
+    }
+
+    /// Check if a breakpoint is hit by executing the instruction at `ip` with
+    /// the given number of call frames.
+    pub(crate) fn check(&mut self, depth: usize, ip: usize) -> bool {
+        let line = match self.lines.get(ip).copied().flatten() {
+            Some(line) => line,
+            None => return false,
+        };
+
+        self.frames.resize(depth + 1, None);
+
+        if self.frames[depth] == Some(line) {
+            return false;
+        }
+
+        self.frames[depth] = Some(line);
+
+        if !self.breakpoints.contains(&line) {
+            return false;
+        }
+
+        self.hit = Some(line);
+        true
+    }
+
+    /// Forget which lines are being executed, so that the breakpoints can be
+    /// hit again by a new call.
+    pub(crate) fn reset(&mut self) {
+        self.frames.clear();
+        self.hit = None;
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! [Value::Bytes]: crate::Value::Bytes.
 
+use crate::{Integer, Value, VmError, VmErrorKind};
 use std::fmt;
 use std::ops;
 
@@ -81,6 +82,11 @@ impl Bytes {
         self.bytes.shrink_to_fit();
     }
 
+    /// Push a byte to the end of the collection.
+    pub fn push(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
     /// Pop the last byte.
     pub fn pop(&mut self) -> Option<u8> {
         self.bytes.pop()
@@ -92,6 +98,24 @@ impl Bytes {
     }
 }
 
+/// Convert a value into a byte, accepting both bytes and integers in the
+/// range `0..=255`.
+pub(crate) fn byte_from_value(value: Value) -> Result<u8, VmError> {
+    use std::convert::TryInto as _;
+
+    match value {
+        Value::Byte(byte) => Ok(byte),
+        Value::Integer(integer) => match integer.try_into() {
+            Ok(byte) => Ok(byte),
+            Err(..) => Err(VmError::from(VmErrorKind::ValueToIntegerCoercionError {
+                from: Integer::I64(integer),
+                to: std::any::type_name::<u8>(),
+            })),
+        },
+        actual => Err(VmError::expected::<u8>(actual.type_info()?)),
+    }
+}
+
 impl fmt::Debug for Bytes {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_list().entries(&self.bytes).finish()
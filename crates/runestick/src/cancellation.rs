@@ -0,0 +1,46 @@
+//! Cooperative cancellation of running executions.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A token which can be used to cancel a [VmExecution][crate::VmExecution].
+///
+/// This is cheap to clone, and all clones refer to the same cancellation
+/// state. It can be sent to other threads, so that an execution can be
+/// cancelled from somewhere else than where it's running.
+///
+/// Cancellation is cooperative and is checked by the execution before each
+/// instruction is executed, so even a script stuck in an infinite loop can be
+/// cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Construct a new cancellation token which hasn't been cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    /// assert!(!token.is_cancelled());
+    /// token.clone().cancel();
+    /// assert!(token.is_cancelled());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the associated execution.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Test if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
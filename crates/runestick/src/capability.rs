@@ -0,0 +1,52 @@
+//! Capabilities which can be tested for using the `is` operator.
+
+use crate::Hash;
+
+/// Marker type for values which can be iterated over.
+///
+/// A value is iterable if it implements the `INTO_ITER` protocol, like vectors,
+/// objects, and iterators do.
+#[derive(Debug)]
+pub enum Iterable {}
+
+/// Marker type for values which can be called.
+///
+/// Function pointers and closures are callable.
+#[derive(Debug)]
+pub enum Callable {}
+
+/// Marker type for values which can be indexed into.
+///
+/// Vectors, tuples, objects and bytes are indexable, as is any type
+/// implementing the `INDEX_GET` protocol.
+#[derive(Debug)]
+pub enum Indexable {}
+
+/// A capability which a value can be tested for with `value is Capability`.
+///
+/// In contrast to testing for a concrete type, a capability test succeeds for
+/// any value which supports the given set of operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// The value can be iterated over. See [Iterable].
+    Iterable,
+    /// The value can be called. See [Callable].
+    Callable,
+    /// The value can be indexed into. See [Indexable].
+    Indexable,
+}
+
+impl Capability {
+    /// Look up the capability corresponding to the given type hash, if any.
+    pub fn from_hash(hash: Hash) -> Option<Self> {
+        if hash == crate::ITERABLE_TYPE.hash {
+            Some(Self::Iterable)
+        } else if hash == crate::CALLABLE_TYPE.hash {
+            Some(Self::Callable)
+        } else if hash == crate::INDEXABLE_TYPE.hash {
+            Some(Self::Indexable)
+        } else {
+            None
+        }
+    }
+}
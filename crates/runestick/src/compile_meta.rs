@@ -4,14 +4,14 @@ use std::fmt;
 use std::sync::Arc;
 
 /// Metadata about a closure.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CompileMetaCapture {
     /// Identity of the captured variable.
     pub ident: String,
 }
 
 /// Compile-time metadata about a unit.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CompileMeta {
     /// Metadata about a tuple.
     Tuple {
@@ -77,6 +77,11 @@ pub enum CompileMeta {
         /// Sequence of captured variables.
         captures: Arc<Vec<CompileMetaCapture>>,
     },
+    /// A lazily initialized static.
+    Static {
+        /// The item of the static.
+        item: Item,
+    },
     /// A macro.
     Macro {
         /// The item of the macro.
@@ -96,6 +101,7 @@ impl CompileMeta {
             CompileMeta::Function { item, .. } => item,
             CompileMeta::Closure { item, .. } => item,
             CompileMeta::AsyncBlock { item, .. } => item,
+            CompileMeta::Static { item, .. } => item,
             CompileMeta::Macro { item, .. } => item,
         }
     }
@@ -111,6 +117,7 @@ impl CompileMeta {
             Self::Function { value_type, .. } => Some(*value_type),
             Self::Closure { value_type, .. } => Some(*value_type),
             Self::AsyncBlock { value_type, .. } => Some(*value_type),
+            Self::Static { .. } => None,
             Self::Macro { .. } => None,
         }
     }
@@ -143,6 +150,9 @@ impl fmt::Display for CompileMeta {
             Self::AsyncBlock { item, .. } => {
                 write!(fmt, "async block {}", item)?;
             }
+            Self::Static { item, .. } => {
+                write!(fmt, "static {}", item)?;
+            }
             Self::Macro { item, .. } => {
                 write!(fmt, "macro {}", item)?;
             }
@@ -153,7 +163,7 @@ impl fmt::Display for CompileMeta {
 }
 
 /// The metadata about a type.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CompileMetaStruct {
     /// The path to the object.
     pub item: Item,
@@ -162,7 +172,7 @@ pub struct CompileMetaStruct {
 }
 
 /// The metadata about a variant.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CompileMetaTuple {
     /// The path to the tuple.
     pub item: Item,
@@ -1,10 +1,11 @@
 use crate::collections::{HashMap, HashSet};
+use crate::host_access::HostAccessSet;
 use crate::module::{
     ModuleAssociatedFn, ModuleFn, ModuleInternalEnum, ModuleMacro, ModuleType, ModuleUnitType,
 };
 use crate::{
-    CompileMeta, CompileMetaStruct, CompileMetaTuple, Component, Hash, Item, Module, Names, Stack,
-    StaticType, Type, TypeCheck, TypeInfo, ValueType, VmError,
+    CompileMeta, CompileMetaStruct, CompileMetaTuple, Component, Hash, HostAccess, Item, Module,
+    Names, Stack, StaticType, Type, TypeCheck, TypeInfo, ValueType, VmError,
 };
 use std::any;
 use std::fmt;
@@ -221,6 +222,8 @@ pub struct Context {
     internal_enums: HashSet<&'static StaticType>,
     /// All available names in the context.
     names: Names,
+    /// Access to the host system granted to native modules.
+    host_access: HostAccessSet,
 }
 
 impl Context {
@@ -252,6 +255,7 @@ impl Context {
         this.install(&crate::modules::option::module()?)?;
         this.install(&crate::modules::future::module()?)?;
         this.install(&crate::modules::stream::module()?)?;
+        this.install(&crate::modules::channel::module()?)?;
         this.install(&crate::modules::io::module()?)?;
         this.install(&crate::modules::fmt::module()?)?;
         this.has_default_modules = true;
@@ -266,6 +270,39 @@ impl Context {
         self.has_default_modules
     }
 
+    /// Grant or deny the given kind of access to the host system to native
+    /// modules which check for it, like the `env` module in `rune-modules`.
+    ///
+    /// Denied access results in [VmErrorKind::DeniedHostAccess] being raised
+    /// when a script tries to use it.
+    ///
+    /// [VmErrorKind::DeniedHostAccess]: crate::VmErrorKind::DeniedHostAccess
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, HostAccess};
+    ///
+    /// let mut context = Context::new();
+    /// assert!(context.is_host_access_allowed(HostAccess::Env));
+    ///
+    /// context.allow_host_access(HostAccess::Env, false);
+    /// assert!(!context.is_host_access_allowed(HostAccess::Env));
+    /// ```
+    pub fn allow_host_access(&mut self, access: HostAccess, allowed: bool) {
+        self.host_access.set(access, allowed);
+    }
+
+    /// Test if the given kind of access to the host system is granted.
+    pub fn is_host_access_allowed(&self, access: HostAccess) -> bool {
+        self.host_access.is_allowed(access)
+    }
+
+    /// Access the host access granted by this context.
+    pub(crate) fn host_access(&self) -> HostAccessSet {
+        self.host_access
+    }
+
     /// Iterate over known child components of the given name.
     pub fn iter_components<I>(&self, iter: I) -> impl Iterator<Item = &Component>
     where
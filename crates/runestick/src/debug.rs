@@ -14,6 +14,11 @@ pub struct DebugInfo {
     pub functions: HashMap<Hash, DebugSignature>,
     /// Reverse lookup of a function.
     pub functions_rev: HashMap<usize, Hash>,
+    /// The names of functions called from the unit, by hash.
+    pub called_functions: HashMap<Hash, Item>,
+    /// The sources the unit was compiled from, by source id, if they were
+    /// embedded in it.
+    pub sources: Vec<DebugSource>,
 }
 
 impl DebugInfo {
@@ -22,6 +27,11 @@ impl DebugInfo {
         self.instructions.get(ip)
     }
 
+    /// Get the embedded source with the given id.
+    pub fn source_at(&self, source_id: usize) -> Option<&DebugSource> {
+        self.sources.get(source_id)
+    }
+
     /// Get the function corresponding to the given instruction pointer.
     pub fn function_at(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
         let hash = *self.functions_rev.get(&ip)?;
@@ -43,6 +53,16 @@ pub struct DebugInst {
     pub label: Option<DebugLabel>,
 }
 
+/// A source embedded in the debug information of a unit, so that diagnostics
+/// can be rendered for a unit which is loaded without its sources.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DebugSource {
+    /// The name of the source.
+    pub name: String,
+    /// The source text.
+    pub source: String,
+}
+
 /// Debug information on function arguments.
 #[derive(Debug, Serialize, Deserialize)]
 pub enum DebugArgs {
@@ -59,6 +79,10 @@ pub struct DebugSignature {
     pub path: Item,
     /// The number of arguments expected in the function.
     pub args: DebugArgs,
+    /// The id of the source the function was declared in, if available.
+    pub source_id: Option<usize>,
+    /// The span of the function declaration, if available.
+    pub span: Option<Span>,
 }
 
 impl DebugSignature {
@@ -67,6 +91,19 @@ impl DebugSignature {
         Self {
             path,
             args: DebugArgs::Named(args),
+            source_id: None,
+            span: None,
+        }
+    }
+
+    /// Construct a new function signature for a function declared at the
+    /// given location.
+    pub fn with_span(path: Item, args: Vec<String>, source_id: usize, span: Span) -> Self {
+        Self {
+            path,
+            args: DebugArgs::Named(args),
+            source_id: Some(source_id),
+            span: Some(span),
         }
     }
 }
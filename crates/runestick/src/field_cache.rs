@@ -0,0 +1,76 @@
+//! Inline caching for field accesses.
+
+use crate::{Hash, Object, Value};
+
+/// An inline cache for field accesses.
+///
+/// Remembers for every field access instruction the type of the last object
+/// it accessed, and the offset of the field in that object. If the next
+/// object accessed by the same instruction has the same type, the field is
+/// fetched by offset instead of being looked up by name.
+///
+/// The cached offset is always checked against the name of the field
+/// actually stored at it, so a change of type or a differently laid out
+/// object simply invalidates the entry.
+#[derive(Debug, Clone, Default)]
+pub struct FieldCache {
+    /// Cached type and field offset by instruction pointer.
+    entries: Vec<Option<(Hash, usize)>>,
+    /// The number of field accesses served from the cache.
+    hits: usize,
+    /// The number of field accesses which required a lookup by name.
+    misses: usize,
+}
+
+impl FieldCache {
+    /// Construct a new empty cache.
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The number of field accesses which were served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of field accesses which required looking up the field by
+    /// name.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Get the given field from an object of the given type, accessed by the
+    /// instruction at `ip`.
+    pub(crate) fn get(
+        &mut self,
+        ip: usize,
+        hash: Hash,
+        object: &Object,
+        field: &str,
+    ) -> Option<Value> {
+        if let Some(Some((cached, offset))) = self.entries.get(ip) {
+            if *cached == hash {
+                if let Some((key, value)) = object.get_index(*offset) {
+                    if key == field {
+                        self.hits += 1;
+                        return Some(value.clone());
+                    }
+                }
+            }
+        }
+
+        self.misses += 1;
+        let (offset, value) = object.get_full(field)?;
+
+        if self.entries.len() <= ip {
+            self.entries.resize(ip + 1, None);
+        }
+
+        self.entries[ip] = Some((hash, offset));
+        Some(value.clone())
+    }
+}
@@ -1,8 +1,10 @@
 use crate::context::Handler;
+use crate::statics::Statics;
 use crate::VmErrorKind;
 use crate::{
-    Args, Call, Context, FromValue, Future, Generator, Hash, OwnedRef, RawOwnedRef, Shared, Stack,
-    Stream, Tuple, Unit, UnsafeFromValue, Value, Vm, VmCall, VmError, VmHalt,
+    AllowedFunctions, Args, Call, Context, FromValue, Future, Gas, Generator, Hash, Memory, Output,
+    OwnedRef, RawOwnedRef, Shared, Stack, Stream, Tuple, Unit, UnsafeFromValue, Value, Vm, VmCall,
+    VmError, VmHalt,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -38,11 +40,37 @@ impl Function {
                 Self::check_args(A::count(), tuple.args)?;
                 Value::variant_tuple(tuple.enum_hash, tuple.hash, args.into_vec()?)
             }
+            Inner::FnPartial(partial) => partial.call(args.into_vec()?)?,
         };
 
         Ok(T::from_value(value)?)
     }
 
+    /// Perform a call with a dynamic number of arguments.
+    fn call_vec(&self, args: Vec<Value>) -> Result<Value, VmError> {
+        Ok(match &self.inner {
+            Inner::FnHandler(handler) => {
+                let count = args.len();
+                let mut stack = Stack::from(args);
+                (handler.handler)(&mut stack, count)?;
+                stack.pop()?
+            }
+            Inner::FnOffset(fn_offset) => fn_offset.call_vec(args, ())?,
+            Inner::FnClosureOffset(closure) => closure
+                .fn_offset
+                .call_vec(args, (closure.environment.clone(),))?,
+            Inner::FnTuple(tuple) => {
+                Self::check_args(args.len(), tuple.args)?;
+                Value::typed_tuple(tuple.hash, args)
+            }
+            Inner::FnVariantTuple(tuple) => {
+                Self::check_args(args.len(), tuple.args)?;
+                Value::variant_tuple(tuple.enum_hash, tuple.hash, args)
+            }
+            Inner::FnPartial(partial) => partial.call(args)?,
+        })
+    }
+
     /// Call with the given virtual machine. This allows for certain
     /// optimizations, like avoiding the allocation of a new vm state in case
     /// the call is internal.
@@ -92,6 +120,10 @@ impl Function {
                 vm.stack_mut().push(value);
                 None
             }
+            Inner::FnPartial(partial) => {
+                let args = partial.push_args(vm.stack_mut(), args)?;
+                return partial.function.borrow_ref()?.call_with_vm(vm, args);
+            }
         };
 
         Ok(reason)
@@ -164,6 +196,14 @@ impl Function {
         }
     }
 
+    /// Create a function pointer which calls the given function with `args`
+    /// bound as its first arguments.
+    pub(crate) fn from_partial(function: Shared<Function>, args: Vec<Value>) -> Self {
+        Self {
+            inner: Inner::FnPartial(FnPartial { function, args }),
+        }
+    }
+
     #[inline]
     fn check_args(actual: usize, expected: usize) -> Result<(), VmError> {
         if actual != expected {
@@ -203,6 +243,13 @@ impl fmt::Debug for Function {
                     tuple.enum_hash, tuple.hash
                 )?;
             }
+            Inner::FnPartial(partial) => {
+                write!(
+                    f,
+                    "partial (function: {:?}, args: {:?})",
+                    partial.function, partial.args
+                )?;
+            }
         }
 
         Ok(())
@@ -228,6 +275,8 @@ enum Inner {
     FnTuple(FnTuple),
     /// Constructor for a tuple variant.
     FnVariantTuple(FnVariantTuple),
+    /// A function with some of its leading arguments already bound.
+    FnPartial(FnPartial),
 }
 
 struct FnHandler {
@@ -262,12 +311,42 @@ impl FnOffset {
     {
         Function::check_args(A::count(), self.args)?;
 
+        let mut vm = self.vm();
+        args.into_stack(vm.stack_mut())?;
+        extra.into_stack(vm.stack_mut())?;
+        self.complete(vm)
+    }
+
+    /// Perform a call into the specified offset with a dynamic number of
+    /// arguments.
+    fn call_vec<E>(&self, args: Vec<Value>, extra: E) -> Result<Value, VmError>
+    where
+        E: Args,
+    {
+        Function::check_args(args.len(), self.args)?;
+
+        let mut vm = self.vm();
+        vm.stack_mut().extend(args);
+        extra.into_stack(vm.stack_mut())?;
+        self.complete(vm)
+    }
+
+    /// Construct a new virtual machine positioned at the offset of the
+    /// function, inheriting the current execution environment.
+    fn vm(&self) -> Vm {
         let mut vm = Vm::new(self.context.clone(), self.unit.clone());
 
         vm.set_ip(self.offset);
-        args.into_stack(vm.stack_mut())?;
-        extra.into_stack(vm.stack_mut())?;
+        vm.set_memory(Memory::current());
+        vm.set_gas(Gas::current());
+        vm.set_allowed_functions(AllowedFunctions::current());
+        vm.set_statics(Statics::current());
+        vm.set_output(Output::current());
+        vm
+    }
 
+    /// Run the virtual machine according to the calling convention.
+    fn complete(&self, vm: Vm) -> Result<Value, VmError> {
         Ok(match self.call {
             Call::Stream => Value::from(Stream::new(vm)),
             Call::Generator => Value::from(Generator::new(vm)),
@@ -297,8 +376,22 @@ impl FnOffset {
 
         let mut new_stack = vm.stack_mut().drain_stack_top(args)?.collect::<Stack>();
         extra.into_stack(&mut new_stack)?;
+        let memory = vm.memory().cloned();
+        let gas = vm.gas().cloned();
+        let cancellation = vm.cancellation().cloned();
+        let arithmetic = vm.arithmetic();
+        let allowed_functions = vm.allowed_functions().cloned();
+        let statics = vm.statics().cloned();
+        let output = vm.output().cloned();
         let mut vm = Vm::new_with_stack(self.context.clone(), self.unit.clone(), new_stack);
         vm.set_ip(self.offset);
+        vm.set_memory(memory);
+        vm.set_gas(gas);
+        vm.set_cancellation(cancellation);
+        vm.set_arithmetic(arithmetic);
+        vm.set_allowed_functions(allowed_functions);
+        vm.set_statics(statics);
+        vm.set_output(output);
         Ok(Some(VmCall::new(self.call, vm)))
     }
 }
@@ -323,6 +416,32 @@ struct FnClosureOffset {
     environment: Shared<Tuple>,
 }
 
+#[derive(Debug)]
+struct FnPartial {
+    /// The function being partially applied.
+    function: Shared<Function>,
+    /// The leading arguments bound to the function.
+    args: Vec<Value>,
+}
+
+impl FnPartial {
+    /// Call the function with the bound arguments followed by `args`.
+    fn call(&self, args: Vec<Value>) -> Result<Value, VmError> {
+        let mut all = self.args.clone();
+        all.extend(args);
+        self.function.borrow_ref()?.call_vec(all)
+    }
+
+    /// Insert the bound arguments beneath the `args` topmost values of the
+    /// stack, returning the total number of arguments.
+    fn push_args(&self, stack: &mut Stack, args: usize) -> Result<usize, VmError> {
+        let rest = stack.pop_sequence(args)?;
+        stack.extend(self.args.iter().cloned());
+        stack.extend(rest);
+        Ok(self.args.len() + args)
+    }
+}
+
 #[derive(Debug)]
 struct FnTuple {
     /// The type of the tuple.
@@ -79,6 +79,16 @@ impl<T, F> SelectFuture<T, F> {
     pub fn new(data: T, future: F) -> Self {
         Self { data, future }
     }
+
+    /// Access the data associated with the select future.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Convert into the associated data and the wrapped future.
+    pub fn into_inner(self) -> (T, F) {
+        (self.data, self.future)
+    }
 }
 
 impl<T, F> future::Future for SelectFuture<T, F>
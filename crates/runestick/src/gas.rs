@@ -0,0 +1,193 @@
+//! Weighted metering of the instructions executed by a virtual machine.
+//!
+//! When [Gas] is associated with a [Vm][crate::Vm], every instruction executed
+//! consumes an amount of gas determined by its [GasCosts]. Once the pool is
+//! exhausted execution errors with [VmErrorKind::OutOfGas].
+
+use crate::collections::HashMap;
+use crate::{Inst, VmError, VmErrorKind};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::mem;
+use std::rc::Rc;
+
+thread_local! {
+    /// The gas pool installed for the currently running virtual machine, if
+    /// any.
+    static CURRENT: RefCell<Option<Gas>> = RefCell::new(None);
+}
+
+/// A table mapping instructions to the amount of gas they cost to execute.
+///
+/// Instructions which haven't been assigned a cost use the default cost,
+/// which is `1` unless otherwise configured. Costs apply to every instruction
+/// of the same kind, regardless of its operands.
+#[derive(Debug, Clone)]
+pub struct GasCosts {
+    default: u64,
+    costs: HashMap<mem::Discriminant<Inst>, u64>,
+}
+
+impl GasCosts {
+    /// Construct a cost table where every instruction costs `1`.
+    pub fn new() -> Self {
+        Self::with_default(1)
+    }
+
+    /// Construct a cost table where every instruction costs `default`.
+    pub fn with_default(default: u64) -> Self {
+        Self {
+            default,
+            costs: HashMap::new(),
+        }
+    }
+
+    /// Set the cost of the kind of instruction of `inst`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{GasCosts, Hash, Inst};
+    ///
+    /// let mut costs = GasCosts::new();
+    /// costs.set(&Inst::Call { hash: Hash::of("foo"), args: 0 }, 100);
+    ///
+    /// assert_eq!(costs.cost(&Inst::Pop), 1);
+    /// assert_eq!(costs.cost(&Inst::Call { hash: Hash::of("bar"), args: 2 }), 100);
+    /// ```
+    pub fn set(&mut self, inst: &Inst, cost: u64) {
+        self.costs.insert(mem::discriminant(inst), cost);
+    }
+
+    /// Get the cost of executing the given instruction.
+    pub fn cost(&self, inst: &Inst) -> u64 {
+        if self.costs.is_empty() {
+            return self.default;
+        }
+
+        match self.costs.get(&mem::discriminant(inst)) {
+            Some(cost) => *cost,
+            None => self.default,
+        }
+    }
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A pool of gas consumed by executing instructions.
+///
+/// This is cheap to clone, all clones refer to the same pool.
+#[derive(Clone)]
+pub struct Gas {
+    inner: Rc<GasInner>,
+}
+
+struct GasInner {
+    /// The amount of gas the pool started out with.
+    limit: u64,
+    /// The amount of gas remaining.
+    remaining: Cell<u64>,
+    /// The cost of each instruction.
+    costs: GasCosts,
+}
+
+impl Gas {
+    /// Construct a new pool with `limit` gas, where every instruction costs
+    /// `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Gas;
+    ///
+    /// let gas = Gas::new(1000);
+    /// assert_eq!(gas.limit(), 1000);
+    /// assert_eq!(gas.remaining(), 1000);
+    /// ```
+    pub fn new(limit: u64) -> Self {
+        Self::with_costs(limit, GasCosts::new())
+    }
+
+    /// Construct a new pool with `limit` gas, where instructions cost what's
+    /// specified in `costs`.
+    pub fn with_costs(limit: u64, costs: GasCosts) -> Self {
+        Self {
+            inner: Rc::new(GasInner {
+                limit,
+                remaining: Cell::new(limit),
+                costs,
+            }),
+        }
+    }
+
+    /// The amount of gas the pool started out with.
+    pub fn limit(&self) -> u64 {
+        self.inner.limit
+    }
+
+    /// The amount of gas remaining.
+    pub fn remaining(&self) -> u64 {
+        self.inner.remaining.get()
+    }
+
+    /// The amount of gas used.
+    pub fn used(&self) -> u64 {
+        self.inner.limit - self.inner.remaining.get()
+    }
+
+    /// Consume the gas needed to execute the given instruction.
+    ///
+    /// If there isn't enough gas left, the pool is left untouched and
+    /// [VmErrorKind::OutOfGas] is returned.
+    pub fn charge(&self, inst: &Inst) -> Result<(), VmError> {
+        let cost = self.inner.costs.cost(inst);
+        let remaining = self.inner.remaining.get();
+
+        if cost > remaining {
+            return Err(VmError::from(VmErrorKind::OutOfGas {
+                limit: self.inner.limit,
+            }));
+        }
+
+        self.inner.remaining.set(remaining - cost);
+        Ok(())
+    }
+
+    /// Get the gas pool installed for the currently running virtual machine,
+    /// if any.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Install this gas pool for the current thread until the returned guard
+    /// is dropped.
+    pub(crate) fn install(&self) -> GasGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        GasGuard { previous }
+    }
+}
+
+impl fmt::Debug for Gas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gas")
+            .field("limit", &self.inner.limit)
+            .field("remaining", &self.inner.remaining.get())
+            .finish()
+    }
+}
+
+/// Guard which restores the previously installed gas pool when dropped.
+pub(crate) struct GasGuard {
+    previous: Option<Gas>,
+}
+
+impl Drop for GasGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
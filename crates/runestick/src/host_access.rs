@@ -0,0 +1,146 @@
+//! Gating access to the host system from native modules.
+//!
+//! Native modules which touch the host system, like the `env`, `fs`, and
+//! `process` modules in `rune-modules`, check that the [Context][crate::Context] of the running
+//! virtual machine grants them access through [HostAccess::check] before doing
+//! so.
+
+use crate::{VmError, VmErrorKind};
+use std::cell::Cell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+thread_local! {
+    /// The host access granted by the context of the currently running virtual
+    /// machine, if any.
+    static CURRENT: Cell<Option<HostAccessSet>> = Cell::new(None);
+}
+
+/// A kind of access to the host system which a [Context][crate::Context] can
+/// grant or deny.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostAccess {
+    /// Reading environment variables and the arguments of the process. Granted
+    /// by default.
+    Env,
+    /// Reading and writing files. Granted by default.
+    Fs,
+    /// Running external commands. Since this effectively grants all other
+    /// kinds of access, it's denied by default.
+    Process,
+}
+
+impl HostAccess {
+    /// Check that the context of the currently running virtual machine grants
+    /// this access, erroring with [VmErrorKind::DeniedHostAccess] otherwise.
+    ///
+    /// Native functions which are called outside of a virtual machine are
+    /// always granted access.
+    pub fn check(self) -> Result<(), VmError> {
+        match CURRENT.with(Cell::get) {
+            Some(set) if !set.is_allowed(self) => {
+                Err(VmError::from(VmErrorKind::DeniedHostAccess {
+                    access: self,
+                }))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for HostAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env => write!(f, "environment"),
+            Self::Fs => write!(f, "filesystem"),
+            Self::Process => write!(f, "process"),
+        }
+    }
+}
+
+/// The kinds of host access granted by a context.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HostAccessSet {
+    env: bool,
+    fs: bool,
+    process: bool,
+}
+
+impl HostAccessSet {
+    /// Test if the given access is granted.
+    pub(crate) fn is_allowed(self, access: HostAccess) -> bool {
+        match access {
+            HostAccess::Env => self.env,
+            HostAccess::Fs => self.fs,
+            HostAccess::Process => self.process,
+        }
+    }
+
+    /// Grant or deny the given access.
+    pub(crate) fn set(&mut self, access: HostAccess, allowed: bool) {
+        match access {
+            HostAccess::Env => self.env = allowed,
+            HostAccess::Fs => self.fs = allowed,
+            HostAccess::Process => self.process = allowed,
+        }
+    }
+
+    /// Install this set for the current thread until the returned guard is
+    /// dropped.
+    pub(crate) fn install(self) -> HostAccessGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self)));
+        HostAccessGuard { previous }
+    }
+
+    /// Wrap the given future so that this set is installed while it's being
+    /// polled, since the futures of async native functions are polled outside
+    /// of the virtual machine which called them.
+    pub(crate) fn scope<F>(self, future: F) -> HostAccessScope<F> {
+        HostAccessScope {
+            future,
+            access: self,
+        }
+    }
+}
+
+impl Default for HostAccessSet {
+    fn default() -> Self {
+        Self {
+            env: true,
+            fs: true,
+            process: false,
+        }
+    }
+}
+
+/// Guard which restores the previously installed host access when dropped.
+pub(crate) struct HostAccessGuard {
+    previous: Option<HostAccessSet>,
+}
+
+impl Drop for HostAccessGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| current.set(previous));
+    }
+}
+
+/// A future which installs a set of host access while it's being polled.
+pub(crate) struct HostAccessScope<F> {
+    future: F,
+    access: HostAccessSet,
+}
+
+impl<F> Future for HostAccessScope<F>
+where
+    F: Future + Unpin,
+{
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = self.access.install();
+        Pin::new(&mut self.future).poll(cx)
+    }
+}
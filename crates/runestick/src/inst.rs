@@ -156,6 +156,34 @@ pub enum Inst {
         /// The frame offset to assign to.
         offset: usize,
     },
+    /// Load the value of a static onto the stack.
+    ///
+    /// If the static hasn't been initialized yet, this calls its initializer
+    /// function identified by `hash`, which stores the value through
+    /// [StaticStore][Inst::StaticStore] before returning it.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// => <value>
+    /// ```
+    Static {
+        /// The hash of the static.
+        hash: Hash,
+    },
+    /// Store the value on the top of the stack as the value of a static,
+    /// leaving it on the stack.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <value>
+    /// ```
+    StaticStore {
+        /// The hash of the static.
+        hash: Hash,
+    },
     /// Encode a function pointer on the stack.
     ///
     /// # Operation
@@ -233,6 +261,31 @@ pub enum Inst {
         /// The number of arguments expected on the stack for this call.
         args: usize,
     },
+    /// Perform a function call on a function pointer stored on the stack,
+    /// using the elements of a vector as arguments.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <fn>
+    /// <vec>
+    /// => <ret>
+    /// ```
+    CallFnSpread,
+    /// Perform an instance function call, using the elements of a vector as
+    /// arguments. The first element of the vector is the instance being
+    /// called on.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <vec>
+    /// => <ret>
+    /// ```
+    CallInstanceSpread {
+        /// The hash of the name of the function to call.
+        hash: Hash,
+    },
     /// Perform an index get operation. Pushing the result on the stack.
     ///
     /// # Operation
@@ -242,7 +295,29 @@ pub enum Inst {
     /// <index>
     /// => <value>
     /// ```
-    IndexGet,
+    IndexGet {
+        /// If negative integer indices should be interpreted as offsets from
+        /// the end of the target.
+        negative: bool,
+    },
+    /// Slice the target on the top of the stack, producing a fresh copy of the
+    /// sliced range. Bounds which are not present default to the start and end
+    /// of the target respectively.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <target>
+    /// <to>?
+    /// <from>?
+    /// => <value>
+    /// ```
+    Slice {
+        /// If the slice has a start bound on the stack.
+        from: bool,
+        /// If the slice has an end bound on the stack.
+        to: bool,
+    },
     /// Get the given index out of a tuple on the top of the stack.
     /// Errors if the item doesn't exist or the item is not a tuple.
     ///
@@ -326,7 +401,11 @@ pub enum Inst {
     /// <value>
     /// => *noop*
     /// ```
-    IndexSet,
+    IndexSet {
+        /// If negative integer indices should be interpreted as offsets from
+        /// the end of the target.
+        negative: bool,
+    },
     /// Push a literal integer.
     Integer {
         /// The number to push.
@@ -352,7 +431,8 @@ pub enum Inst {
     /// stack.
     ///
     /// This operation will block the VM until at least one of the underlying
-    /// futures complete.
+    /// futures complete, unless the select has a default branch. In that case
+    /// a unit is pushed instead, if none of the futures are ready.
     ///
     /// # Operation
     ///
@@ -363,6 +443,8 @@ pub enum Inst {
     Select {
         /// The number of futures to poll.
         len: usize,
+        /// If the select has a default branch, which makes it non-blocking.
+        default: bool,
     },
     /// Pop the value on the stack, discarding its result.
     ///
@@ -578,6 +660,19 @@ pub enum Inst {
         /// The size of the vector.
         count: usize,
     },
+    /// Concatenate `count` vectors from the stack into a new vector. Errors
+    /// if any of the values is not a vector.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <vec..>
+    /// => <vec>
+    /// ```
+    VecConcat {
+        /// The number of vectors to concatenate.
+        count: usize,
+    },
     /// Construct a push a tuple value onto the stack. The number of elements
     /// in the tuple are determined by `count` and are popped from the stack.
     ///
@@ -741,6 +836,17 @@ pub enum Inst {
     /// => <boolean>
     /// ```
     IsNot,
+    /// Test if the second item on the stack is contained in the collection at
+    /// the top of the stack.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <collection>
+    /// <value>
+    /// => <boolean>
+    /// ```
+    In,
     /// Pop two values from the stack and test if they are both boolean true.
     ///
     /// # Operation
@@ -1085,6 +1191,12 @@ impl fmt::Display for Inst {
             Self::CallInstance { hash, args } => {
                 write!(fmt, "call-instance {}, {}", hash, args)?;
             }
+            Self::Static { hash } => {
+                write!(fmt, "static {}", hash)?;
+            }
+            Self::StaticStore { hash } => {
+                write!(fmt, "static-store {}", hash)?;
+            }
             Self::Fn { hash } => {
                 write!(fmt, "fn {}", hash)?;
             }
@@ -1094,11 +1206,20 @@ impl fmt::Display for Inst {
             Self::CallFn { args } => {
                 write!(fmt, "call-fn {}", args)?;
             }
+            Self::CallFnSpread => {
+                write!(fmt, "call-fn-spread")?;
+            }
+            Self::CallInstanceSpread { hash } => {
+                write!(fmt, "call-instance-spread {}", hash)?;
+            }
             Self::LoadInstanceFn { hash } => {
                 write!(fmt, "load-instance-fn {}", hash)?;
             }
-            Self::IndexGet => {
-                write!(fmt, "index-get")?;
+            Self::IndexGet { negative } => {
+                write!(fmt, "index-get {}", negative)?;
+            }
+            Self::Slice { from, to } => {
+                write!(fmt, "slice {}, {}", from, to)?;
             }
             Self::TupleIndexGet { index } => {
                 write!(fmt, "tuple-index-get {}", index)?;
@@ -1115,20 +1236,21 @@ impl fmt::Display for Inst {
             Self::ObjectSlotIndexGetAt { offset, slot } => {
                 write!(fmt, "object-slot-index-get-at {}, {}", offset, slot)?;
             }
-            Self::IndexSet => {
-                write!(fmt, "index-set")?;
+            Self::IndexSet { negative } => {
+                write!(fmt, "index-set {}", negative)?;
             }
             Self::Integer { number } => {
                 write!(fmt, "integer {}", number)?;
             }
             Self::Float { number } => {
-                write!(fmt, "float {}", number)?;
+                write!(fmt, "float ")?;
+                crate::value::write_float(fmt, *number)?;
             }
             Self::Await => {
                 write!(fmt, "await")?;
             }
-            Self::Select { len } => {
-                write!(fmt, "select {}", len)?;
+            Self::Select { len, default } => {
+                write!(fmt, "select {}, {}", len, default)?;
             }
             Self::Pop => {
                 write!(fmt, "pop")?;
@@ -1196,6 +1318,9 @@ impl fmt::Display for Inst {
             Self::Vec { count } => {
                 write!(fmt, "vec {}", count)?;
             }
+            Self::VecConcat { count } => {
+                write!(fmt, "vec-concat {}", count)?;
+            }
             Self::Tuple { count } => {
                 write!(fmt, "tuple {}", count)?;
             }
@@ -1236,6 +1361,9 @@ impl fmt::Display for Inst {
             Self::IsNot => {
                 write!(fmt, "is-not")?;
             }
+            Self::In => {
+                write!(fmt, "in")?;
+            }
             Self::And => {
                 write!(fmt, "and")?;
             }
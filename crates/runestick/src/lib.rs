@@ -50,24 +50,34 @@ mod vm;
 #[macro_use]
 mod macros;
 mod access;
+mod allowed_functions;
 mod args;
+mod arithmetic;
 mod awaited;
+mod breakpoints;
 mod bytes;
 mod call;
+mod cancellation;
+mod capability;
 mod compile_meta;
 pub mod debug;
+mod field_cache;
 mod function;
 mod future;
+mod gas;
 mod generator;
 mod generator_state;
 mod hash;
+mod host_access;
 mod inst;
 mod item;
 mod label;
+mod memory;
 pub mod module;
 pub mod modules;
 mod names;
 mod object;
+mod output;
 mod panic;
 mod protocol;
 mod reflection;
@@ -79,16 +89,19 @@ mod span;
 mod stack;
 mod static_string;
 mod static_type;
+mod statics;
 mod stream;
 mod tuple;
 mod type_;
 mod type_info;
 mod unit;
+mod unit_diff;
 mod vec_tuple;
 mod vm_call;
 mod vm_error;
 mod vm_execution;
 mod vm_halt;
+mod watchpoints;
 
 crate::__internal_impl_external!(anyhow::Error);
 
@@ -111,9 +124,10 @@ pub use self::source::Source;
 pub use self::span::Span;
 pub use self::static_string::StaticString;
 pub use self::static_type::{
-    StaticType, BOOL_TYPE, BYTES_TYPE, BYTE_TYPE, CHAR_TYPE, FLOAT_TYPE, FUNCTION_TYPE,
-    FUTURE_TYPE, GENERATOR_STATE_TYPE, GENERATOR_TYPE, INTEGER_TYPE, OBJECT_TYPE, OPTION_TYPE,
-    RESULT_TYPE, STREAM_TYPE, STRING_TYPE, TUPLE_TYPE, UNIT_TYPE, VEC_TYPE,
+    StaticType, BOOL_TYPE, BYTES_TYPE, BYTE_TYPE, CALLABLE_TYPE, CHAR_TYPE, FLOAT_TYPE,
+    FUNCTION_TYPE, FUTURE_TYPE, GENERATOR_STATE_TYPE, GENERATOR_TYPE, INDEXABLE_TYPE, INTEGER_TYPE,
+    ITERABLE_TYPE, OBJECT_TYPE, OPTION_TYPE, RESULT_TYPE, STREAM_TYPE, STRING_TYPE, TUPLE_TYPE,
+    UNIT_TYPE, VEC_TYPE,
 };
 pub use self::stream::Stream;
 pub use self::tuple::Tuple;
@@ -123,19 +137,29 @@ pub use crate::access::{
     AccessError, BorrowMut, BorrowRef, NotAccessibleMut, NotAccessibleRef, RawBorrowedMut,
     RawBorrowedRef,
 };
+pub use crate::allowed_functions::AllowedFunctions;
 pub use crate::any::{Any, AnyVtable};
+pub use crate::arithmetic::Arithmetic;
 pub use crate::awaited::Awaited;
+pub use crate::breakpoints::Breakpoints;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
+pub use crate::cancellation::CancellationToken;
+pub use crate::capability::{Callable, Capability, Indexable, Iterable};
 pub use crate::context::{Context, ContextError};
-pub use crate::debug::{DebugInfo, DebugInst};
+pub use crate::debug::{DebugInfo, DebugInst, DebugSource};
+pub use crate::field_cache::FieldCache;
 pub use crate::function::Function;
 pub use crate::future::Future;
+pub use crate::gas::{Gas, GasCosts};
 pub use crate::hash::{Hash, IntoHash};
+pub use crate::host_access::HostAccess;
 pub use crate::inst::{Inst, PanicReason, TypeCheck};
 pub use crate::item::{Component, Item};
+pub use crate::memory::Memory;
 pub use crate::names::Names;
 pub use crate::object::Object;
+pub use crate::output::{Output, OutputBuffer};
 pub use crate::panic::Panic;
 pub use crate::protocol::{
     Protocol, ADD, ADD_ASSIGN, BIT_AND, BIT_AND_ASSIGN, BIT_OR, BIT_OR_ASSIGN, BIT_XOR,
@@ -145,7 +169,10 @@ pub use crate::protocol::{
 pub use crate::reflection::{FromAny, FromValue, ToValue, UnsafeFromValue, ValueType};
 pub use crate::shared::{OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared};
 pub use crate::stack::{Stack, StackError};
-pub use crate::unit::{Unit, UnitFn, UnitTypeInfo};
+pub use crate::unit::{
+    DisassembledInst, LinkError, Unit, UnitFn, UnitHover, UnitSymbol, UnitTypeInfo,
+};
+pub use crate::unit_diff::{FunctionDiff, FunctionDiffKind, InstDiff};
 pub use crate::value::{Integer, TupleVariant, TypedObject, TypedTuple, Value, VariantObject};
 pub use crate::vec_tuple::VecTuple;
 pub use crate::vm::{CallFrame, Vm};
@@ -153,6 +180,7 @@ pub use crate::vm_call::VmCall;
 pub use crate::vm_error::{VmError, VmErrorKind};
 pub use crate::vm_execution::VmExecution;
 pub use crate::vm_halt::{VmHalt, VmHaltInfo};
+pub use crate::watchpoints::Watchpoints;
 pub(crate) use runestick_macros::__internal_impl_external;
 pub use runestick_macros::{Any, FromValue};
 
@@ -0,0 +1,179 @@
+//! Accounting of memory allocated by a virtual machine.
+//!
+//! When a [Memory] limit is associated with a [Vm][crate::Vm], every
+//! [Shared][crate::Shared] value constructed while the virtual machine is
+//! running is charged against it. The charge is released once the value is
+//! freed.
+//!
+//! Strings, byte arrays, vectors and objects are additionally charged for the
+//! capacity of their buffers, which is re-measured as they grow. Operations
+//! which perform a single large allocation reserve it up front, so that they
+//! fail before allocating instead of after.
+
+use crate::{VmError, VmErrorKind};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+thread_local! {
+    /// The memory accounting installed for the currently running virtual
+    /// machine, if any.
+    static CURRENT: RefCell<Option<Memory>> = RefCell::new(None);
+}
+
+/// Memory accounting with a fixed upper limit.
+///
+/// This is cheap to clone, all clones refer to the same counter.
+#[derive(Clone)]
+pub struct Memory {
+    inner: Rc<MemoryInner>,
+}
+
+struct MemoryInner {
+    /// The maximum number of bytes that can be allocated.
+    limit: usize,
+    /// The number of bytes currently allocated.
+    used: Cell<usize>,
+}
+
+impl Memory {
+    /// Construct new memory accounting limited to `limit` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Memory;
+    ///
+    /// let memory = Memory::new(1024);
+    /// assert_eq!(memory.limit(), 1024);
+    /// assert_eq!(memory.used(), 0);
+    /// ```
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Rc::new(MemoryInner {
+                limit,
+                used: Cell::new(0),
+            }),
+        }
+    }
+
+    /// The maximum number of bytes that can be allocated.
+    pub fn limit(&self) -> usize {
+        self.inner.limit
+    }
+
+    /// The number of bytes currently allocated.
+    pub fn used(&self) -> usize {
+        self.inner.used.get()
+    }
+
+    /// Check that the allocated memory is within the configured limit.
+    pub fn check(&self) -> Result<(), VmError> {
+        let used = self.inner.used.get();
+
+        if used > self.inner.limit {
+            return Err(VmError::from(VmErrorKind::MemoryLimitExceeded {
+                limit: self.inner.limit,
+                used,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Get the memory accounting installed for the currently running virtual
+    /// machine, if any.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Install this memory accounting for the current thread until the
+    /// returned guard is dropped.
+    pub(crate) fn install(&self) -> MemoryGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        MemoryGuard { previous }
+    }
+
+    /// Charge `size` bytes against the currently installed memory accounting.
+    ///
+    /// The charge is released when the returned value is dropped.
+    pub(crate) fn charge_current(size: usize) -> Option<MemoryCharge> {
+        CURRENT.with(|current| {
+            let memory = current.borrow().clone()?;
+            let used = memory.inner.used.get().saturating_add(size);
+            memory.inner.used.set(used);
+
+            Some(MemoryCharge {
+                memory,
+                size: Cell::new(size),
+            })
+        })
+    }
+
+    /// Check that `size` more bytes can be allocated under the currently
+    /// installed memory accounting, before performing the allocation.
+    pub(crate) fn reserve_current(size: usize) -> Result<(), VmError> {
+        CURRENT.with(|current| match &*current.borrow() {
+            Some(memory) => {
+                let used = memory.inner.used.get().saturating_add(size);
+
+                if used > memory.inner.limit {
+                    return Err(VmError::from(VmErrorKind::MemoryLimitExceeded {
+                        limit: memory.inner.limit,
+                        used,
+                    }));
+                }
+
+                Ok(())
+            }
+            None => Ok(()),
+        })
+    }
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memory")
+            .field("limit", &self.inner.limit)
+            .field("used", &self.inner.used.get())
+            .finish()
+    }
+}
+
+/// A number of bytes charged against a memory limit.
+pub(crate) struct MemoryCharge {
+    memory: Memory,
+    size: Cell<usize>,
+}
+
+impl MemoryCharge {
+    /// Change the number of bytes charged.
+    pub(crate) fn resize(&self, size: usize) {
+        let inner = &self.memory.inner;
+        let used = inner.used.get().saturating_sub(self.size.get());
+        inner.used.set(used.saturating_add(size));
+        self.size.set(size);
+    }
+}
+
+impl Drop for MemoryCharge {
+    fn drop(&mut self) {
+        let inner = &self.memory.inner;
+        inner
+            .used
+            .set(inner.used.get().saturating_sub(self.size.get()));
+    }
+}
+
+/// Guard which restores the previously installed memory accounting when
+/// dropped.
+pub(crate) struct MemoryGuard {
+    previous: Option<Memory>,
+}
+
+impl Drop for MemoryGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
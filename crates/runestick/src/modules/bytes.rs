@@ -1,6 +1,7 @@
 //! `std::bytes` module.
 
-use crate::{Bytes, ContextError, Module};
+use crate::{Bytes, ContextError, Memory, Module, Value, VmError};
+use std::string::FromUtf8Error;
 
 /// Construct the `std::bytes` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -8,21 +9,63 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.ty(&["Bytes"]).build::<Bytes>()?;
     module.function(&["Bytes", "new"], Bytes::new)?;
-    module.function(&["Bytes", "with_capacity"], Bytes::with_capacity)?;
+    module.function(&["Bytes", "with_capacity"], with_capacity)?;
     module.function(&["Bytes", "from_vec"], Bytes::from_vec)?;
 
     module.inst_fn("into_vec", Bytes::into_vec)?;
+    module.inst_fn("into_string", into_string)?;
+    module.inst_fn("into_string_lossy", into_string_lossy)?;
     module.inst_fn("extend", Bytes::extend)?;
     module.inst_fn("extend_str", Bytes::extend_str)?;
+    module.inst_fn("push", push)?;
     module.inst_fn("pop", Bytes::pop)?;
     module.inst_fn("last", Bytes::last)?;
 
     module.inst_fn("len", Bytes::len)?;
     module.inst_fn("capacity", Bytes::capacity)?;
     module.inst_fn("clear", Bytes::clear)?;
-    module.inst_fn("reserve", Bytes::reserve)?;
-    module.inst_fn("reserve_exact", Bytes::reserve_exact)?;
+    module.inst_fn("reserve", reserve)?;
+    module.inst_fn("reserve_exact", reserve_exact)?;
     module.inst_fn("clone", Bytes::clone)?;
     module.inst_fn("shrink_to_fit", Bytes::shrink_to_fit)?;
     Ok(module)
 }
+
+/// Construct bytes with the given capacity, checking it against the memory
+/// limit before allocating.
+fn with_capacity(capacity: usize) -> Result<Bytes, VmError> {
+    Memory::reserve_current(capacity)?;
+    Ok(Bytes::with_capacity(capacity))
+}
+
+/// Reserve capacity for at least `additional` more bytes.
+fn reserve(bytes: &mut Bytes, additional: usize) -> Result<(), VmError> {
+    Memory::reserve_current(additional)?;
+    bytes.reserve(additional);
+    Ok(())
+}
+
+/// Reserve capacity for exactly `additional` more bytes.
+fn reserve_exact(bytes: &mut Bytes, additional: usize) -> Result<(), VmError> {
+    Memory::reserve_current(additional)?;
+    bytes.reserve_exact(additional);
+    Ok(())
+}
+
+/// Push a byte or an integer in the range `0..=255` to the end of the bytes.
+fn push(bytes: &mut Bytes, value: Value) -> Result<(), VmError> {
+    bytes.push(crate::bytes::byte_from_value(value)?);
+    Ok(())
+}
+
+/// Decode the bytes as UTF-8 into a string, erroring if they are not valid
+/// UTF-8.
+fn into_string(bytes: Bytes) -> Result<String, FromUtf8Error> {
+    String::from_utf8(bytes.into_vec())
+}
+
+/// Decode the bytes as UTF-8 into a string, replacing invalid sequences with
+/// `U+FFFD REPLACEMENT CHARACTER`.
+fn into_string_lossy(bytes: &Bytes) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
@@ -0,0 +1,98 @@
+//! The `std::channel` module.
+//!
+//! Provides an asynchronous, unbounded multi-producer single-consumer channel
+//! which can be used to communicate between concurrently running scripts, or
+//! between a script and its host.
+
+use crate::{ContextError, Module, Value};
+use futures::channel::mpsc;
+use futures::StreamExt as _;
+
+/// Construct the `std::channel` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "channel"]);
+    module.ty(&["Sender"]).build::<Sender>()?;
+    module.ty(&["Receiver"]).build::<Receiver>()?;
+
+    module.function(&["channel"], channel)?;
+    module.inst_fn("send", Sender::send)?;
+    module.inst_fn("clone", Sender::clone)?;
+    module.inst_fn("close", Sender::close)?;
+    module.async_inst_fn("recv", Receiver::recv)?;
+    module.inst_fn("close", Receiver::close)?;
+    Ok(module)
+}
+
+/// Construct a new unbounded channel, returning its sending and receiving
+/// halves.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::modules::channel;
+/// use runestick::Value;
+///
+/// # fn main() {
+/// let (sender, mut receiver) = channel::channel();
+/// assert!(sender.send(Value::Integer(42)).is_ok());
+///
+/// let value = futures::executor::block_on(receiver.recv());
+/// assert!(matches!(value, Some(Value::Integer(42))));
+/// # }
+/// ```
+pub fn channel() -> (Sender, Receiver) {
+    let (tx, rx) = mpsc::unbounded();
+    (Sender { tx }, Receiver { rx })
+}
+
+/// The sending half of a channel.
+#[derive(Debug, Clone)]
+pub struct Sender {
+    tx: mpsc::UnboundedSender<Value>,
+}
+
+impl Sender {
+    /// Send a value over the channel.
+    ///
+    /// If the receiving half has been dropped or closed, the value is handed
+    /// back as an error.
+    pub fn send(&self, value: Value) -> Result<(), Value> {
+        self.tx
+            .unbounded_send(value)
+            .map_err(mpsc::TrySendError::into_inner)
+    }
+
+    /// Close this sender.
+    ///
+    /// Once all senders are closed or dropped, the receiver drains any
+    /// buffered values and then yields `None`.
+    pub fn close(&mut self) {
+        self.tx.disconnect();
+    }
+}
+
+/// The receiving half of a channel.
+#[derive(Debug)]
+pub struct Receiver {
+    rx: mpsc::UnboundedReceiver<Value>,
+}
+
+impl Receiver {
+    /// Receive the next value from the channel.
+    ///
+    /// Returns `None` once the channel is closed and no buffered values
+    /// remain.
+    pub async fn recv(&mut self) -> Option<Value> {
+        self.rx.next().await
+    }
+
+    /// Close the receiving half, causing any further sends to fail.
+    ///
+    /// Values which were already sent can still be received.
+    pub fn close(&mut self) {
+        self.rx.close();
+    }
+}
+
+crate::__internal_impl_external!(Sender);
+crate::__internal_impl_external!(Receiver);
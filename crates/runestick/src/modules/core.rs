@@ -1,6 +1,9 @@
 //! The core `std` module.
 
-use crate::{ContextError, Module, Panic, Stack, Value, VmError};
+use crate::{
+    ContextError, Function, Module, Output, Panic, Shared, Stack, Value, VmError, VmErrorKind,
+};
+use std::cmp::Ordering;
 use std::io;
 use std::io::Write as _;
 
@@ -13,17 +16,60 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty(&["char"]).build::<char>()?;
     module.ty(&["byte"]).build::<u8>()?;
 
+    module.ty(&["Iterable"]).build::<crate::Iterable>()?;
+    module.ty(&["Callable"]).build::<crate::Callable>()?;
+    module.ty(&["Indexable"]).build::<crate::Indexable>()?;
+
     module.function(&["print"], print_impl)?;
     module.function(&["println"], println_impl)?;
+    module.function(&["eprint"], eprint_impl)?;
+    module.function(&["eprintln"], eprintln_impl)?;
     module.function(&["panic"], panic_impl)?;
     module.raw_fn(&["dbg"], dbg_impl)?;
 
     module.function(&["drop"], drop_impl)?;
     module.function(&["is_readable"], is_readable)?;
     module.function(&["is_writable"], is_writable)?;
+
+    module.function(&["some"], some_impl)?;
+    module.function(&["none"], none_impl)?;
+    module.function(&["ok"], ok_impl)?;
+    module.function(&["err"], err_impl)?;
+
+    module.function(&["partial"], partial_impl)?;
+
+    module.function(&["cmp", "min"], min_impl)?;
+    module.function(&["cmp", "max"], max_impl)?;
+    module.function(&["cmp", "clamp"], clamp_impl)?;
     Ok(module)
 }
 
+/// Wrap a value in `Some`.
+fn some_impl(value: Value) -> Option<Value> {
+    Some(value)
+}
+
+/// Construct `None`.
+fn none_impl() -> Option<Value> {
+    None
+}
+
+/// Wrap a value in `Ok`.
+fn ok_impl(value: Value) -> Result<Value, Value> {
+    Ok(value)
+}
+
+/// Wrap a value in `Err`.
+fn err_impl(value: Value) -> Result<Value, Value> {
+    Err(value)
+}
+
+/// Bind `arg` as the first argument of `function`, producing a new function
+/// which takes one less argument.
+fn partial_impl(function: Shared<Function>, arg: Value) -> Function {
+    Function::from_partial(function, vec![arg])
+}
+
 fn drop_impl(value: Value) -> Result<(), VmError> {
     match value {
         Value::Any(any) => {
@@ -62,35 +108,101 @@ fn drop_impl(value: Value) -> Result<(), VmError> {
     Ok::<(), VmError>(())
 }
 
+/// Get the smallest of two values, preferring the first if they are equal.
+fn min_impl(a: Value, b: Value) -> Result<Value, VmError> {
+    Ok(match Value::value_ptr_cmp(&a, &b)? {
+        Ordering::Greater => b,
+        _ => a,
+    })
+}
+
+/// Get the largest of two values, preferring the second if they are equal.
+fn max_impl(a: Value, b: Value) -> Result<Value, VmError> {
+    Ok(match Value::value_ptr_cmp(&a, &b)? {
+        Ordering::Greater => a,
+        _ => b,
+    })
+}
+
+/// Restrict a value to the inclusive range `min..=max`.
+fn clamp_impl(value: Value, min: Value, max: Value) -> Result<Value, VmError> {
+    if Value::value_ptr_cmp(&min, &max)? == Ordering::Greater {
+        return Err(VmError::from(VmErrorKind::BadClampBounds));
+    }
+
+    if Value::value_ptr_cmp(&value, &min)? == Ordering::Less {
+        return Ok(min);
+    }
+
+    if Value::value_ptr_cmp(&value, &max)? == Ordering::Greater {
+        return Ok(max);
+    }
+
+    Ok(value)
+}
+
 fn dbg_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
+    let pretty = Output::current_is_pretty();
 
-    for _ in 0..args {
-        match stack.pop() {
-            Ok(value) => {
-                writeln!(stdout, "{:?}", value).map_err(VmError::panic)?;
-            }
-            Err(e) => {
-                writeln!(stdout, "{}", e).map_err(VmError::panic)?;
+    Output::with_stdout(|stdout| {
+        for _ in 0..args {
+            match stack.pop() {
+                Ok(value) if pretty => {
+                    writeln!(stdout, "{:#?}", value).map_err(VmError::panic)?;
+                }
+                Ok(value) => {
+                    writeln!(stdout, "{:?}", value).map_err(VmError::panic)?;
+                }
+                Err(e) => {
+                    writeln!(stdout, "{}", e).map_err(VmError::panic)?;
+                }
             }
         }
-    }
+
+        Ok::<_, VmError>(())
+    })?;
 
     stack.push(Value::Unit);
     Ok(())
 }
 
-fn print_impl(m: &str) -> Result<(), Panic> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
-    write!(stdout, "{}", m).map_err(Panic::custom)
+fn print_impl(value: Value) -> Result<(), VmError> {
+    Output::with_stdout(|stdout| {
+        write_value(stdout, &value)?;
+        stdout.flush().map_err(VmError::panic)
+    })
+}
+
+fn println_impl(value: Value) -> Result<(), VmError> {
+    Output::with_stdout(|stdout| {
+        write_value(stdout, &value)?;
+        writeln!(stdout).map_err(VmError::panic)?;
+        stdout.flush().map_err(VmError::panic)
+    })
+}
+
+fn eprint_impl(value: Value) -> Result<(), VmError> {
+    Output::with_stderr(|stderr| {
+        write_value(stderr, &value)?;
+        stderr.flush().map_err(VmError::panic)
+    })
+}
+
+fn eprintln_impl(value: Value) -> Result<(), VmError> {
+    Output::with_stderr(|stderr| {
+        write_value(stderr, &value)?;
+        writeln!(stderr).map_err(VmError::panic)?;
+        stderr.flush().map_err(VmError::panic)
+    })
 }
 
-fn println_impl(m: &str) -> Result<(), Panic> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
-    writeln!(stdout, "{}", m).map_err(Panic::custom)
+/// Write the user-facing representation of the given value to the output.
+///
+/// See [Value::string_display].
+fn write_value(out: &mut dyn io::Write, value: &Value) -> Result<(), VmError> {
+    let mut buf = String::new();
+    value.string_display(&mut buf)?;
+    out.write_all(buf.as_bytes()).map_err(VmError::panic)
 }
 
 fn panic_impl(m: &str) -> Result<(), Panic> {
@@ -128,3 +240,24 @@ fn is_writable(value: Value) -> bool {
         _ => true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::write_value;
+    use crate::Value;
+
+    #[test]
+    fn test_print_uses_display() {
+        let mut out = Vec::new();
+        write_value(&mut out, &Value::from(String::from("hi"))).unwrap();
+        write_value(&mut out, &Value::from('!')).unwrap();
+        assert_eq!(out, b"hi!");
+
+        let mut out = Vec::new();
+        write_value(&mut out, &Value::vec(vec![Value::from(String::from("hi"))])).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{:?}", Value::vec(vec![Value::from(String::from("hi"))]))
+        );
+    }
+}
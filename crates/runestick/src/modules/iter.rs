@@ -1,28 +1,66 @@
 //! The `std::iter` module.
 
-use crate::{ContextError, Module};
+use crate::{
+    ContextError, Function, Module, Object, Shared, Tuple, UnsafeFromValue, Value, ValueType,
+    VmError, VmErrorKind,
+};
 
 /// Construct the `std::iter` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "iter"]);
+    module.ty(&["Iterator"]).build::<Iterator>()?;
     module.ty(&["Range"]).build::<Range>()?;
     module.ty(&["Rev"]).build::<Rev>()?;
+    module.ty(&["Peekable"]).build::<Peekable>()?;
     module.function(&["range"], Range::new)?;
     module.inst_fn(crate::INTO_ITER, Range::into_iter)?;
     module.inst_fn(crate::NEXT, Range::next)?;
     module.inst_fn("rev", Range::rev)?;
     module.inst_fn(crate::INTO_ITER, Rev::into_iter)?;
     module.inst_fn(crate::NEXT, Rev::next)?;
+    install_adapters::<Range>(&mut module)?;
+    install_adapters::<Rev>(&mut module)?;
+
+    module.inst_fn("next", Iterator::next)?;
+    module.inst_fn(crate::NEXT, Iterator::next)?;
+    module.inst_fn(crate::INTO_ITER, Iterator::into_iter)?;
+    install_adapters::<Iterator>(&mut module)?;
+
+    module.inst_fn("next", Peekable::next)?;
+    module.inst_fn("peek", Peekable::peek)?;
+    module.inst_fn(crate::NEXT, Peekable::next)?;
+    module.inst_fn(crate::INTO_ITER, Peekable::into_iter)?;
+    install_adapters::<Peekable>(&mut module)?;
     Ok(module)
 }
 
+/// Install the iterator adapters for the native iterator `I`.
+///
+/// Adapters produce the type-erased [Iterator], or a [Peekable] wrapping one,
+/// both of which have the same adapters installed, so adapters can be chained
+/// onto each other in any order.
+pub(crate) fn install_adapters<I>(module: &mut Module) -> Result<(), ContextError>
+where
+    I: 'static + std::iter::Iterator + UnsafeFromValue + ValueType,
+    I::Item: IntoValue,
+{
+    module.inst_fn("map", map::<I>)?;
+    module.inst_fn("flat_map", flat_map::<I>)?;
+    module.inst_fn("peekable", peekable::<I>)?;
+    module.inst_fn("take_while", take_while::<I>)?;
+    module.inst_fn("skip_while", skip_while::<I>)?;
+    module.inst_fn("collect_object", collect_object::<I>)?;
+    module.inst_fn("group_by", group_by::<I>)?;
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Rev {
     current: i64,
     start: i64,
 }
 
-impl Iterator for Rev {
+impl std::iter::Iterator for Rev {
     type Item = i64;
 
     fn next(&mut self) -> Option<i64> {
@@ -57,7 +95,7 @@ impl Range {
     }
 }
 
-impl Iterator for Range {
+impl std::iter::Iterator for Range {
     type Item = i64;
 
     fn next(&mut self) -> Option<i64> {
@@ -72,5 +110,395 @@ impl Iterator for Range {
     }
 }
 
+/// A type-erased iterator over values, which is produced by the iterator
+/// adapters.
+pub struct Iterator {
+    iter: Box<dyn std::iter::Iterator<Item = Result<Value, VmError>>>,
+}
+
+impl Iterator {
+    /// Construct a type-erased iterator out of a native iterator.
+    pub(crate) fn new<I>(iter: I) -> Self
+    where
+        I: 'static + std::iter::Iterator,
+        I::Item: IntoValue,
+    {
+        Self {
+            iter: Box::new(iter.map(IntoValue::into_value)),
+        }
+    }
+
+    fn into_iter(self) -> Self {
+        self
+    }
+
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        self.iter.next().transpose()
+    }
+}
+
+impl std::iter::Iterator for Iterator {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Iterator::next(self).transpose()
+    }
+}
+
+/// Map each element of an iterator to a new value using a function.
+fn map<I>(iter: I, function: Function) -> Iterator
+where
+    I: 'static + std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    Iterator::new(Map {
+        iter: Iterator::new(iter),
+        function,
+    })
+}
+
+/// Map each element of an iterator to an iterable value using a function, and
+/// flatten the result.
+fn flat_map<I>(iter: I, function: Function) -> Iterator
+where
+    I: 'static + std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    Iterator::new(FlatMap {
+        iter: Iterator::new(iter),
+        function,
+        current: None,
+    })
+}
+
+/// Convert an iterator into one which can look at the next element without
+/// consuming it.
+fn peekable<I>(iter: I) -> Peekable
+where
+    I: 'static + std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    Peekable {
+        iter: Iterator::new(iter),
+        peeked: None,
+    }
+}
+
+/// Yield the elements of an iterator as long as a predicate function returns
+/// `true`.
+///
+/// The first element for which the predicate returns `false` is consumed and
+/// discarded, after which no more elements are produced.
+fn take_while<I>(iter: I, function: Function) -> Iterator
+where
+    I: 'static + std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    Iterator::new(TakeWhile {
+        iter: Iterator::new(iter),
+        function,
+        done: false,
+    })
+}
+
+/// Skip the elements of an iterator as long as a predicate function returns
+/// `true`, and yield every element after that.
+fn skip_while<I>(iter: I, function: Function) -> Iterator
+where
+    I: 'static + std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    Iterator::new(SkipWhile {
+        iter: Iterator::new(iter),
+        function,
+        skipping: true,
+    })
+}
+
+/// An iterator which can look at the next element without consuming it
+/// through `peek`.
+pub struct Peekable {
+    iter: Iterator,
+    peeked: Option<Option<Value>>,
+}
+
+impl Peekable {
+    fn into_iter(self) -> Self {
+        self
+    }
+
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        match self.peeked.take() {
+            Some(value) => Ok(value),
+            None => self.iter.next(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<Value>, VmError> {
+        if let Some(value) = &self.peeked {
+            return Ok(value.clone());
+        }
+
+        let value = self.iter.next()?;
+        self.peeked = Some(value.clone());
+        Ok(value)
+    }
+}
+
+impl std::iter::Iterator for Peekable {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Peekable::next(self).transpose()
+    }
+}
+
+struct Map {
+    iter: Iterator,
+    function: Function,
+}
+
+impl Map {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        let value = match self.iter.next()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        Ok(Some(self.function.call::<_, Value>((value,))?))
+    }
+}
+
+impl std::iter::Iterator for Map {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Map::next(self).transpose()
+    }
+}
+
+struct FlatMap {
+    iter: Iterator,
+    function: Function,
+    current: Option<std::vec::IntoIter<Value>>,
+}
+
+impl FlatMap {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        loop {
+            if let Some(value) = self.current.as_mut().and_then(std::iter::Iterator::next) {
+                return Ok(Some(value));
+            }
+
+            let value = match self.iter.next()? {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            let mapped = self.function.call::<_, Value>((value,))?;
+            self.current = Some(iterable_values(mapped)?.into_iter());
+        }
+    }
+}
+
+impl std::iter::Iterator for FlatMap {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        FlatMap::next(self).transpose()
+    }
+}
+
+struct TakeWhile {
+    iter: Iterator,
+    function: Function,
+    done: bool,
+}
+
+impl TakeWhile {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let value = match self.iter.next()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        if self.function.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(value));
+        }
+
+        self.done = true;
+        Ok(None)
+    }
+}
+
+impl std::iter::Iterator for TakeWhile {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        TakeWhile::next(self).transpose()
+    }
+}
+
+struct SkipWhile {
+    iter: Iterator,
+    function: Function,
+    skipping: bool,
+}
+
+impl SkipWhile {
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        while let Some(value) = self.iter.next()? {
+            if self.skipping && self.function.call::<_, bool>((value.clone(),))? {
+                continue;
+            }
+
+            self.skipping = false;
+            return Ok(Some(value));
+        }
+
+        Ok(None)
+    }
+}
+
+impl std::iter::Iterator for SkipWhile {
+    type Item = Result<Value, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        SkipWhile::next(self).transpose()
+    }
+}
+
+/// Helper to convert the items produced by native iterators into values.
+pub(crate) trait IntoValue {
+    fn into_value(self) -> Result<Value, VmError>;
+}
+
+impl IntoValue for i64 {
+    fn into_value(self) -> Result<Value, VmError> {
+        Ok(Value::Integer(self))
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Result<Value, VmError> {
+        Ok(self)
+    }
+}
+
+impl IntoValue for Result<Value, VmError> {
+    fn into_value(self) -> Result<Value, VmError> {
+        self
+    }
+}
+
+impl IntoValue for (String, Value) {
+    fn into_value(self) -> Result<Value, VmError> {
+        let (key, value) = self;
+        Ok(Value::tuple(vec![Value::String(Shared::new(key)), value]))
+    }
+}
+
+/// Collect an iterator of `(key, value)` tuples into an object.
+///
+/// If a key occurs more than once, the last value is kept.
+pub(crate) fn collect_object<I>(iter: I) -> Result<Object, VmError>
+where
+    I: std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    let mut object = Object::new();
+
+    for entry in iter {
+        let (key, value) = match entry.into_value()? {
+            Value::Tuple(tuple) => {
+                let tuple = tuple.borrow_ref()?;
+
+                match &tuple[..] {
+                    [key, value] => (key.clone(), value.clone()),
+                    _ => {
+                        return Err(VmError::from(VmErrorKind::ExpectedTupleLength {
+                            actual: tuple.len(),
+                            expected: 2,
+                        }))
+                    }
+                }
+            }
+            actual => return Err(VmError::expected::<Tuple>(actual.type_info()?)),
+        };
+
+        let key = match key {
+            Value::String(key) => key.borrow_ref()?.clone(),
+            Value::StaticString(key) => key.as_str().to_owned(),
+            actual => return Err(VmError::expected::<String>(actual.type_info()?)),
+        };
+
+        object.insert(key, value);
+    }
+
+    Ok(object)
+}
+
+/// Group the elements of an iterator into an object, mapping the key produced
+/// by calling `function` on each element to a vector of the elements which
+/// produced it.
+///
+/// Keys must be strings, or values which can be converted into strings like
+/// integers, characters, and booleans.
+pub(crate) fn group_by<I>(iter: I, function: Function) -> Result<Object, VmError>
+where
+    I: std::iter::Iterator,
+    I::Item: IntoValue,
+{
+    let mut object = Object::new();
+
+    for value in iter {
+        let value = value.into_value()?;
+        let key = group_key(function.call::<_, Value>((value.clone(),))?)?;
+
+        match object.get_mut(&key) {
+            Some(Value::Vec(group)) => group.borrow_mut()?.push(value),
+            _ => {
+                object.insert(key, Value::vec(vec![value]));
+            }
+        }
+    }
+
+    Ok(object)
+}
+
+/// Convert a value into a key suitable for grouping.
+fn group_key(value: Value) -> Result<String, VmError> {
+    Ok(match value {
+        Value::String(string) => string.borrow_ref()?.clone(),
+        Value::StaticString(string) => string.as_str().to_owned(),
+        Value::Integer(integer) => integer.to_string(),
+        Value::Char(c) => c.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Byte(b) => b.to_string(),
+        actual => return Err(VmError::expected::<String>(actual.type_info()?)),
+    })
+}
+
+/// Collect the values of something which can be natively iterated over,
+/// like a vector, a tuple, or an option.
+pub(crate) fn iterable_values(value: Value) -> Result<Vec<Value>, VmError> {
+    Ok(match value {
+        Value::Vec(vec) => vec.borrow_ref()?.clone(),
+        Value::Tuple(tuple) => tuple.borrow_ref()?.to_vec(),
+        Value::Option(option) => option.borrow_ref()?.iter().cloned().collect(),
+        actual => {
+            return Err(VmError::from(VmErrorKind::UnsupportedIterable {
+                actual: actual.type_info()?,
+            }))
+        }
+    })
+}
+
 crate::__internal_impl_external!(Range);
 crate::__internal_impl_external!(Rev);
+crate::__internal_impl_external!(Iterator);
+crate::__internal_impl_external!(Peekable);
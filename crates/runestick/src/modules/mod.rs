@@ -2,6 +2,7 @@
 //! machines.
 
 pub mod bytes;
+pub mod channel;
 pub mod core;
 pub mod float;
 pub mod fmt;
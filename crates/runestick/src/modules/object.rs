@@ -1,5 +1,6 @@
 //! The `std::object` module.
 
+use crate::modules::iter::install_adapters;
 use crate::{ContextError, Module, Object, Value};
 use std::iter::Rev;
 
@@ -11,22 +12,29 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty(&["Iter"]).build::<Iter>()?;
     module.ty(&["Rev"]).build::<Rev<Iter>>()?;
 
+    module.inst_fn("iter", object_iter)?;
     module.inst_fn("len", Object::len)?;
     module.inst_fn("insert", Object::insert)?;
     module.inst_fn("clear", Object::clear)?;
     module.inst_fn("contains_key", contains_key)?;
     module.inst_fn("get", get)?;
+    module.inst_fn("remove", remove)?;
+    module.inst_fn("keys", keys)?;
+    module.inst_fn("values", values)?;
 
     module.inst_fn(crate::INTO_ITER, object_iter)?;
     module.inst_fn("next", Iter::next)?;
     module.inst_fn(crate::NEXT, Iter::next)?;
     module.inst_fn(crate::INTO_ITER, Iter::into_iter)?;
 
+    install_adapters::<Iter>(&mut module)?;
+
     module.inst_fn("rev", Iter::rev)?;
     module.inst_fn("next", Rev::<Iter>::next)?;
     module.inst_fn("next_back", Rev::<Iter>::next_back)?;
     module.inst_fn(crate::NEXT, Rev::<Iter>::next)?;
     module.inst_fn(crate::INTO_ITER, Rev::<Iter>::into_iter)?;
+    install_adapters::<Rev<Iter>>(&mut module)?;
 
     Ok(module)
 }
@@ -68,5 +76,17 @@ fn get(object: &Object, key: &str) -> Option<Value> {
     object.get(key).cloned()
 }
 
+fn remove(object: &mut Object, key: &str) -> Option<Value> {
+    object.remove(key)
+}
+
+fn keys(object: &Object) -> Vec<String> {
+    object.keys().cloned().collect()
+}
+
+fn values(object: &Object) -> Vec<Value> {
+    object.values().cloned().collect()
+}
+
 crate::__internal_impl_external!(Iter);
 crate::__internal_impl_external!(Rev<Iter>);
@@ -8,6 +8,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty(&["Stream"]).build::<Stream>()?;
 
     module.async_inst_fn("next", Stream::next)?;
+    module.async_inst_fn(crate::NEXT, Stream::next)?;
     module.async_inst_fn("resume", Stream::resume)?;
     Ok(module)
 }
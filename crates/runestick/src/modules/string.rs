@@ -1,36 +1,117 @@
 //! The `std::string` module.
 
-use crate::{Bytes, ContextError, Module};
+use crate::{Bytes, ContextError, Memory, Module, VmError, VmErrorKind};
+use std::string::FromUtf8Error;
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "string"]);
 
     module.ty(&["String"]).build::<String>()?;
+    module.ty(&["FromUtf8Error"]).build::<FromUtf8Error>()?;
+    module.ty(&["StringBuilder"]).build::<StringBuilder>()?;
 
     module.function(&["String", "from_str"], <String as From<&str>>::from)?;
     module.function(&["String", "new"], String::new)?;
-    module.function(&["String", "with_capacity"], String::with_capacity)?;
+    module.function(&["String", "with_capacity"], string_with_capacity)?;
 
     module.inst_fn("len", String::len)?;
     module.inst_fn("capacity", String::capacity)?;
     module.inst_fn("clear", String::clear)?;
     module.inst_fn("push", String::push)?;
     module.inst_fn("push_str", String::push_str)?;
-    module.inst_fn("reserve", String::reserve)?;
-    module.inst_fn("reserve_exact", String::reserve_exact)?;
+    module.inst_fn("reserve", reserve)?;
+    module.inst_fn("reserve_exact", reserve_exact)?;
     module.inst_fn("into_bytes", into_bytes)?;
     module.inst_fn("clone", String::clone)?;
     module.inst_fn("shrink_to_fit", String::shrink_to_fit)?;
     module.inst_fn("char_at", char_at)?;
+    module.inst_fn("repeat", repeat)?;
+    module.inst_fn("find", find)?;
+    module.inst_fn("rfind", rfind)?;
     module.inst_fn(crate::ADD, add)?;
     module.inst_fn(crate::ADD_ASSIGN, String::push_str)?;
+
+    module.function(&["StringBuilder", "new"], StringBuilder::new)?;
+    module.function(
+        &["StringBuilder", "with_capacity"],
+        StringBuilder::with_capacity,
+    )?;
+    module.inst_fn("len", StringBuilder::len)?;
+    module.inst_fn("push", StringBuilder::push)?;
+    module.inst_fn("push_str", StringBuilder::push_str)?;
+    module.inst_fn("build", StringBuilder::build)?;
     Ok(module)
 }
 
+/// A builder which constructs a string by appending to it in place.
+///
+/// Unlike repeatedly concatenating strings with `+`, which allocates a new
+/// string for every operation, this takes linear time in the length of the
+/// constructed string.
+#[derive(Debug, Default)]
+pub struct StringBuilder {
+    buf: String,
+}
+
+impl StringBuilder {
+    /// Construct a new, empty string builder.
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new string builder with the given capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    /// Get the length in bytes of the string built so far.
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Append a character.
+    fn push(&mut self, c: char) {
+        self.buf.push(c);
+    }
+
+    /// Append a string.
+    fn push_str(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    /// Consume the builder and return the constructed string.
+    fn build(self) -> String {
+        self.buf
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct NotCharBoundary(());
 
+/// Construct a string with the given capacity, checking it against the
+/// memory limit before allocating.
+fn string_with_capacity(capacity: usize) -> Result<String, VmError> {
+    Memory::reserve_current(capacity)?;
+    Ok(String::with_capacity(capacity))
+}
+
+/// Reserve capacity for at least `additional` more bytes.
+fn reserve(s: &mut String, additional: usize) -> Result<(), VmError> {
+    Memory::reserve_current(additional)?;
+    s.reserve(additional);
+    Ok(())
+}
+
+/// Reserve capacity for exactly `additional` more bytes.
+fn reserve_exact(s: &mut String, additional: usize) -> Result<(), VmError> {
+    Memory::reserve_current(additional)?;
+    s.reserve_exact(additional);
+    Ok(())
+}
+
 /// into_bytes shim for strings.
 fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
@@ -44,6 +125,30 @@ fn char_at(s: &str, index: usize) -> Result<Option<char>, NotCharBoundary> {
     Ok(s[index..].chars().next())
 }
 
+/// Repeat a string `count` times.
+///
+/// Errors instead of panicking if the length of the repeated string overflows
+/// the maximum size of an allocation.
+fn repeat(s: &str, count: usize) -> Result<String, VmError> {
+    match s.len().checked_mul(count) {
+        Some(len) if len <= isize::MAX as usize => {
+            Memory::reserve_current(len)?;
+            Ok(s.repeat(count))
+        }
+        _ => Err(VmError::from(VmErrorKind::Overflow)),
+    }
+}
+
+/// Find the byte index of the first occurrence of `needle`.
+fn find(s: &str, needle: &str) -> Option<usize> {
+    s.find(needle)
+}
+
+/// Find the byte index of the last occurrence of `needle`.
+fn rfind(s: &str, needle: &str) -> Option<usize> {
+    s.rfind(needle)
+}
+
 /// The add operation for strings.
 fn add(a: &str, b: &str) -> String {
     let mut string = String::with_capacity(a.len() + b.len());
@@ -53,3 +158,5 @@ fn add(a: &str, b: &str) -> String {
 }
 
 crate::__internal_impl_external!(NotCharBoundary);
+crate::__internal_impl_external!(FromUtf8Error);
+crate::__internal_impl_external!(StringBuilder);
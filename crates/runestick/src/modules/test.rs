@@ -1,12 +1,14 @@
 //! The `std::test` module.
 
-use crate::{ContextError, Module, Panic};
+use crate::{ContextError, Module, Panic, Value, VmError};
 use std::fmt;
 
 /// Construct the `std::test` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "test"]);
     module.function(&["assert"], assert)?;
+    module.function(&["assert_eq"], assert_eq)?;
+    module.function(&["black_box"], black_box)?;
     Ok(module)
 }
 
@@ -19,6 +21,22 @@ impl fmt::Display for AssertionFailed {
     }
 }
 
+#[derive(Debug)]
+struct AssertionNotEqual {
+    left: String,
+    right: String,
+}
+
+impl fmt::Display for AssertionNotEqual {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "assertion failed `left == right` (left: {}, right: {})",
+            self.left, self.right
+        )
+    }
+}
+
 /// Assert that a value is true.
 fn assert(value: bool, message: &str) -> Result<(), Panic> {
     if !value {
@@ -27,3 +45,25 @@ fn assert(value: bool, message: &str) -> Result<(), Panic> {
 
     Ok(())
 }
+
+/// Assert that two values are equal.
+///
+/// On failure the panic reports both of the compared values.
+fn assert_eq(left: Value, right: Value) -> Result<(), VmError> {
+    if !Value::value_ptr_eq(&left, &right)? {
+        return Err(VmError::panic(AssertionNotEqual {
+            left: format!("{:?}", left),
+            right: format!("{:?}", right),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Return the given value unchanged.
+///
+/// Since this is a native function, the compiler can't see through it. So it
+/// can be used in benchmarks to make sure that a computation isn't elided.
+fn black_box(value: Value) -> Value {
+    value
+}
@@ -1,6 +1,8 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Value};
+use crate::modules::iter::{install_adapters, iterable_values};
+use crate::{ContextError, Memory, Module, Value, VmError};
+use std::cmp::Ordering;
 use std::iter::Rev;
 
 /// Construct the `std::vec` module.
@@ -12,22 +14,31 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty(&["Rev"]).build::<Rev<Iter>>()?;
 
     module.function(&["Vec", "new"], Vec::<Value>::new)?;
+    module.function(&["Vec", "with_capacity"], vec_with_capacity)?;
     module.inst_fn("iter", vec_iter)?;
     module.inst_fn("len", Vec::<Value>::len)?;
+    module.inst_fn("get", vec_get)?;
     module.inst_fn("push", Vec::<Value>::push)?;
+    module.inst_fn("extend", vec_extend)?;
     module.inst_fn("clear", Vec::<Value>::clear)?;
     module.inst_fn("pop", Vec::<Value>::pop)?;
+    module.inst_fn("join", vec_join)?;
+    module.inst_fn("binary_search", vec_binary_search)?;
+    module.inst_fn("flatten", vec_flatten)?;
 
     module.inst_fn(crate::INTO_ITER, vec_iter)?;
     module.inst_fn("next", Iter::next)?;
     module.inst_fn(crate::NEXT, Iter::next)?;
     module.inst_fn(crate::INTO_ITER, Iter::into_iter)?;
 
+    install_adapters::<Iter>(&mut module)?;
+
     module.inst_fn("rev", Iter::rev)?;
     module.inst_fn("next", Rev::<Iter>::next)?;
     module.inst_fn("next_back", Rev::<Iter>::next_back)?;
     module.inst_fn(crate::NEXT, Rev::<Iter>::next)?;
     module.inst_fn(crate::INTO_ITER, Rev::<Iter>::into_iter)?;
+    install_adapters::<Rev<Iter>>(&mut module)?;
     Ok(module)
 }
 
@@ -50,11 +61,94 @@ impl DoubleEndedIterator for Iter {
     }
 }
 
+/// Construct a vector with the given capacity, checking it against the memory
+/// limit before allocating.
+fn vec_with_capacity(capacity: usize) -> Result<Vec<Value>, VmError> {
+    Memory::reserve_current(capacity.saturating_mul(std::mem::size_of::<Value>()))?;
+    Ok(Vec::with_capacity(capacity))
+}
+
 fn vec_iter(vec: &[Value]) -> Iter {
     Iter {
         iter: vec.to_vec().into_iter(),
     }
 }
 
+/// Get the value at the given index without trapping, unlike indexing with
+/// `vec[index]`. Indices which are out of bounds, including negative ones,
+/// result in `None`.
+fn vec_get(vec: &[Value], index: i64) -> Option<Value> {
+    if index < 0 {
+        return None;
+    }
+
+    vec.get(index as usize).cloned()
+}
+
+/// Join a vector of strings together with the given separator.
+fn vec_join(vec: &[Value], sep: &str) -> Result<String, VmError> {
+    let mut string = String::new();
+
+    for (index, value) in vec.iter().enumerate() {
+        if index > 0 {
+            string.push_str(sep);
+        }
+
+        match value {
+            Value::String(s) => string.push_str(&s.borrow_ref()?),
+            Value::StaticString(s) => string.push_str(s.as_ref()),
+            actual => return Err(VmError::expected::<String>(actual.type_info()?)),
+        }
+    }
+
+    Ok(string)
+}
+
+/// Append all values of an iterable value, like a vector or a tuple, to the
+/// end of the vector in place.
+fn vec_extend(vec: &mut Vec<Value>, value: Value) -> Result<(), VmError> {
+    vec.extend(iterable_values(value)?);
+    Ok(())
+}
+
+/// Flatten a vector of iterable values, like vectors or tuples, into a single
+/// vector.
+fn vec_flatten(vec: &[Value]) -> Result<Vec<Value>, VmError> {
+    let mut output = Vec::new();
+
+    for value in vec {
+        output.extend(iterable_values(value.clone())?);
+    }
+
+    Ok(output)
+}
+
+/// Binary search a sorted vector for the given value.
+///
+/// Returns `Ok` with the index of a matching element if found, otherwise `Err`
+/// with the index where the value could be inserted to keep the vector sorted.
+/// If there are multiple matches, any one of them may be returned.
+///
+/// Values are compared using their canonical ordering, so the vector must be
+/// sorted in that same ordering. Searching an unsorted vector is the
+/// responsibility of the caller, and produces an unspecified result.
+fn vec_binary_search(vec: &[Value], value: Value) -> Result<Result<usize, usize>, VmError> {
+    let mut error = None;
+
+    let result = vec.binary_search_by(|probe| match Value::value_ptr_cmp(probe, &value) {
+        Ok(ordering) => ordering,
+        Err(e) => {
+            error.get_or_insert(e);
+            Ordering::Equal
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(result)
+}
+
 crate::__internal_impl_external!(Iter);
 crate::__internal_impl_external!(Rev<Iter>);
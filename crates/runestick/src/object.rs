@@ -2,6 +2,7 @@ use crate::collections::HashMap;
 use crate::{
     FromValue, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, UnsafeFromValue, Value, VmError,
 };
+use indexmap::IndexMap;
 use std::borrow;
 use std::cmp;
 use std::fmt;
@@ -14,7 +15,7 @@ use std::hash;
 ///
 /// [`into_iter`]: struct.Object.html#method.into_iter
 /// [`Object`]: struct.Object.html
-pub type IntoIter = crate::collections::hash_map::IntoIter<String, Value>;
+pub type IntoIter = indexmap::map::IntoIter<String, Value>;
 
 /// A mutable iterator over the entries of a `Object`.
 ///
@@ -23,7 +24,7 @@ pub type IntoIter = crate::collections::hash_map::IntoIter<String, Value>;
 ///
 /// [`iter_mut`]: struct.Object.html#method.iter_mut
 /// [`Object`]: struct.Object.html
-pub type IterMut<'a> = crate::collections::hash_map::IterMut<'a, String, Value>;
+pub type IterMut<'a> = indexmap::map::IterMut<'a, String, Value>;
 
 /// An iterator over the entries of a `Object`.
 ///
@@ -32,20 +33,23 @@ pub type IterMut<'a> = crate::collections::hash_map::IterMut<'a, String, Value>;
 ///
 /// [`iter`]: struct.Object.html#method.iter
 /// [`Object`]: struct.Object.html
-pub type Iter<'a> = crate::collections::hash_map::Iter<'a, String, Value>;
+pub type Iter<'a> = indexmap::map::Iter<'a, String, Value>;
 
 /// Struct representing a dynamic anonymous object.
+///
+/// Entries are kept in the order in which they were inserted, so iterating
+/// over an object is deterministic.
 #[derive(Default, Clone)]
 #[repr(transparent)]
 pub struct Object {
-    inner: HashMap<String, Value>,
+    inner: IndexMap<String, Value>,
 }
 
 impl Object {
     /// Construct a new object.
     pub fn new() -> Self {
         Self {
-            inner: HashMap::new(),
+            inner: IndexMap::new(),
         }
     }
 
@@ -69,6 +73,18 @@ impl Object {
         self.inner.get(k)
     }
 
+    /// Returns the offset of the given key and a reference to the value
+    /// corresponding to it.
+    pub(crate) fn get_full(&self, k: &str) -> Option<(usize, &Value)> {
+        let (offset, _, value) = self.inner.get_full(k)?;
+        Some((offset, value))
+    }
+
+    /// Returns the key and value stored at the given offset.
+    pub(crate) fn get_index(&self, offset: usize) -> Option<(&String, &Value)> {
+        self.inner.get_index(offset)
+    }
+
     /// Returns a mutable reference to the value corresponding to the key.
     pub fn get_mut<Q: ?Sized>(&mut self, k: &Q) -> Option<&mut Value>
     where
@@ -89,15 +105,20 @@ impl Object {
 
     /// Removes a key from the object, returning the value at the key if the key
     /// was previously in the object.
+    ///
+    /// The order of the remaining entries is preserved.
     pub fn remove<Q: ?Sized>(&mut self, k: &Q) -> Option<Value>
     where
         String: borrow::Borrow<Q>,
         Q: hash::Hash + cmp::Eq,
     {
-        self.inner.remove(k)
+        self.inner.shift_remove(k)
     }
 
     /// Inserts a key-value pair into the object.
+    ///
+    /// New keys are added after all existing ones, while updating an existing
+    /// key retains its position.
     pub fn insert(&mut self, k: String, v: Value) -> Option<Value> {
         self.inner.insert(k, v)
     }
@@ -108,25 +129,50 @@ impl Object {
         self.inner.clear();
     }
 
+    /// Returns the number of elements the object can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
     /// Construct a new object with the given capacity.
     pub fn with_capacity(cap: usize) -> Self {
         Self {
-            inner: HashMap::with_capacity(cap),
+            inner: IndexMap::with_capacity(cap),
         }
     }
 
     /// Convert into inner.
+    ///
+    /// This doesn't preserve the insertion order of the object, use
+    /// `into_index_map` for that.
     pub fn into_inner(self) -> HashMap<String, Value> {
+        self.inner.into_iter().collect()
+    }
+
+    /// Convert into an index map, preserving the insertion order of the
+    /// object.
+    pub fn into_index_map(self) -> IndexMap<String, Value> {
         self.inner
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// An iterator visiting all keys in insertion order.
+    pub fn keys(&self) -> indexmap::map::Keys<'_, String, Value> {
+        self.inner.keys()
+    }
+
+    /// An iterator visiting all values in insertion order.
+    pub fn values(&self) -> indexmap::map::Values<'_, String, Value> {
+        self.inner.values()
+    }
+
+    /// An iterator visiting all key-value pairs in insertion order.
     /// The iterator element type is `(&'a String, &'a Value)`.
     pub fn iter(&self) -> Iter<'_> {
         self.inner.iter()
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order,
+    /// An iterator visiting all key-value pairs in insertion order,
     /// with mutable references to the values.
     /// The iterator element type is `(&'a String, &'a mut Value)`.
     pub fn iter_mut(&mut self) -> IterMut<'_> {
@@ -157,7 +203,7 @@ impl IntoIterator for Object {
     type IntoIter = IntoIter;
 
     /// Creates a consuming iterator, that is, one that moves each key-value
-    /// pair out of the object in arbitrary order. The object cannot be used
+    /// pair out of the object in insertion order. The object cannot be used
     /// after calling this.
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -172,6 +218,14 @@ impl fmt::Debug for Object {
 
 impl From<HashMap<String, Value>> for Object {
     fn from(object: HashMap<String, Value>) -> Self {
+        Self {
+            inner: object.into_iter().collect(),
+        }
+    }
+}
+
+impl From<IndexMap<String, Value>> for Object {
+    fn from(object: IndexMap<String, Value>) -> Self {
         Self { inner: object }
     }
 }
@@ -0,0 +1,184 @@
+//! Configurable output streams for a virtual machine.
+//!
+//! When an [Output] is associated with a [Vm][crate::Vm], functions like
+//! `print`, `println` and `dbg` write to its stdout sink, and `eprint` and
+//! `eprintln` write to its stderr sink, instead of the process streams.
+//!
+//! An output can also be configured to have `dbg` pretty-print values over
+//! multiple lines, like `{:#?}` does.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io;
+use std::rc::Rc;
+
+thread_local! {
+    /// The output installed for the currently running virtual machine, if
+    /// any.
+    static CURRENT: RefCell<Option<Output>> = RefCell::new(None);
+}
+
+/// Output sinks used by a virtual machine.
+///
+/// This is cheap to clone, all clones write to the same sinks.
+#[derive(Clone)]
+pub struct Output {
+    stdout: Rc<RefCell<Box<dyn io::Write>>>,
+    stderr: Rc<RefCell<Box<dyn io::Write>>>,
+    pretty: bool,
+}
+
+impl Output {
+    /// Construct output which writes to the given stdout and stderr sinks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Output, OutputBuffer};
+    ///
+    /// let stdout = OutputBuffer::new();
+    /// let output = Output::new(stdout.clone(), std::io::sink());
+    /// ```
+    pub fn new<O, E>(stdout: O, stderr: E) -> Self
+    where
+        O: 'static + io::Write,
+        E: 'static + io::Write,
+    {
+        Self {
+            stdout: Rc::new(RefCell::new(Box::new(stdout))),
+            stderr: Rc::new(RefCell::new(Box::new(stderr))),
+            pretty: false,
+        }
+    }
+
+    /// Construct output which writes to the process stdout and stderr.
+    pub fn process() -> Self {
+        Self::new(io::stdout(), io::stderr())
+    }
+
+    /// Configure whether `dbg` should pretty-print values over multiple lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Output;
+    ///
+    /// let output = Output::process().with_pretty(true);
+    /// assert!(output.is_pretty());
+    /// ```
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Test if `dbg` pretty-prints values with this output.
+    pub fn is_pretty(&self) -> bool {
+        self.pretty
+    }
+
+    /// Test if the output of the currently running virtual machine, if any,
+    /// pretty-prints values.
+    pub(crate) fn current_is_pretty() -> bool {
+        CURRENT.with(|current| matches!(&*current.borrow(), Some(output) if output.pretty))
+    }
+
+    /// Write to the stdout sink of the currently running virtual machine, or
+    /// the process stdout if none is installed.
+    pub(crate) fn with_stdout<T>(f: impl FnOnce(&mut dyn io::Write) -> T) -> T {
+        match Self::current() {
+            Some(output) => f(&mut **output.stdout.borrow_mut()),
+            None => f(&mut io::stdout().lock()),
+        }
+    }
+
+    /// Write to the stderr sink of the currently running virtual machine, or
+    /// the process stderr if none is installed.
+    pub(crate) fn with_stderr<T>(f: impl FnOnce(&mut dyn io::Write) -> T) -> T {
+        match Self::current() {
+            Some(output) => f(&mut **output.stderr.borrow_mut()),
+            None => f(&mut io::stderr().lock()),
+        }
+    }
+
+    /// Get the output installed for the currently running virtual machine,
+    /// if any.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Install this output for the current thread until the returned guard
+    /// is dropped.
+    pub(crate) fn install(&self) -> OutputGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        OutputGuard { previous }
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::process()
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Output")
+            .field("pretty", &self.pretty)
+            .finish()
+    }
+}
+
+/// Guard which restores the previously installed output when dropped.
+pub(crate) struct OutputGuard {
+    previous: Option<Output>,
+}
+
+impl Drop for OutputGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// An in-memory sink which can be used to capture output.
+///
+/// This is cheap to clone, all clones refer to the same buffer.
+#[derive(Debug, Default, Clone)]
+pub struct OutputBuffer {
+    buf: Rc<RefCell<Vec<u8>>>,
+}
+
+impl OutputBuffer {
+    /// Construct a new, empty output buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the output captured so far, leaving the buffer empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::OutputBuffer;
+    /// use std::io::Write as _;
+    ///
+    /// let mut buffer = OutputBuffer::new();
+    /// write!(buffer, "hello").unwrap();
+    /// assert_eq!(buffer.take(), b"hello");
+    /// assert!(buffer.take().is_empty());
+    /// ```
+    pub fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.buf.borrow_mut())
+    }
+}
+
+impl io::Write for OutputBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
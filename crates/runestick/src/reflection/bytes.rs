@@ -5,7 +5,8 @@ use crate::{
 impl FromValue for Bytes {
     fn from_value(value: Value) -> Result<Self, VmError> {
         let bytes = value.into_bytes()?;
-        Ok(bytes.borrow_ref()?.clone())
+        let bytes = bytes.borrow_ref()?.clone();
+        Ok(bytes)
     }
 }
 
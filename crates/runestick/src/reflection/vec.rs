@@ -1,6 +1,6 @@
 use crate::{
     FromValue, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared, ToValue, UnsafeFromValue,
-    Value, VmError,
+    Value, VmError, VmErrorKind,
 };
 
 impl FromValue for OwnedMut<Vec<Value>> {
@@ -25,8 +25,11 @@ where
 
         let mut output = Vec::with_capacity(vec.len());
 
-        for value in vec {
-            output.push(T::from_value(value)?);
+        for (index, value) in vec.into_iter().enumerate() {
+            let value = T::from_value(value)
+                .map_err(|error| VmError::from(VmErrorKind::BadVecElement { index, error }))?;
+
+            output.push(value);
         }
 
         Ok(output)
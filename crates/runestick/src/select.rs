@@ -10,26 +10,90 @@ use std::task::{Context, Poll};
 #[derive(Debug)]
 pub struct Select {
     futures: FuturesUnordered<SelectFuture<usize, OwnedMut<Future>>>,
+    default: bool,
 }
 
 impl Select {
     /// Construct a new stored select.
-    pub(crate) fn new(futures: FuturesUnordered<SelectFuture<usize, OwnedMut<Future>>>) -> Self {
-        Self { futures }
+    ///
+    /// If the select has a `default` branch, it resolves to `None` instead of
+    /// waiting if none of the futures are ready.
+    pub(crate) fn new(
+        futures: FuturesUnordered<SelectFuture<usize, OwnedMut<Future>>>,
+        default: bool,
+    ) -> Self {
+        Self { futures, default }
+    }
+
+    /// Iterate over the branches which have not yet completed.
+    ///
+    /// After the select has resolved, these are the branches which were still
+    /// pending.
+    pub fn remaining(&self) -> impl Iterator<Item = usize> + '_ {
+        self.futures.iter().map(|future| *future.data())
+    }
+
+    /// Convert the select into the futures which have not yet completed,
+    /// together with the branch they belong to.
+    ///
+    /// This allows in-flight futures to be reused after the select has
+    /// resolved, instead of being dropped.
+    pub fn into_remaining(self) -> Vec<(usize, OwnedMut<Future>)> {
+        self.futures
+            .into_iter()
+            .map(SelectFuture::into_inner)
+            .collect()
     }
 }
 
 impl future::Future for Select {
-    type Output = Result<(usize, Value), VmError>;
+    type Output = Result<Option<(usize, Value)>, VmError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let poll = Pin::new(&mut self.futures).poll_next(cx);
 
         let poll = match poll {
             Poll::Ready(poll) => poll.expect("inner stream should never end"),
+            Poll::Pending if self.default => return Poll::Ready(Ok(None)),
             Poll::Pending => return Poll::Pending,
         };
 
-        Poll::Ready(poll)
+        Poll::Ready(poll.map(Some))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Select;
+    use crate::future::SelectFuture;
+    use crate::{Future, Shared, VmError};
+    use futures::stream::FuturesUnordered;
+
+    #[test]
+    fn test_into_remaining() {
+        let futures = FuturesUnordered::new();
+
+        let pending = Shared::new(Future::new(
+            futures::future::pending::<Result<i64, VmError>>(),
+        ));
+        let ready = Shared::new(Future::new(async { Ok::<_, VmError>(42i64) }));
+
+        futures.push(SelectFuture::new(0, pending.clone().owned_mut().unwrap()));
+        futures.push(SelectFuture::new(1, ready.owned_mut().unwrap()));
+
+        let mut select = Select::new(futures, false);
+        let (branch, value) = futures::executor::block_on(&mut select).unwrap().unwrap();
+        assert_eq!(branch, 1);
+        assert_eq!(value.into_integer().unwrap(), 42);
+        assert_eq!(select.remaining().collect::<Vec<_>>(), vec![0]);
+
+        let remaining = select.into_remaining();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].0, 0);
+        assert!(!remaining[0].1.is_completed());
+
+        // NB: the pending future is accessible once the select is dropped.
+        drop(remaining);
+        assert!(pending.borrow_mut().is_ok());
     }
 }
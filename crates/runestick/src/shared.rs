@@ -1,11 +1,12 @@
 use crate::access::{Access, AccessError, BorrowMut, BorrowRef, RawBorrowedMut, RawBorrowedRef};
+use crate::memory::{Memory, MemoryCharge};
 use crate::{Any, Hash};
 use std::any;
 use std::cell::{Cell, UnsafeCell};
 use std::fmt;
 use std::future::Future;
 use std::marker;
-use std::mem::ManuallyDrop;
+use std::mem::{self, ManuallyDrop};
 use std::ops;
 use std::pin::Pin;
 use std::process;
@@ -19,10 +20,16 @@ pub struct Shared<T: ?Sized> {
 
 impl<T> Shared<T> {
     /// Construct a new shared value.
+    ///
+    /// If a virtual machine with a memory limit is currently running, the
+    /// allocation is charged against that limit.
     pub fn new(data: T) -> Self {
+        let memory = Memory::charge_current(mem::size_of::<SharedBox<T>>());
+
         let inner = Box::leak(Box::new(SharedBox {
             access: Access::new(),
             count: Cell::new(1),
+            memory,
             data: data.into(),
         }));
 
@@ -31,6 +38,20 @@ impl<T> Shared<T> {
         }
     }
 
+    /// Charge `heap` bytes owned by the shared value, like the buffer of a
+    /// vector, in addition to its own allocation.
+    ///
+    /// This replaces any previous heap charge, and does nothing if the value
+    /// wasn't allocated under a memory limit.
+    pub(crate) fn charge_heap(&self, heap: usize) {
+        // Safety: the box is alive since this container is alive.
+        let inner = unsafe { self.inner.as_ref() };
+
+        if let Some(memory) = &inner.memory {
+            memory.resize(mem::size_of::<SharedBox<T>>().saturating_add(heap));
+        }
+    }
+
     /// Return a debug formatter, that when printed will display detailed
     /// diagnostics of this shared type.
     pub fn debug(&self) -> SharedDebug<'_, T> {
@@ -127,6 +148,43 @@ impl<T> Shared<T> {
     /// assert_eq!(a.counter, 1);
     /// ```
     pub fn take(self) -> Result<T, AccessError> {
+        self.try_take()
+    }
+
+    /// Take the interior value, if we have exlusive access to it and there
+    /// are no other live exlusive or shared references.
+    ///
+    /// Unlike [take][Shared::take] this doesn't consume the handle, so if the
+    /// value is currently being accessed taking it can be retried later. Once
+    /// the value has been taken it can no longer be accessed through any
+    /// handle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Shared;
+    ///
+    /// #[derive(Debug)]
+    /// struct Foo {
+    ///     counter: isize,
+    /// }
+    ///
+    /// let a = Shared::new(Foo { counter: 0 });
+    /// let b = a.clone();
+    ///
+    /// {
+    ///     let mut a = a.borrow_mut().unwrap();
+    ///     // NB: this is prevented since we have a live reference, but `b` is
+    ///     // still usable afterwards.
+    ///     assert!(b.try_take().is_err());
+    ///     a.counter += 1;
+    /// }
+    ///
+    /// let b = b.try_take().unwrap();
+    /// assert_eq!(b.counter, 1);
+    /// assert!(a.try_take().is_err());
+    /// ```
+    pub fn try_take(&self) -> Result<T, AccessError> {
         // Safety: We know that interior value is alive since this container is
         // alive.
         //
@@ -137,7 +195,12 @@ impl<T> Shared<T> {
             // NB: don't drop guard to avoid yielding access back.
             // This will prevent the value from being dropped in the shared
             // destructor and future illegal access of any kind.
-            let _ = ManuallyDrop::new(inner.access.take()?);
+            let _ = ManuallyDrop::new(
+                inner
+                    .access
+                    .take()
+                    .map_err(|e| e.with_type_name(any::type_name::<T>()))?,
+            );
 
             // Read the pointer out without dropping the inner structure.
             // The data field will be invalid at this point, which should be
@@ -187,7 +250,12 @@ impl<T> Shared<T> {
         //
         // Appropriate access is checked when constructing the guards.
         unsafe {
-            let guard = self.inner.as_ref().access.shared()?;
+            let guard = self
+                .inner
+                .as_ref()
+                .access
+                .shared()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
 
             // NB: we need to prevent the Drop impl for Shared from being called,
             // since we are deconstructing its internals.
@@ -237,7 +305,12 @@ impl<T> Shared<T> {
         //
         // Appropriate access is checked when constructing the guards.
         unsafe {
-            let guard = self.inner.as_ref().access.exclusive()?;
+            let guard = self
+                .inner
+                .as_ref()
+                .access
+                .exclusive()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
 
             // NB: we need to prevent the Drop impl for Shared from being called,
             // since we are deconstructing its internals.
@@ -291,7 +364,10 @@ impl<T: ?Sized> Shared<T> {
         // Appropriate access is checked when constructing the guards.
         unsafe {
             let inner = self.inner.as_ref();
-            let guard = inner.access.shared()?;
+            let guard = inner
+                .access
+                .shared()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
             Ok(BorrowRef::from_raw(inner.data.get(), guard))
         }
     }
@@ -330,7 +406,10 @@ impl<T: ?Sized> Shared<T> {
         // Appropriate access is checked when constructing the guards.
         unsafe {
             let inner = self.inner.as_ref();
-            let guard = inner.access.exclusive()?;
+            let guard = inner
+                .access
+                .exclusive()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
             Ok(BorrowMut::from_raw(inner.data.get(), guard))
         }
     }
@@ -353,7 +432,12 @@ impl Shared<Any> {
             // NB: don't drop guard to avoid yielding access back.
             // This will prevent the value from being dropped in the shared
             // destructor and future illegal access of any kind.
-            let guard = ManuallyDrop::new(inner.access.take()?);
+            let guard = ManuallyDrop::new(
+                inner
+                    .access
+                    .take()
+                    .map_err(|e| e.with_type_name(any::type_name::<T>()))?,
+            );
 
             // Read the pointer out without dropping the inner structure.
             // Note that the data field will after this point be invalid.
@@ -396,7 +480,10 @@ impl Shared<Any> {
     {
         unsafe {
             let inner = self.inner.as_ref();
-            let guard = inner.access.shared()?;
+            let guard = inner
+                .access
+                .shared()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
             let expected = Hash::from_type_id(any::TypeId::of::<T>());
 
             let data = match (*inner.data.get()).as_ptr(expected) {
@@ -421,7 +508,10 @@ impl Shared<Any> {
         unsafe {
             let (data, guard) = {
                 let inner = self.inner.as_ref();
-                let guard = inner.access.shared()?;
+                let guard = inner
+                    .access
+                    .shared()
+                    .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
                 let expected = Hash::from_type_id(any::TypeId::of::<T>());
 
                 match (*inner.data.get()).as_ptr(expected) {
@@ -455,7 +545,10 @@ impl Shared<Any> {
     {
         unsafe {
             let inner = self.inner.as_ref();
-            let guard = inner.access.exclusive()?;
+            let guard = inner
+                .access
+                .exclusive()
+                .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
             let expected = Hash::from_type_id(any::TypeId::of::<T>());
 
             let data = match (*inner.data.get()).as_mut_ptr(expected) {
@@ -480,7 +573,10 @@ impl Shared<Any> {
         unsafe {
             let (data, guard) = {
                 let inner = self.inner.as_ref();
-                let guard = inner.access.exclusive()?;
+                let guard = inner
+                    .access
+                    .exclusive()
+                    .map_err(|e| e.with_type_name(any::type_name::<T>()))?;
                 let expected = Hash::from_type_id(any::TypeId::of::<T>());
 
                 match (*inner.data.get()).as_mut_ptr(expected) {
@@ -539,7 +635,7 @@ where
             if !inner.access.is_shared() {
                 write!(fmt, "*not accessible*")
             } else {
-                write!(fmt, "{:?}", &&*inner.data.get())
+                fmt::Debug::fmt(&*inner.data.get(), fmt)
             }
         }
     }
@@ -584,6 +680,8 @@ struct SharedBox<T: ?Sized> {
     access: Access,
     /// The number of strong references to the shared data.
     count: Cell<usize>,
+    /// The memory charge of this allocation, released when the box is freed.
+    memory: Option<MemoryCharge>,
     /// The value being held. Guarded by the `access` field to determine if it
     /// can be access shared or exclusively.
     data: UnsafeCell<T>,
@@ -696,6 +794,46 @@ impl<T: ?Sized> OwnedRef<T> {
 
         (this.data, guard)
     }
+
+    /// Map the interior reference into a reference to one of its parts, like
+    /// a field, while retaining the access guard.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{OwnedRef, Shared};
+    ///
+    /// struct Foo {
+    ///     counter: isize,
+    /// }
+    ///
+    /// let a = Shared::new(Foo { counter: 0 });
+    /// let b = a.clone();
+    ///
+    /// let counter = OwnedRef::map(a.owned_ref().unwrap(), |foo| &foo.counter);
+    /// assert_eq!(*counter, 0);
+    ///
+    /// // NB: the field is still guarded against exclusive access.
+    /// assert!(b.borrow_mut().is_err());
+    /// drop(counter);
+    /// assert!(b.borrow_mut().is_ok());
+    /// ```
+    pub fn map<M, U: ?Sized>(this: Self, m: M) -> OwnedRef<U>
+    where
+        M: FnOnce(&T) -> &U,
+    {
+        // Safety: the returned reference is bound to the lifetime of the
+        // reference passed in, so it must point into the guarded data which
+        // is kept alive by the retained guard.
+        let data = m(unsafe { &*this.data });
+
+        OwnedRef {
+            data,
+            guard: this.guard,
+            inner: this.inner,
+            _marker: marker::PhantomData,
+        }
+    }
 }
 
 impl<T: ?Sized> ops::Deref for OwnedRef<T> {
@@ -747,6 +885,46 @@ impl<T: ?Sized> OwnedMut<T> {
 
         (this.data, guard)
     }
+
+    /// Map the interior mutable reference into a mutable reference to one of
+    /// its parts, like a field, while retaining the access guard.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{OwnedMut, Shared};
+    ///
+    /// struct Foo {
+    ///     counter: isize,
+    /// }
+    ///
+    /// let a = Shared::new(Foo { counter: 0 });
+    /// let b = a.clone();
+    ///
+    /// let mut counter = OwnedMut::map(a.owned_mut().unwrap(), |foo| &mut foo.counter);
+    /// *counter += 1;
+    ///
+    /// // NB: the field is still guarded against any other access.
+    /// assert!(b.borrow_ref().is_err());
+    /// drop(counter);
+    /// assert_eq!(b.borrow_ref().unwrap().counter, 1);
+    /// ```
+    pub fn map<M, U: ?Sized>(this: Self, m: M) -> OwnedMut<U>
+    where
+        M: FnOnce(&mut T) -> &mut U,
+    {
+        // Safety: the returned reference is bound to the lifetime of the
+        // reference passed in, so it must point into the guarded data which
+        // is kept alive by the retained guard.
+        let data = m(unsafe { &mut *this.data });
+
+        OwnedMut {
+            data,
+            guard: this.guard,
+            inner: this.inner,
+            _marker: marker::PhantomData,
+        }
+    }
 }
 
 impl<T: ?Sized> ops::Deref for OwnedMut<T> {
@@ -50,6 +50,11 @@ impl Stack {
         }
     }
 
+    /// Get the number of values the stack can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
     /// Clear the current stack.
     pub fn clear(&mut self) {
         self.stack.clear();
@@ -183,3 +183,27 @@ pub static FUNCTION_TYPE: &StaticType = &StaticType {
 impl_static_type!(crate::Function => FUNCTION_TYPE);
 impl_static_type!(crate::Shared<crate::Function> => FUNCTION_TYPE);
 impl_static_type!(crate::OwnedRef<crate::Function> => FUNCTION_TYPE);
+
+/// The type information for the `Iterable` capability marker.
+pub static ITERABLE_TYPE: &StaticType = &StaticType {
+    name: "Iterable",
+    hash: Hash::new(0x433b8a431c4b1520),
+};
+
+impl_static_type!(crate::Iterable => ITERABLE_TYPE);
+
+/// The type information for the `Callable` capability marker.
+pub static CALLABLE_TYPE: &StaticType = &StaticType {
+    name: "Callable",
+    hash: Hash::new(0xe6820919c0a21c28),
+};
+
+impl_static_type!(crate::Callable => CALLABLE_TYPE);
+
+/// The type information for the `Indexable` capability marker.
+pub static INDEXABLE_TYPE: &StaticType = &StaticType {
+    name: "Indexable",
+    hash: Hash::new(0x7f1eaafef7ba5ac2),
+};
+
+impl_static_type!(crate::Indexable => INDEXABLE_TYPE);
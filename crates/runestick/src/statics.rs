@@ -0,0 +1,108 @@
+//! Storage for lazily initialized statics.
+//!
+//! Statics are initialized the first time they are accessed by a virtual
+//! machine, after which the value is cached and shared with any virtual
+//! machines spawned by it, like the ones backing generators and async
+//! functions.
+
+use crate::collections::HashMap;
+use crate::{Hash, Unit, Value, VmError, VmErrorKind};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+thread_local! {
+    /// The statics installed for the currently running virtual machine, if
+    /// any.
+    static CURRENT: RefCell<Option<Statics>> = RefCell::new(None);
+}
+
+/// The values of statics which have been initialized.
+///
+/// This is cheap to clone, all clones refer to the same storage.
+#[derive(Clone)]
+pub(crate) struct Statics {
+    /// The unit the statics are declared in.
+    unit: Arc<Unit>,
+    /// Statics by hash. `None` indicates a static which is currently being
+    /// initialized.
+    inner: Rc<RefCell<HashMap<Hash, Option<Value>>>>,
+}
+
+impl Statics {
+    /// Construct a new, empty collection of statics for the given unit.
+    pub(crate) fn new(unit: Arc<Unit>) -> Self {
+        Self {
+            unit,
+            inner: Default::default(),
+        }
+    }
+
+    /// Test if these are the statics of the given unit.
+    pub(crate) fn is_for(&self, unit: &Arc<Unit>) -> bool {
+        Arc::ptr_eq(&self.unit, unit)
+    }
+
+    /// Get the value of the given static if it has been initialized.
+    ///
+    /// If the static hasn't been initialized it's marked as being initialized,
+    /// and trying to access it again before it has been stored errors with
+    /// [VmErrorKind::StaticCycle].
+    pub(crate) fn get_or_begin(&self, hash: Hash) -> Result<Option<Value>, VmError> {
+        let mut inner = self.inner.borrow_mut();
+
+        match inner.get(&hash) {
+            Some(Some(value)) => Ok(Some(value.clone())),
+            Some(None) => Err(VmError::from(VmErrorKind::StaticCycle { hash })),
+            None => {
+                inner.insert(hash, None);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Store the initialized value of a static.
+    pub(crate) fn store(&self, hash: Hash, value: Value) {
+        self.inner.borrow_mut().insert(hash, Some(value));
+    }
+
+    /// Forget about statics which didn't finish initializing, so that they
+    /// are initialized again the next time they are accessed.
+    pub(crate) fn clear_pending(&self) {
+        self.inner.borrow_mut().retain(|_, value| value.is_some());
+    }
+
+    /// Get the statics installed for the currently running virtual machine,
+    /// if any.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Install these statics for the current thread until the returned guard
+    /// is dropped.
+    pub(crate) fn install(&self) -> StaticsGuard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        StaticsGuard { previous }
+    }
+}
+
+impl fmt::Debug for Statics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Statics")
+            .field("len", &self.inner.borrow().len())
+            .finish()
+    }
+}
+
+/// Guard which restores the previously installed statics when dropped.
+pub(crate) struct StaticsGuard {
+    previous: Option<Statics>,
+}
+
+impl Drop for StaticsGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
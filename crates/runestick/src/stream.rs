@@ -1,14 +1,17 @@
 use crate::{
-    FromValue, GeneratorState, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared,
+    FromValue, GeneratorState, OwnedMut, OwnedRef, RawOwnedMut, RawOwnedRef, Shared, ToValue,
     UnsafeFromValue, Value, Vm, VmError, VmErrorKind, VmExecution,
 };
+use futures::stream::{LocalBoxStream, StreamExt as _};
 use std::fmt;
 use std::mem;
 
-/// A stream with a stored virtual machine.
+/// A stream, either backed by a stored virtual machine or by a native stream
+/// provided by the host.
 pub struct Stream {
     execution: Option<VmExecution>,
     first: bool,
+    native: Option<LocalBoxStream<'static, Result<Value, VmError>>>,
 }
 
 impl Stream {
@@ -17,6 +20,30 @@ impl Stream {
         Self {
             execution: Some(VmExecution::new(vm)),
             first: true,
+            native: None,
+        }
+    }
+
+    /// Construct a stream from a native stream, which allows it to be
+    /// consumed by scripts using `for await` or `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Shared, Stream, Value};
+    ///
+    /// let stream = Stream::from_stream(futures::stream::iter(vec![1i64, 2, 3]));
+    /// let stream = Value::from(Shared::new(stream));
+    /// ```
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: futures::Stream + 'static,
+        S::Item: ToValue,
+    {
+        Self {
+            execution: None,
+            first: false,
+            native: Some(stream.map(ToValue::to_value).boxed_local()),
         }
     }
 
@@ -29,7 +56,20 @@ impl Stream {
     }
 
     /// Get the next value produced by this stream.
+    ///
+    /// Since native streams can't be resumed with a value, the value is
+    /// ignored for them.
     pub async fn resume(&mut self, value: Value) -> Result<GeneratorState, VmError> {
+        if let Some(native) = &mut self.native {
+            return Ok(match native.next().await.transpose()? {
+                Some(value) => GeneratorState::Yielded(value),
+                None => {
+                    self.native = None;
+                    GeneratorState::Complete(Value::Unit)
+                }
+            });
+        }
+
         let execution = match &mut self.execution {
             Some(execution) => execution,
             None => {
@@ -54,7 +94,10 @@ impl Stream {
 impl fmt::Debug for Stream {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Stream")
-            .field("completed", &self.execution.is_none())
+            .field(
+                "completed",
+                &(self.execution.is_none() && self.native.is_none()),
+            )
             .finish()
     }
 }
@@ -23,6 +23,16 @@ impl Tuple {
 
 impl fmt::Debug for Tuple {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut tuple = f.debug_tuple("");
+
+            for el in self.inner.iter() {
+                tuple.field(el);
+            }
+
+            return tuple.finish();
+        }
+
         write!(f, "(")?;
 
         let mut it = self.inner.iter();
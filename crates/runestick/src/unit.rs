@@ -4,10 +4,51 @@
 //! metadata like function locations.
 
 use crate::collections::HashMap;
-use crate::{Call, DebugInfo, Hash, Inst, StaticString, Type, VmError, VmErrorKind};
+use crate::debug::DebugSignature;
+use crate::{
+    Call, Context, DebugInfo, DebugLabel, FunctionDiff, Hash, Inst, Item, Span, StaticString, Type,
+    VmError, VmErrorKind,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::sync::Arc;
+use thiserror::Error;
+
+/// An error raised when linking a unit against a context.
+#[derive(Debug, Error)]
+pub struct LinkError {
+    missing: Vec<(Hash, Option<Item>)>,
+}
+
+impl LinkError {
+    /// The hashes of the missing functions, in the order they are first used,
+    /// together with their names if they are available in the debug
+    /// information of the unit.
+    pub fn missing(&self) -> &[(Hash, Option<Item>)] {
+        &self.missing
+    }
+}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "missing functions: ")?;
+
+        let mut it = self.missing.iter().peekable();
+
+        while let Some((hash, item)) = it.next() {
+            match item {
+                Some(item) => write!(fmt, "`{}` ({})", item, hash)?,
+                None => write!(fmt, "{}", hash)?,
+            }
+
+            if it.peek().is_some() {
+                write!(fmt, ", ")?;
+            }
+        }
+
+        Ok(())
+    }
+}
 
 /// Instructions from a single source file.
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -29,6 +70,10 @@ pub struct Unit {
     ///
     /// All keys are sorted with the default string sort.
     static_object_keys: Vec<Box<[String]>>,
+    /// Functions marked with `#[test]`, in the order they were declared.
+    tests: Vec<Item>,
+    /// Functions marked with `#[bench]`, in the order they were declared.
+    benches: Vec<Item>,
     /// Debug info if available for unit.
     debug: Option<Box<DebugInfo>>,
 }
@@ -51,10 +96,34 @@ impl Unit {
             static_strings,
             static_bytes,
             static_object_keys,
+            tests: Vec::new(),
+            benches: Vec::new(),
             debug,
         }
     }
 
+    /// Set the functions marked with `#[test]` in the unit.
+    pub fn with_tests(self, tests: Vec<Item>) -> Self {
+        Self { tests, ..self }
+    }
+
+    /// Iterate over all functions marked with `#[test]` in the unit, in the
+    /// order they were declared.
+    pub fn iter_tests(&self) -> impl Iterator<Item = &Item> + '_ {
+        self.tests.iter()
+    }
+
+    /// Set the functions marked with `#[bench]` in the unit.
+    pub fn with_benches(self, benches: Vec<Item>) -> Self {
+        Self { benches, ..self }
+    }
+
+    /// Iterate over all functions marked with `#[bench]` in the unit, in the
+    /// order they were declared.
+    pub fn iter_benches(&self) -> impl Iterator<Item = &Item> + '_ {
+        self.benches.iter()
+    }
+
     /// Access the type for the given language item.
     pub fn lookup_type(&self, hash: Hash) -> Option<&UnitTypeInfo> {
         self.types.get(&hash)
@@ -91,6 +160,149 @@ impl Unit {
         self.instructions.iter().copied()
     }
 
+    /// Disassemble all instructions in the unit into structured records,
+    /// including any debug information associated with them.
+    pub fn disassemble(&self) -> Vec<DisassembledInst<'_>> {
+        let debug = self.debug_info();
+
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(index, inst)| {
+                let formatted = inst.to_string();
+
+                // NB: instructions are formatted as the opcode, optionally
+                // followed by a space and its operands.
+                let (opcode, operands) = match formatted.find(' ') {
+                    Some(n) => (formatted[..n].to_owned(), formatted[n + 1..].to_owned()),
+                    None => (formatted, String::new()),
+                };
+
+                let function = debug.and_then(|d| d.function_at(index));
+                let debug = debug.and_then(|d| d.instruction_at(index));
+
+                DisassembledInst {
+                    index,
+                    inst: *inst,
+                    opcode,
+                    operands,
+                    function,
+                    source_id: debug.map(|d| d.source_id),
+                    span: debug.map(|d| d.span),
+                    label: debug.and_then(|d| d.label.as_ref()),
+                    comment: debug.and_then(|d| d.comment.as_deref()),
+                }
+            })
+            .collect()
+    }
+
+    /// Compare the instructions of this unit against a newer version of it,
+    /// aligned by function.
+    ///
+    /// Functions are matched by hash, and only the ones which differ are
+    /// returned. Functions which are only present in one of the units are
+    /// reported as wholly added or removed. Instructions are compared by how
+    /// they are [disassembled][Unit::disassemble].
+    pub fn diff<'a>(&'a self, other: &'a Unit) -> Vec<FunctionDiff<'a>> {
+        crate::unit_diff::diff(self, other)
+    }
+
+    /// Collect all functions defined in the unit which have a known
+    /// declaration, ordered by where they are declared.
+    ///
+    /// This requires the unit to be compiled with debug information, otherwise
+    /// no symbols are returned.
+    pub fn symbols(&self) -> Vec<UnitSymbol<'_>> {
+        let debug = match self.debug_info() {
+            Some(debug) => debug,
+            None => return Vec::new(),
+        };
+
+        let mut symbols = self
+            .functions
+            .iter()
+            .flat_map(|(hash, f)| Self::symbol(debug, *hash, f))
+            .collect::<Vec<_>>();
+
+        symbols.sort_by_key(|s| (s.source_id, s.span.start, s.span.end));
+        symbols
+    }
+
+    /// Query what is known about the given byte position in the source
+    /// identified by `source_id`.
+    ///
+    /// This is only as precise as the debug information stored in the unit,
+    /// which maps instructions to the spans they were compiled from. The
+    /// innermost instruction covering the position is reported, together with
+    /// the innermost function declared around it. Calls to functions which
+    /// are declared in the unit are resolved to their signature, but no type
+    /// information is available for other expressions, and positions which
+    /// don't produce any instructions (like comments or unused items) only
+    /// report their enclosing function.
+    ///
+    /// This requires the unit to be compiled with debug information, otherwise
+    /// `None` is returned.
+    pub fn hover(&self, source_id: usize, position: usize) -> Option<UnitHover<'_>> {
+        let debug = self.debug_info()?;
+
+        let contains = |span: Span| span.start <= position && position < span.end;
+
+        let function = self
+            .functions
+            .iter()
+            .flat_map(|(hash, f)| Self::symbol(debug, *hash, f))
+            .filter(|s| s.source_id == source_id && contains(s.span))
+            .min_by_key(|s| s.span.end - s.span.start);
+
+        let instruction = debug
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.source_id == source_id && contains(d.span))
+            .min_by_key(|(_, d)| d.span.end - d.span.start);
+
+        let (index, span) = match instruction {
+            Some((index, d)) => (Some(index), Some(d.span)),
+            None => (None, None),
+        };
+
+        let inst = index.and_then(|index| self.instructions.get(index).copied());
+
+        let call = match inst {
+            Some(Inst::Call { hash, .. }) => Some(hash),
+            Some(Inst::Fn { hash }) => Some(hash),
+            Some(Inst::Closure { hash, .. }) => Some(hash),
+            _ => None,
+        };
+
+        Some(UnitHover {
+            function,
+            index,
+            inst,
+            span,
+            call,
+            signature: call.and_then(|hash| debug.functions.get(&hash)),
+        })
+    }
+
+    /// Construct the symbol for a function, if it has a known declaration.
+    fn symbol<'a>(debug: &'a DebugInfo, hash: Hash, f: &UnitFn) -> Option<UnitSymbol<'a>> {
+        let args = match f {
+            UnitFn::Offset { args, .. } => *args,
+            _ => return None,
+        };
+
+        let signature = debug.functions.get(&hash)?;
+
+        Some(UnitSymbol {
+            hash,
+            path: &signature.path,
+            args,
+            source_id: signature.source_id?,
+            span: signature.span?,
+        })
+    }
+
     /// Iterate over dynamic functions.
     pub fn iter_functions(&self) -> impl Iterator<Item = (Hash, &UnitFn)> + '_ {
         self.functions.iter().map(|(h, f)| (*h, f))
@@ -101,6 +313,128 @@ impl Unit {
         self.types.iter().map(|(h, v)| (*h, v))
     }
 
+    /// Verify that every instruction in the unit only refers to things which
+    /// exist, like jump targets, static slots and functions which are either
+    /// declared in the unit or in the given context.
+    ///
+    /// The virtual machine assumes that a unit is well-formed, so this should
+    /// be used before running a unit loaded from an untrusted source, like a
+    /// deserialized bytecode cache.
+    pub fn verify(&self, context: &Context) -> Result<(), VmError> {
+        for (hash, f) in &self.functions {
+            if let UnitFn::Offset { offset, .. } = f {
+                if *offset >= self.instructions.len() {
+                    return Err(VmError::from(VmErrorKind::BadFunctionOffset {
+                        hash: *hash,
+                        offset: *offset,
+                    }));
+                }
+            }
+        }
+
+        for (ip, inst) in self.instructions.iter().enumerate() {
+            if let Err(error) = self.verify_inst(context, ip, inst) {
+                return Err(VmError::from(VmErrorKind::BadInstruction {
+                    ip,
+                    inst: *inst,
+                    error,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that every function called from the unit is either declared in
+    /// the unit or provided by the given context.
+    ///
+    /// Unlike [verify][Unit::verify], this reports every missing function at
+    /// once. It can be used to check that a deserialized bytecode cache can
+    /// still be run with the current context.
+    pub fn link(&self, context: &Context) -> Result<(), LinkError> {
+        let mut missing = Vec::<(Hash, Option<Item>)>::new();
+
+        for inst in &self.instructions {
+            let hash = match *inst {
+                Inst::Call { hash, .. } | Inst::Fn { hash } => {
+                    if self.functions.contains_key(&hash) || context.lookup(hash).is_some() {
+                        continue;
+                    }
+
+                    hash
+                }
+                Inst::Closure { hash, .. } if !self.functions.contains_key(&hash) => hash,
+                _ => continue,
+            };
+
+            if missing.iter().any(|(h, _)| *h == hash) {
+                continue;
+            }
+
+            let item = self
+                .debug_info()
+                .and_then(|debug| debug.called_functions.get(&hash))
+                .cloned();
+
+            missing.push((hash, item));
+        }
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        Err(LinkError { missing })
+    }
+
+    /// Verify the operands of a single instruction.
+    fn verify_inst(&self, context: &Context, ip: usize, inst: &Inst) -> Result<(), VmError> {
+        match *inst {
+            Inst::Jump { offset }
+            | Inst::JumpIf { offset }
+            | Inst::JumpIfNot { offset }
+            | Inst::JumpIfBranch { offset, .. }
+            | Inst::PopAndJumpIfNot { offset, .. } => {
+                // NB: the instruction pointer is advanced after the jump.
+                let target = (ip as isize)
+                    .checked_add(offset)
+                    .and_then(|target| target.checked_add(1));
+
+                match target {
+                    Some(target) if target >= 0 && (target as usize) < self.instructions.len() => {}
+                    _ => return Err(VmError::from(VmErrorKind::IpOutOfBounds)),
+                }
+            }
+            Inst::String { slot }
+            | Inst::EqStaticString { slot }
+            | Inst::ObjectSlotIndexGet { slot }
+            | Inst::ObjectSlotIndexGetAt { slot, .. } => {
+                self.lookup_string(slot)?;
+            }
+            Inst::Bytes { slot } => {
+                self.lookup_bytes(slot)?;
+            }
+            Inst::Object { slot }
+            | Inst::TypedObject { slot, .. }
+            | Inst::VariantObject { slot, .. }
+            | Inst::MatchObject { slot, .. }
+                if self.lookup_object_keys(slot).is_none() =>
+            {
+                return Err(VmError::from(VmErrorKind::MissingStaticObjectKeys { slot }));
+            }
+            Inst::Call { hash, .. } | Inst::Fn { hash }
+                if !self.functions.contains_key(&hash) && context.lookup(hash).is_none() =>
+            {
+                return Err(VmError::from(VmErrorKind::MissingFunction { hash }));
+            }
+            Inst::Closure { hash, .. } if !self.functions.contains_key(&hash) => {
+                return Err(VmError::from(VmErrorKind::MissingFunction { hash }));
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
     /// Lookup the static string by slot, if it exists.
     pub fn lookup_string(&self, slot: usize) -> Result<&Arc<StaticString>, VmError> {
         Ok(self
@@ -189,3 +523,78 @@ pub struct UnitTypeInfo {
     /// value type of the given type.
     pub value_type: Type,
 }
+
+/// A function defined in a unit.
+///
+/// Constructed through [Unit::symbols].
+#[derive(Debug, Clone)]
+pub struct UnitSymbol<'a> {
+    /// The hash of the function.
+    pub hash: Hash,
+    /// The path of the function.
+    pub path: &'a Item,
+    /// The number of parameters the function takes.
+    pub args: usize,
+    /// The id of the source the function was declared in.
+    pub source_id: usize,
+    /// The span of the function declaration.
+    pub span: Span,
+}
+
+/// Information about a position in a source.
+///
+/// Constructed through [Unit::hover].
+#[derive(Debug, Clone)]
+pub struct UnitHover<'a> {
+    /// The innermost function declared around the position, if any.
+    pub function: Option<UnitSymbol<'a>>,
+    /// The index of the innermost instruction covering the position, if any.
+    pub index: Option<usize>,
+    /// The innermost instruction covering the position, if any.
+    pub inst: Option<Inst>,
+    /// The span of the innermost instruction covering the position, if any.
+    pub span: Option<Span>,
+    /// The hash of the function being called or referenced by the
+    /// instruction, if it is known statically.
+    pub call: Option<Hash>,
+    /// The signature of the function being called or referenced, if it is
+    /// declared in the unit.
+    pub signature: Option<&'a DebugSignature>,
+}
+
+/// A single disassembled instruction.
+///
+/// Constructed through [Unit::disassemble].
+#[derive(Debug, Clone)]
+pub struct DisassembledInst<'a> {
+    /// The index of the instruction in the unit.
+    pub index: usize,
+    /// The instruction.
+    pub inst: Inst,
+    /// The name of the instruction, like `copy`.
+    pub opcode: String,
+    /// The formatted operands of the instruction, which might be empty.
+    pub operands: String,
+    /// The function which starts at this instruction, if any.
+    pub function: Option<(Hash, &'a DebugSignature)>,
+    /// The id of the source the instruction was compiled from, if available.
+    pub source_id: Option<usize>,
+    /// The span of the instruction, if available.
+    pub span: Option<Span>,
+    /// The label attached to the instruction, if any.
+    pub label: Option<&'a DebugLabel>,
+    /// The comment attached to the instruction, if any.
+    pub comment: Option<&'a str>,
+}
+
+impl fmt::Display for DisassembledInst<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04} = {}", self.index, self.inst)?;
+
+        if let Some(comment) = self.comment {
+            write!(f, " // {}", comment)?;
+        }
+
+        Ok(())
+    }
+}
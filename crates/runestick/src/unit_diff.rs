@@ -0,0 +1,274 @@
+//! Diffing of the instructions of two units.
+
+use crate::collections::HashMap;
+use crate::debug::DebugSignature;
+use crate::{Hash, Inst, Unit, UnitFn};
+use std::fmt;
+use std::ops::Range;
+
+/// How a function differs between two units.
+///
+/// Constructed through [Unit::diff].
+#[derive(Debug, Clone)]
+pub struct FunctionDiff<'a> {
+    /// The hash of the function.
+    pub hash: Hash,
+    /// The signature of the function, if the units have debug information.
+    pub signature: Option<&'a DebugSignature>,
+    /// How the function changed.
+    pub kind: FunctionDiffKind,
+    /// The instructions of the function, aligned between the two units.
+    pub insts: Vec<InstDiff>,
+}
+
+/// How a function changed between two units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionDiffKind {
+    /// The function is only present in the new unit.
+    Added,
+    /// The function is only present in the old unit.
+    Removed,
+    /// The function is present in both units, but its instructions differ.
+    Changed,
+}
+
+/// A single aligned instruction in a [FunctionDiff].
+#[derive(Debug, Clone, Copy)]
+pub enum InstDiff {
+    /// The instruction is the same in both units.
+    Same(Inst),
+    /// The instruction was added in the new unit.
+    Added(Inst),
+    /// The instruction was removed from the old unit.
+    Removed(Inst),
+    /// The instruction has the same opcode, but different operands.
+    Changed(Inst, Inst),
+}
+
+impl fmt::Display for FunctionDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.kind {
+            FunctionDiffKind::Added => '+',
+            FunctionDiffKind::Removed => '-',
+            FunctionDiffKind::Changed => '~',
+        };
+
+        match self.signature {
+            Some(signature) => writeln!(f, "{} fn {} ({}):", prefix, signature, self.hash)?,
+            None => writeln!(f, "{} fn {}:", prefix, self.hash)?,
+        }
+
+        for inst in &self.insts {
+            match inst {
+                InstDiff::Same(inst) => writeln!(f, "    {}", inst)?,
+                InstDiff::Added(inst) => writeln!(f, "  + {}", inst)?,
+                InstDiff::Removed(inst) => writeln!(f, "  - {}", inst)?,
+                InstDiff::Changed(a, b) => writeln!(f, "  ~ {} -> {}", a, b)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare the instructions of the functions of two units.
+pub(crate) fn diff<'a>(a: &'a Unit, b: &'a Unit) -> Vec<FunctionDiff<'a>> {
+    let a_insts = a.iter_instructions().collect::<Vec<_>>();
+    let b_insts = b.iter_instructions().collect::<Vec<_>>();
+
+    let a_functions = functions(a, a_insts.len());
+    let b_functions = functions(b, b_insts.len());
+
+    let mut diffs = Vec::new();
+
+    for (hash, range) in &a_functions {
+        let insts = &a_insts[range.clone()];
+
+        let kind = match b_functions.iter().find(|(h, _)| h == hash) {
+            Some((_, range)) => {
+                let insts = diff_insts(insts, &b_insts[range.clone()]);
+
+                if insts.iter().all(|i| matches!(i, InstDiff::Same(..))) {
+                    continue;
+                }
+
+                (FunctionDiffKind::Changed, insts)
+            }
+            None => (
+                FunctionDiffKind::Removed,
+                insts.iter().copied().map(InstDiff::Removed).collect(),
+            ),
+        };
+
+        diffs.push(FunctionDiff {
+            hash: *hash,
+            signature: signature(b, *hash).or_else(|| signature(a, *hash)),
+            kind: kind.0,
+            insts: kind.1,
+        });
+    }
+
+    for (hash, range) in &b_functions {
+        if a_functions.iter().any(|(h, _)| h == hash) {
+            continue;
+        }
+
+        diffs.push(FunctionDiff {
+            hash: *hash,
+            signature: signature(b, *hash),
+            kind: FunctionDiffKind::Added,
+            insts: b_insts[range.clone()]
+                .iter()
+                .copied()
+                .map(InstDiff::Added)
+                .collect(),
+        });
+    }
+
+    diffs
+}
+
+/// Collect the instruction ranges of all functions in the unit with `len`
+/// instructions, ordered by offset.
+///
+/// Functions which are registered under multiple hashes are identified by the
+/// hash recorded in the debug information, or the smallest one if there is
+/// none.
+fn functions(unit: &Unit, len: usize) -> Vec<(Hash, Range<usize>)> {
+    let mut by_offset = HashMap::<usize, Hash>::new();
+
+    for (hash, f) in unit.iter_functions() {
+        let offset = match f {
+            UnitFn::Offset { offset, .. } => *offset,
+            _ => continue,
+        };
+
+        let current = by_offset.entry(offset).or_insert(hash);
+
+        if hash < *current {
+            *current = hash;
+        }
+    }
+
+    if let Some(debug) = unit.debug_info() {
+        for (offset, hash) in by_offset.iter_mut() {
+            if let Some(h) = debug.functions_rev.get(offset) {
+                *hash = *h;
+            }
+        }
+    }
+
+    let mut offsets = by_offset.into_iter().collect::<Vec<_>>();
+    offsets.sort_by_key(|(offset, _)| *offset);
+
+    let mut functions = Vec::with_capacity(offsets.len());
+
+    for (n, (offset, hash)) in offsets.iter().enumerate() {
+        let end = offsets.get(n + 1).map(|(o, _)| *o).unwrap_or(len);
+        functions.push((*hash, *offset..end));
+    }
+
+    functions
+}
+
+/// Get the signature of the function with the given hash from the unit.
+fn signature(unit: &Unit, hash: Hash) -> Option<&DebugSignature> {
+    unit.debug_info()?.functions.get(&hash)
+}
+
+/// Align two instruction sequences through their longest common
+/// subsequence, comparing instructions by how they are disassembled.
+fn diff_insts(a: &[Inst], b: &[Inst]) -> Vec<InstDiff> {
+    let a_text = a.iter().map(Inst::to_string).collect::<Vec<_>>();
+    let b_text = b.iter().map(Inst::to_string).collect::<Vec<_>>();
+
+    // lcs[i][j] is the length of the longest common subsequence of a[i..]
+    // and b[j..].
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a_text[i] == b_text[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a_text[i] == b_text[j] {
+            out.push(InstDiff::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(InstDiff::Removed(a[i]));
+            i += 1;
+        } else {
+            out.push(InstDiff::Added(b[j]));
+            j += 1;
+        }
+    }
+
+    pair_changes(out)
+}
+
+/// Pair up runs of removed instructions directly followed by runs of added
+/// instructions, turning the ones with the same opcode into changed
+/// instructions.
+fn pair_changes(insts: Vec<InstDiff>) -> Vec<InstDiff> {
+    let mut out = Vec::with_capacity(insts.len());
+    let mut it = insts.into_iter().peekable();
+
+    while let Some(inst) = it.next() {
+        let first = match inst {
+            InstDiff::Removed(inst) => inst,
+            inst => {
+                out.push(inst);
+                continue;
+            }
+        };
+
+        let mut removed = vec![first];
+
+        while let Some(InstDiff::Removed(inst)) = it.peek().copied() {
+            removed.push(inst);
+            it.next();
+        }
+
+        let mut added = Vec::new();
+
+        while let Some(InstDiff::Added(inst)) = it.peek().copied() {
+            added.push(inst);
+            it.next();
+        }
+
+        for n in 0..removed.len().max(added.len()) {
+            match (removed.get(n).copied(), added.get(n).copied()) {
+                (Some(a), Some(b)) if opcode(&a) == opcode(&b) => {
+                    out.push(InstDiff::Changed(a, b));
+                }
+                (a, b) => {
+                    out.extend(a.map(InstDiff::Removed));
+                    out.extend(b.map(InstDiff::Added));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Get the opcode of an instruction as it's disassembled.
+fn opcode(inst: &Inst) -> String {
+    let mut formatted = inst.to_string();
+
+    if let Some(n) = formatted.find(' ') {
+        formatted.truncate(n);
+    }
+
+    formatted
+}
@@ -1,9 +1,13 @@
 use crate::{
     Any, Bytes, Function, Future, Generator, GeneratorState, Hash, Object, OwnedMut, OwnedRef,
     RawOwnedMut, RawOwnedRef, Shared, StaticString, Stream, Tuple, Type, TypeInfo, VmError,
+    VmErrorKind,
 };
 use std::any;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash;
+use std::mem;
 use std::sync::Arc;
 
 /// A tuple with a well-defined type.
@@ -447,6 +451,47 @@ impl Value {
         })
     }
 
+    /// Charge the buffer owned by a string, byte array, vector or object
+    /// against the memory limit it was allocated under, if any.
+    ///
+    /// Values which are currently borrowed exclusively are skipped, and will be
+    /// charged the next time they're measured.
+    pub(crate) fn charge_heap(&self) {
+        match self {
+            Self::String(string) => {
+                if let Ok(s) = string.borrow_ref() {
+                    string.charge_heap(s.capacity());
+                }
+            }
+            Self::Bytes(bytes) => {
+                if let Ok(b) = bytes.borrow_ref() {
+                    bytes.charge_heap(b.capacity());
+                }
+            }
+            Self::Vec(vec) => {
+                if let Ok(v) = vec.borrow_ref() {
+                    vec.charge_heap(v.capacity().saturating_mul(mem::size_of::<Value>()));
+                }
+            }
+            Self::Object(object) => {
+                if let Ok(o) = object.borrow_ref() {
+                    let entry = mem::size_of::<(String, Value)>();
+                    object.charge_heap(o.capacity().saturating_mul(entry));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Get the hash of the type of the current value.
+    ///
+    /// This allows host code to dispatch on the type of a value by comparing
+    /// it against [Hash::from_type_id], or the hash of a type registered in a
+    /// context, without matching over every variant.
+    pub fn type_hash(&self) -> Result<Hash, VmError> {
+        Ok(*self.value_type()?)
+    }
+
     /// Get the type information for the current value.
     pub fn type_info(&self) -> Result<TypeInfo, VmError> {
         Ok(match self {
@@ -478,54 +523,117 @@ impl Value {
         })
     }
 
+    /// Write the user-facing representation of this value to `out`.
+    ///
+    /// This is what's used when printing a value or interpolating it into a
+    /// template string. Strings and characters are written without quotes,
+    /// numbers and booleans are written as-is, while other values are written
+    /// using their [Debug][fmt::Debug] representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Value;
+    ///
+    /// let value = Value::from(String::from("hi"));
+    ///
+    /// let mut out = String::new();
+    /// value.string_display(&mut out).unwrap();
+    /// assert_eq!(out, "hi");
+    /// ```
+    pub fn string_display<W>(&self, out: &mut W) -> Result<(), VmError>
+    where
+        W: ?Sized + fmt::Write,
+    {
+        let result = match self {
+            Self::String(string) => out.write_str(&string.borrow_ref()?),
+            Self::StaticString(string) => out.write_str(string.as_str()),
+            Self::Char(c) => out.write_char(*c),
+            Self::Bool(b) => write!(out, "{}", b),
+            Self::Byte(b) => write!(out, "{}", b),
+            Self::Integer(integer) => out.write_str(itoa::Buffer::new().format(*integer)),
+            Self::Float(float) => write_float(out, *float),
+            value => write!(out, "{:?}", value),
+        };
+
+        result.map_err(|fmt::Error| VmError::from(VmErrorKind::FormatError))
+    }
+
     /// Optimized function to test if two value pointers are deeply equal to
     /// each other.
     ///
-    /// This is the basis for the eq operation (`==`).
+    /// This is the basis for the eq operation (`==`). Vectors, tuples, and
+    /// objects, including typed ones, are compared structurally by comparing
+    /// their elements.
+    ///
+    /// Containers which contain themselves are compared by assuming that a
+    /// pair of containers which is already being compared is equal, so
+    /// comparing self-referential values terminates.
     pub(crate) fn value_ptr_eq(a: &Value, b: &Value) -> Result<bool, VmError> {
+        Self::value_ptr_eq_with(a, b, &mut Vec::new())
+    }
+
+    /// Test if two values are deeply equal, keeping track of the pairs of
+    /// containers being compared in `visiting` to detect cycles.
+    fn value_ptr_eq_with(
+        a: &Value,
+        b: &Value,
+        visiting: &mut Vec<(*const (), *const ())>,
+    ) -> Result<bool, VmError> {
         Ok(match (a, b) {
             (Self::Unit, Self::Unit) => true,
             (Self::Char(a), Self::Char(b)) => a == b,
             (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Byte(a), Self::Byte(b)) => a == b,
             (Self::Integer(a), Self::Integer(b)) => a == b,
             (Self::Float(a), Self::Float(b)) => a == b,
+            (Self::Type(a), Self::Type(b)) => a == b,
+            (Self::Bytes(a), Self::Bytes(b)) => *a.borrow_ref()? == *b.borrow_ref()?,
             (Self::Vec(a), Self::Vec(b)) => {
                 let a = a.borrow_ref()?;
                 let b = b.borrow_ref()?;
-
-                if a.len() != b.len() {
-                    return Ok(false);
-                }
-
-                for (a, b) in a.iter().zip(b.iter()) {
-                    if !Self::value_ptr_eq(a, b)? {
-                        return Ok(false);
-                    }
-                }
-
-                true
+                Self::slice_eq(&a, &b, visiting)?
+            }
+            (Self::Tuple(a), Self::Tuple(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                Self::slice_eq(&a, &b, visiting)?
             }
             (Self::Object(a), Self::Object(b)) => {
                 let a = a.borrow_ref()?;
                 let b = b.borrow_ref()?;
-
-                if a.len() != b.len() {
-                    return Ok(false);
-                }
-
-                for (key, a) in a.iter() {
-                    let b = match b.get(key) {
-                        Some(b) => b,
-                        None => return Ok(false),
-                    };
-
-                    if !Self::value_ptr_eq(a, b)? {
-                        return Ok(false);
-                    }
-                }
-
-                true
+                Self::object_eq(&a, &b, visiting)?
+            }
+            (Self::TypedTuple(a), Self::TypedTuple(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.hash == b.hash && Self::slice_eq(&a.tuple, &b.tuple, visiting)?
+            }
+            (Self::TupleVariant(a), Self::TupleVariant(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.hash == b.hash && Self::slice_eq(&a.tuple, &b.tuple, visiting)?
+            }
+            (Self::TypedObject(a), Self::TypedObject(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.hash == b.hash && Self::object_eq(&a.object, &b.object, visiting)?
+            }
+            (Self::VariantObject(a), Self::VariantObject(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.hash == b.hash && Self::object_eq(&a.object, &b.object, visiting)?
             }
+            (Self::Option(a), Self::Option(b)) => match (&*a.borrow_ref()?, &*b.borrow_ref()?) {
+                (Some(a), Some(b)) => Self::value_ptr_eq_with(a, b, visiting)?,
+                (None, None) => true,
+                _ => false,
+            },
+            (Self::Result(a), Self::Result(b)) => match (&*a.borrow_ref()?, &*b.borrow_ref()?) {
+                (Ok(a), Ok(b)) => Self::value_ptr_eq_with(a, b, visiting)?,
+                (Err(a), Err(b)) => Self::value_ptr_eq_with(a, b, visiting)?,
+                _ => false,
+            },
             (Self::String(a), Self::String(b)) => {
                 let a = a.borrow_ref()?;
                 let b = b.borrow_ref()?;
@@ -547,6 +655,310 @@ impl Value {
             _ => false,
         })
     }
+
+    /// Test if two slices of values are deeply equal.
+    fn slice_eq(
+        a: &[Value],
+        b: &[Value],
+        visiting: &mut Vec<(*const (), *const ())>,
+    ) -> Result<bool, VmError> {
+        if a.len() != b.len() {
+            return Ok(false);
+        }
+
+        let key = (a.as_ptr() as *const (), b.as_ptr() as *const ());
+
+        if a.is_empty() || visiting.contains(&key) {
+            return Ok(true);
+        }
+
+        visiting.push(key);
+
+        for (a, b) in a.iter().zip(b.iter()) {
+            if !Self::value_ptr_eq_with(a, b, visiting)? {
+                visiting.pop();
+                return Ok(false);
+            }
+        }
+
+        visiting.pop();
+        Ok(true)
+    }
+
+    /// Test if two objects are deeply equal.
+    fn object_eq(
+        a: &Object,
+        b: &Object,
+        visiting: &mut Vec<(*const (), *const ())>,
+    ) -> Result<bool, VmError> {
+        if a.len() != b.len() {
+            return Ok(false);
+        }
+
+        let key = (a as *const _ as *const (), b as *const _ as *const ());
+
+        if visiting.contains(&key) {
+            return Ok(true);
+        }
+
+        visiting.push(key);
+
+        for (key, a) in a.iter() {
+            let equal = match b.get(key) {
+                Some(b) => Self::value_ptr_eq_with(a, b, visiting)?,
+                None => false,
+            };
+
+            if !equal {
+                visiting.pop();
+                return Ok(false);
+            }
+        }
+
+        visiting.pop();
+        Ok(true)
+    }
+
+    /// Compare two values, producing their total ordering.
+    ///
+    /// This is the canonical ordering of values, used by the comparison
+    /// operators (`<`, `<=`, `>`, `>=`) and `Vec::binary_search`. Numbers,
+    /// characters, bytes, and booleans compare by value, strings compare
+    /// lexicographically by their bytes, and vectors and tuples compare
+    /// lexicographically by their elements.
+    ///
+    /// Comparing values of different types, floats which are NaN, or values
+    /// which don't have an ordering results in an error. So does comparing
+    /// vectors or tuples which contain themselves, since their ordering would
+    /// never be resolved.
+    pub(crate) fn value_ptr_cmp(a: &Value, b: &Value) -> Result<Ordering, VmError> {
+        Self::value_ptr_cmp_with(a, b, &mut Vec::new())
+    }
+
+    /// Compare two values, keeping track of the pairs of containers being
+    /// compared in `visiting` to detect cycles.
+    fn value_ptr_cmp_with(
+        a: &Value,
+        b: &Value,
+        visiting: &mut Vec<(*const (), *const ())>,
+    ) -> Result<Ordering, VmError> {
+        Ok(match (a, b) {
+            (Self::Unit, Self::Unit) => Ordering::Equal,
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Byte(a), Self::Byte(b)) => a.cmp(b),
+            (Self::Char(a), Self::Char(b)) => a.cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::Float(x), Self::Float(y)) => match x.partial_cmp(y) {
+                Some(ordering) => ordering,
+                None => return Err(Self::unsupported_cmp(a, b)?),
+            },
+            (Self::String(a), Self::String(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.as_str().cmp(b.as_str())
+            }
+            (Self::StaticString(a), Self::String(b)) => {
+                let b = b.borrow_ref()?;
+                a.as_str().cmp(b.as_str())
+            }
+            (Self::String(a), Self::StaticString(b)) => {
+                let a = a.borrow_ref()?;
+                a.as_str().cmp(b.as_str())
+            }
+            (Self::StaticString(a), Self::StaticString(b)) => a.as_str().cmp(b.as_str()),
+            (Self::Vec(x), Self::Vec(y)) => {
+                let x = x.borrow_ref()?;
+                let y = y.borrow_ref()?;
+                Self::slice_cmp(a, b, &x, &y, visiting)?
+            }
+            (Self::Tuple(x), Self::Tuple(y)) => {
+                let x = x.borrow_ref()?;
+                let y = y.borrow_ref()?;
+                Self::slice_cmp(a, b, &x, &y, visiting)?
+            }
+            (a, b) => return Err(Self::unsupported_cmp(a, b)?),
+        })
+    }
+
+    /// Feed this value into the given hasher.
+    ///
+    /// Hashing agrees with equality, so values which are equal according to
+    /// the `==` operator hash to the same value. Integers, floats, booleans,
+    /// bytes, characters, strings, byte arrays, and vectors and tuples of
+    /// hashable values can be hashed.
+    ///
+    /// Any other kind of value, or a vector or tuple which contains itself,
+    /// results in a [VmErrorKind::Unhashable] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Value;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher as _;
+    ///
+    /// fn hash(value: &Value) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher).unwrap();
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let a = Value::vec(vec![Value::from(1i64), Value::from(String::from("foo"))]);
+    /// let b = Value::vec(vec![Value::from(1i64), Value::from(String::from("foo"))]);
+    /// assert_eq!(hash(&a), hash(&b));
+    /// ```
+    pub fn hash<H>(&self, hasher: &mut H) -> Result<(), VmError>
+    where
+        H: hash::Hasher,
+    {
+        self.hash_with(hasher, &mut Vec::new())
+    }
+
+    /// Hash the value, keeping track of the containers being hashed in
+    /// `parents` to detect cycles.
+    fn hash_with<H>(&self, hasher: &mut H, parents: &mut Vec<*const ()>) -> Result<(), VmError>
+    where
+        H: hash::Hasher,
+    {
+        use std::hash::Hash as _;
+
+        match self {
+            Self::Unit => 0u8.hash(hasher),
+            Self::Bool(b) => {
+                1u8.hash(hasher);
+                b.hash(hasher);
+            }
+            Self::Byte(b) => {
+                2u8.hash(hasher);
+                b.hash(hasher);
+            }
+            Self::Char(c) => {
+                3u8.hash(hasher);
+                c.hash(hasher);
+            }
+            Self::Integer(integer) => {
+                4u8.hash(hasher);
+                integer.hash(hasher);
+            }
+            Self::Float(float) => {
+                5u8.hash(hasher);
+                // NB: `0.0 == -0.0`, so they need to hash the same.
+                let float = if *float == 0.0 { 0.0 } else { *float };
+                float.to_bits().hash(hasher);
+            }
+            Self::String(string) => {
+                6u8.hash(hasher);
+                string.borrow_ref()?.as_str().hash(hasher);
+            }
+            Self::StaticString(string) => {
+                6u8.hash(hasher);
+                string.as_str().hash(hasher);
+            }
+            Self::Bytes(bytes) => {
+                7u8.hash(hasher);
+                bytes.borrow_ref()?.as_ref().hash(hasher);
+            }
+            Self::Vec(vec) => {
+                8u8.hash(hasher);
+                let vec = vec.borrow_ref()?;
+                Self::hash_slice(self, &vec, hasher, parents)?;
+            }
+            Self::Tuple(tuple) => {
+                9u8.hash(hasher);
+                let tuple = tuple.borrow_ref()?;
+                Self::hash_slice(self, &tuple, hasher, parents)?;
+            }
+            actual => {
+                return Err(VmError::from(VmErrorKind::Unhashable {
+                    actual: actual.type_info()?,
+                }))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hash a slice of values belonging to the container `this`.
+    fn hash_slice<H>(
+        this: &Value,
+        values: &[Value],
+        hasher: &mut H,
+        parents: &mut Vec<*const ()>,
+    ) -> Result<(), VmError>
+    where
+        H: hash::Hasher,
+    {
+        use std::hash::Hash as _;
+
+        let ptr = values.as_ptr() as *const ();
+
+        if !values.is_empty() && parents.contains(&ptr) {
+            return Err(VmError::from(VmErrorKind::Unhashable {
+                actual: this.type_info()?,
+            }));
+        }
+
+        parents.push(ptr);
+        values.len().hash(hasher);
+
+        for value in values {
+            value.hash_with(hasher, parents)?;
+        }
+
+        parents.pop();
+        Ok(())
+    }
+
+    /// Lexicographically compare two slices of values belonging to the
+    /// containers `this` and `other`.
+    fn slice_cmp(
+        this: &Value,
+        other: &Value,
+        a: &[Value],
+        b: &[Value],
+        visiting: &mut Vec<(*const (), *const ())>,
+    ) -> Result<Ordering, VmError> {
+        let key = (a.as_ptr() as *const (), b.as_ptr() as *const ());
+
+        if !a.is_empty() && !b.is_empty() && visiting.contains(&key) {
+            return Err(VmError::from(VmErrorKind::CyclicComparison {
+                lhs: this.type_info()?,
+                rhs: other.type_info()?,
+            }));
+        }
+
+        visiting.push(key);
+
+        for (a, b) in a.iter().zip(b.iter()) {
+            match Self::value_ptr_cmp_with(a, b, visiting)? {
+                Ordering::Equal => (),
+                ordering => {
+                    visiting.pop();
+                    return Ok(ordering);
+                }
+            }
+        }
+
+        visiting.pop();
+        Ok(a.len().cmp(&b.len()))
+    }
+
+    fn unsupported_cmp(a: &Value, b: &Value) -> Result<VmError, VmError> {
+        Ok(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+            op: "cmp",
+            lhs: a.type_info()?,
+            rhs: b.type_info()?,
+        }))
+    }
+}
+
+/// The user-facing representation of a value.
+///
+/// See [Value::string_display].
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.string_display(f).map_err(|_| fmt::Error)
+    }
 }
 
 impl fmt::Debug for Value {
@@ -568,7 +980,7 @@ impl fmt::Debug for Value {
                 write!(f, "{:?}", value)?;
             }
             Value::Float(value) => {
-                write!(f, "{:?}", value)?;
+                write_float(f, *value)?;
             }
             Value::Type(value) => {
                 write!(f, "Type({})", value)?;
@@ -580,16 +992,16 @@ impl fmt::Debug for Value {
                 write!(f, "{:?}", value)?;
             }
             Value::Bytes(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Vec(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Tuple(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Object(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Future(value) => {
                 write!(f, "{:?}", value)?;
@@ -604,22 +1016,22 @@ impl fmt::Debug for Value {
                 write!(f, "{:?}", value)?;
             }
             Value::Option(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Result(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::TypedTuple(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::TupleVariant(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::TypedObject(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::VariantObject(value) => {
-                write!(f, "{:?}", value)?;
+                fmt::Debug::fmt(value, f)?;
             }
             Value::Function(value) => {
                 write!(f, "{:?}", value)?;
@@ -734,9 +1146,166 @@ impl fmt::Display for Integer {
     }
 }
 
+/// Write a float in a way which is deterministic and independent of the
+/// formatting of the standard library.
+///
+/// Finite floats always include a decimal point, like `1.0` or `1.0e300`.
+/// Non-finite floats are written as `NaN`, `inf`, and `-inf`.
+pub(crate) fn write_float<W>(out: &mut W, float: f64) -> fmt::Result
+where
+    W: ?Sized + fmt::Write,
+{
+    if float.is_nan() {
+        return out.write_str("NaN");
+    }
+
+    if float.is_infinite() {
+        let string = if float.is_sign_negative() {
+            "-inf"
+        } else {
+            "inf"
+        };
+        return out.write_str(string);
+    }
+
+    let mut buffer = ryu::Buffer::new();
+    let string = buffer.format_finite(float);
+
+    if string.contains('.') {
+        return out.write_str(string);
+    }
+
+    // NB: ryu omits the decimal point for numbers using exponent notation,
+    // like `1e300`.
+    match string.find('e') {
+        Some(n) => {
+            out.write_str(&string[..n])?;
+            out.write_str(".0")?;
+            out.write_str(&string[n..])
+        }
+        None => {
+            out.write_str(string)?;
+            out.write_str(".0")
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Value;
+    use crate::{Shared, VmErrorKind};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher as _;
+
+    fn hash(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher).unwrap();
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_equal_values_hash_equally() {
+        let values = vec![
+            Value::Unit,
+            Value::from(true),
+            Value::from(42i64),
+            Value::from(1.5f64),
+            Value::from('a'),
+            Value::from(String::from("foo")),
+            Value::tuple(vec![Value::from(1i64), Value::from(String::from("foo"))]),
+            Value::vec(vec![Value::vec(vec![Value::from(1i64)]), Value::Unit]),
+        ];
+
+        for a in &values {
+            for b in &values {
+                if Value::value_ptr_eq(a, b).unwrap() {
+                    assert_eq!(hash(a), hash(b), "{:?} and {:?} should hash equally", a, b);
+                }
+            }
+        }
+
+        let a = Value::vec(vec![Value::from(1i64), Value::from(String::from("foo"))]);
+        let b = Value::vec(vec![Value::from(1i64), Value::from(String::from("foo"))]);
+        assert!(Value::value_ptr_eq(&a, &b).unwrap());
+        assert_eq!(hash(&a), hash(&b));
+
+        assert_eq!(hash(&Value::from(0.0f64)), hash(&Value::from(-0.0f64)));
+        assert_ne!(hash(&Value::from(1i64)), hash(&Value::from(2i64)));
+    }
+
+    #[test]
+    fn test_cyclic_eq() {
+        let a = Shared::new(vec![Value::from(1i64)]);
+        a.borrow_mut().unwrap().push(Value::Vec(a.clone()));
+        let b = Shared::new(vec![Value::from(1i64)]);
+        b.borrow_mut().unwrap().push(Value::Vec(b.clone()));
+        let c = Shared::new(vec![Value::from(2i64)]);
+        c.borrow_mut().unwrap().push(Value::Vec(c.clone()));
+
+        assert!(Value::value_ptr_eq(&Value::Vec(a.clone()), &Value::Vec(a.clone())).unwrap());
+        assert!(Value::value_ptr_eq(&Value::Vec(a.clone()), &Value::Vec(b.clone())).unwrap());
+        assert!(!Value::value_ptr_eq(&Value::Vec(a.clone()), &Value::Vec(c.clone())).unwrap());
+
+        // NB: break the cycles so that the vectors are freed.
+        a.borrow_mut().unwrap().clear();
+        b.borrow_mut().unwrap().clear();
+        c.borrow_mut().unwrap().clear();
+    }
+
+    #[test]
+    fn test_unhashable() {
+        let mut hasher = DefaultHasher::new();
+
+        let object = Value::Object(Shared::new(Default::default()));
+        let error = object.hash(&mut hasher).unwrap_err();
+        assert!(matches!(error.kind(), VmErrorKind::Unhashable { .. }));
+
+        let vec = Shared::new(vec![Value::from(1i64)]);
+        vec.borrow_mut().unwrap().push(Value::Vec(vec.clone()));
+        let error = Value::Vec(vec.clone()).hash(&mut hasher).unwrap_err();
+        assert!(matches!(error.kind(), VmErrorKind::Unhashable { .. }));
+
+        // NB: break the cycle so that the vector is freed.
+        vec.borrow_mut().unwrap().clear();
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        let string = Value::from(String::from("hi"));
+        assert_eq!(string.to_string(), "hi");
+        assert_eq!(format!("{:?}", string), "\"hi\"");
+
+        let c = Value::from('c');
+        assert_eq!(c.to_string(), "c");
+        assert_eq!(format!("{:?}", c), "'c'");
+
+        assert_eq!(Value::from(1.5f64).to_string(), "1.5");
+        assert_eq!(Value::from(42i64).to_string(), "42");
+        assert_eq!(Value::from(true).to_string(), "true");
+    }
+
+    #[test]
+    fn test_float_formatting() {
+        let cases = [
+            (1.0f64, "1.0"),
+            (-2.0, "-2.0"),
+            (0.1, "0.1"),
+            (-0.0, "-0.0"),
+            (1e300, "1.0e300"),
+            (-1.5e300, "-1.5e300"),
+            (1e-7, "1.0e-7"),
+            (f64::MAX, "1.7976931348623157e308"),
+            (f64::NAN, "NaN"),
+            (f64::INFINITY, "inf"),
+            (f64::NEG_INFINITY, "-inf"),
+        ];
+
+        for (float, expected) in cases.iter().copied() {
+            let value = Value::from(float);
+            assert_eq!(value.to_string(), expected);
+            assert_eq!(format!("{:?}", value), expected);
+        }
+    }
 
     #[test]
     fn test_size() {
@@ -1,10 +1,14 @@
 use crate::future::SelectFuture;
+use crate::statics::Statics;
 use crate::unit::UnitFn;
 use crate::{
-    Args, Awaited, Bytes, Call, Context, FromValue, Function, Future, Generator, Hash, Inst,
-    Integer, IntoHash, Object, Panic, Select, Shared, Stack, Stream, Tuple, TypeCheck, TypedObject,
-    Unit, Value, VariantObject, VmError, VmErrorKind, VmExecution, VmHalt,
+    AllowedFunctions, Args, Arithmetic, Awaited, Breakpoints, Bytes, Call, CancellationToken,
+    Capability, Context, FieldCache, FromValue, Function, Future, Gas, Generator, Hash, Inst,
+    Integer, IntoHash, Memory, Object, Output, Panic, Select, Shared, Stack, Stream, Tuple,
+    TypeCheck, TypedObject, Unit, Value, VariantObject, VmError, VmErrorKind, VmExecution, VmHalt,
+    Watchpoints,
 };
+use std::cmp::Ordering;
 use std::fmt;
 use std::mem;
 use std::sync::Arc;
@@ -22,6 +26,26 @@ pub struct Vm {
     stack: Stack,
     /// Frames relative to the stack.
     call_frames: Vec<CallFrame>,
+    /// Memory accounting used to limit allocations, if any.
+    memory: Option<Memory>,
+    /// Gas consumed by executed instructions, if any.
+    gas: Option<Gas>,
+    /// Token used to cancel the execution this virtual machine is part of.
+    cancellation: Option<CancellationToken>,
+    /// How integer arithmetic behaves on overflow.
+    arithmetic: Arithmetic,
+    /// The native functions which are allowed to be called, if restricted.
+    allowed_functions: Option<AllowedFunctions>,
+    /// The values of statics initialized so far, created on first use.
+    statics: Option<Statics>,
+    /// The sinks that output is written to, if not the process streams.
+    output: Option<Output>,
+    /// Inline cache for field accesses.
+    field_cache: FieldCache,
+    /// Breakpoints which pause the execution, if any.
+    breakpoints: Option<Box<Breakpoints>>,
+    /// Watchpoints which pause the execution, if any.
+    watchpoints: Option<Box<Watchpoints>>,
 }
 
 impl Vm {
@@ -38,6 +62,237 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: Vec::new(),
+            memory: None,
+            gas: None,
+            cancellation: None,
+            arithmetic: Arithmetic::Checked,
+            allowed_functions: None,
+            statics: None,
+            output: None,
+            field_cache: FieldCache::new(),
+            breakpoints: None,
+            watchpoints: None,
+        }
+    }
+
+    /// Limit the memory that can be allocated by the virtual machine.
+    ///
+    /// Allocations are charged against the given [Memory] while the virtual
+    /// machine is running, and freeing them releases the charge. If the limit
+    /// is exceeded execution errors with
+    /// [VmErrorKind::MemoryLimitExceeded].
+    ///
+    /// Virtual machines spawned by this one, like the ones backing
+    /// generators and async functions, share the same limit.
+    pub fn with_memory(mut self, memory: Memory) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Set the memory accounting used to limit allocations.
+    ///
+    /// See [with_memory][Vm::with_memory].
+    pub fn set_memory(&mut self, memory: Option<Memory>) {
+        self.memory = memory;
+    }
+
+    /// Access the memory accounting used to limit allocations, if any.
+    pub fn memory(&self) -> Option<&Memory> {
+        self.memory.as_ref()
+    }
+
+    /// Write output produced by the virtual machine, like through `print` or
+    /// `eprintln`, to the given sinks instead of the process streams.
+    ///
+    /// Virtual machines spawned by this one, like the ones backing
+    /// generators and async functions, write to the same sinks.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Set the sinks that output is written to.
+    ///
+    /// See [with_output][Vm::with_output].
+    pub fn set_output(&mut self, output: Option<Output>) {
+        self.output = output;
+    }
+
+    /// Access the sinks that output is written to, if not the process
+    /// streams.
+    pub fn output(&self) -> Option<&Output> {
+        self.output.as_ref()
+    }
+
+    /// Meter the instructions executed by the virtual machine.
+    ///
+    /// Every executed instruction consumes gas from the given pool according
+    /// to its [GasCosts][crate::GasCosts]. If the pool is exhausted execution
+    /// errors with [VmErrorKind::OutOfGas].
+    ///
+    /// Virtual machines spawned by this one, like the ones backing
+    /// generators and async functions, share the same pool.
+    pub fn with_gas(mut self, gas: Gas) -> Self {
+        self.gas = Some(gas);
+        self
+    }
+
+    /// Set the gas pool used to meter executed instructions.
+    ///
+    /// See [with_gas][Vm::with_gas].
+    pub fn set_gas(&mut self, gas: Option<Gas>) {
+        self.gas = gas;
+    }
+
+    /// Access the gas pool used to meter executed instructions, if any.
+    pub fn gas(&self) -> Option<&Gas> {
+        self.gas.as_ref()
+    }
+
+    /// Pause the execution when a line with a breakpoint is about to be
+    /// executed.
+    ///
+    /// Breakpoints pause an execution which is resumed with
+    /// [resume_until_breakpoint][VmExecution::resume_until_breakpoint]. When
+    /// stepping, a step which hits a breakpoint pauses without executing any
+    /// instruction. Breakpoints are ignored when an execution is run to
+    /// completion. They only apply to this virtual machine, and not to the
+    /// ones spawned by it for generators and async functions.
+    pub fn with_breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = Some(Box::new(breakpoints));
+        self
+    }
+
+    /// Set the breakpoints which pause the execution.
+    ///
+    /// See [with_breakpoints][Vm::with_breakpoints].
+    pub fn set_breakpoints(&mut self, breakpoints: Option<Breakpoints>) {
+        self.breakpoints = breakpoints.map(Box::new);
+    }
+
+    /// Access the breakpoints which pause the execution, if any.
+    pub fn breakpoints(&self) -> Option<&Breakpoints> {
+        self.breakpoints.as_deref()
+    }
+
+    /// Access the breakpoints which pause the execution mutably, so that
+    /// breakpoints can be added or removed while the execution is paused.
+    pub fn breakpoints_mut(&mut self) -> Option<&mut Breakpoints> {
+        self.breakpoints.as_deref_mut()
+    }
+
+    /// Pause the execution right after an instruction changed the value of a
+    /// watched variable.
+    ///
+    /// Watchpoints pause an execution in the same situations as
+    /// [breakpoints][Vm::with_breakpoints] do.
+    pub fn with_watchpoints(mut self, watchpoints: Watchpoints) -> Self {
+        self.watchpoints = Some(Box::new(watchpoints));
+        self
+    }
+
+    /// Set the watchpoints which pause the execution.
+    ///
+    /// See [with_watchpoints][Vm::with_watchpoints].
+    pub fn set_watchpoints(&mut self, watchpoints: Option<Watchpoints>) {
+        self.watchpoints = watchpoints.map(Box::new);
+    }
+
+    /// Access the watchpoints which pause the execution, if any.
+    pub fn watchpoints(&self) -> Option<&Watchpoints> {
+        self.watchpoints.as_deref()
+    }
+
+    /// Access the watchpoints which pause the execution mutably, so that
+    /// variables can be watched while the execution is paused.
+    pub fn watchpoints_mut(&mut self) -> Option<&mut Watchpoints> {
+        self.watchpoints.as_deref_mut()
+    }
+
+    /// Select how integer arithmetic behaves on overflow. Defaults to
+    /// [Arithmetic::Checked].
+    ///
+    /// Virtual machines spawned by this one, like the ones backing
+    /// generators and async functions, use the same mode.
+    pub fn with_arithmetic(mut self, arithmetic: Arithmetic) -> Self {
+        self.arithmetic = arithmetic;
+        self
+    }
+
+    /// Set how integer arithmetic behaves on overflow.
+    ///
+    /// See [with_arithmetic][Vm::with_arithmetic].
+    pub fn set_arithmetic(&mut self, arithmetic: Arithmetic) {
+        self.arithmetic = arithmetic;
+    }
+
+    /// Get how integer arithmetic behaves on overflow.
+    pub fn arithmetic(&self) -> Arithmetic {
+        self.arithmetic
+    }
+
+    /// Access the inline cache used for field accesses.
+    pub fn field_cache(&self) -> &FieldCache {
+        &self.field_cache
+    }
+
+    /// Only allow the virtual machine to call the given native functions.
+    ///
+    /// Calling any other native function errors with
+    /// [VmErrorKind::DeniedFunction]. See [AllowedFunctions] for what is
+    /// covered.
+    pub fn with_allowed_functions(mut self, allowed_functions: AllowedFunctions) -> Self {
+        self.allowed_functions = Some(allowed_functions);
+        self
+    }
+
+    /// Set the native functions the virtual machine is allowed to call, or
+    /// `None` to allow all of them.
+    ///
+    /// See [with_allowed_functions][Vm::with_allowed_functions].
+    pub fn set_allowed_functions(&mut self, allowed_functions: Option<AllowedFunctions>) {
+        self.allowed_functions = allowed_functions;
+    }
+
+    /// Access the native functions the virtual machine is allowed to call, if
+    /// restricted.
+    pub fn allowed_functions(&self) -> Option<&AllowedFunctions> {
+        self.allowed_functions.as_ref()
+    }
+
+    /// Access the values of statics initialized by the virtual machine, if
+    /// any.
+    pub(crate) fn statics(&self) -> Option<&Statics> {
+        self.statics.as_ref()
+    }
+
+    /// Set the values of statics to use, or `None` to initialize them anew.
+    ///
+    /// Statics belonging to a different unit are ignored.
+    pub(crate) fn set_statics(&mut self, statics: Option<Statics>) {
+        self.statics = statics.filter(|statics| statics.is_for(&self.unit));
+    }
+
+    /// Access the token used to cancel the execution, if any.
+    pub(crate) fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Set the token used to cancel the execution.
+    ///
+    /// The token is checked before each instruction is executed, and is
+    /// shared with the virtual machines spawned by this one.
+    pub(crate) fn set_cancellation(&mut self, cancellation: Option<CancellationToken>) {
+        self.cancellation = cancellation;
+    }
+
+    /// Check that the native function with the given hash is allowed to be
+    /// called.
+    #[inline]
+    fn check_allowed(&self, hash: Hash) -> Result<(), VmError> {
+        match &self.allowed_functions {
+            Some(allowed_functions) => allowed_functions.check(hash),
+            None => Ok(()),
         }
     }
 
@@ -99,6 +354,49 @@ impl Vm {
         self.ip = 0;
         self.stack.clear();
         self.call_frames.clear();
+
+        if let Some(breakpoints) = &mut self.breakpoints {
+            breakpoints.reset();
+        }
+
+        if let Some(watchpoints) = &mut self.watchpoints {
+            watchpoints.reset();
+        }
+    }
+
+    /// Forget the values of any statics, which causes them to be initialized
+    /// again the next time they are accessed.
+    pub fn clear_statics(&mut self) {
+        self.statics = None;
+    }
+
+    /// Reset this virtual machine so that it can be reused for another call.
+    ///
+    /// This retains the allocated capacity of the stack and call frames, as
+    /// well as the context and unit, which avoids reallocating them when
+    /// running many short calls. The values of any statics which have been
+    /// initialized are retained.
+    ///
+    /// Since calling a virtual machine consumes it, it has to be recovered
+    /// from its execution through [VmExecution::into_vm], which performs this
+    /// reset. Any values still referenced by the execution are dropped at
+    /// that point.
+    pub fn reset(&mut self) {
+        self.ip = 0;
+        self.stack.clear();
+        self.call_frames.clear();
+
+        if let Some(statics) = &self.statics {
+            statics.clear_pending();
+        }
+
+        if let Some(breakpoints) = &mut self.breakpoints {
+            breakpoints.reset();
+        }
+
+        if let Some(watchpoints) = &mut self.watchpoints {
+            watchpoints.reset();
+        }
     }
 
     /// Access the current instruction pointer.
@@ -199,7 +497,7 @@ impl Vm {
         }
     }
 
-    fn op_select(&mut self, len: usize) -> Result<Option<Select>, VmError> {
+    fn op_select(&mut self, len: usize, default: bool) -> Result<Option<Select>, VmError> {
         let futures = futures::stream::FuturesUnordered::new();
 
         let arguments = self.stack.drain_stack_top(len)?.collect::<Vec<_>>();
@@ -225,7 +523,7 @@ impl Vm {
             return Ok(None);
         }
 
-        Ok(Some(Select::new(futures)))
+        Ok(Some(Select::new(futures, default)))
     }
 
     /// Helper function to call an instance function.
@@ -255,10 +553,15 @@ impl Vm {
             None => return Ok(false),
         };
 
+        self.check_allowed(hash)?;
         self.stack.push(target.clone());
         args.into_stack(&mut self.stack)?;
 
         handler(&mut self.stack, count)?;
+
+        if self.memory.is_some() {
+            target.charge_heap();
+        }
         Ok(true)
     }
 
@@ -276,6 +579,7 @@ impl Vm {
             None => return Ok(false),
         };
 
+        self.check_allowed(hash)?;
         args.into_stack(&mut self.stack)?;
 
         self.stack.push(target.clone());
@@ -339,25 +643,38 @@ impl Vm {
         Ok(())
     }
 
+    /// Internal impl of a comparison operation.
+    ///
+    /// Integers and floats are compared directly, everything else is compared
+    /// using the canonical ordering of values. This means that strings are
+    /// compared lexicographically, and comparing values of incompatible types
+    /// errors.
     fn internal_boolean_ops(
         &mut self,
-        int_op: impl FnOnce(i64, i64) -> bool,
+        ordering_op: impl FnOnce(Ordering) -> bool,
         float_op: impl FnOnce(f64, f64) -> bool,
         op: &'static str,
     ) -> Result<(), VmError> {
         let rhs = self.stack.pop()?;
         let lhs = self.stack.pop()?;
 
-        let out = match (lhs, rhs) {
-            (Value::Integer(lhs), Value::Integer(rhs)) => int_op(lhs, rhs),
-            (Value::Float(lhs), Value::Float(rhs)) => float_op(lhs, rhs),
-            (lhs, rhs) => {
-                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
-                    op,
-                    lhs: lhs.type_info()?,
-                    rhs: rhs.type_info()?,
-                }))
-            }
+        let out = match (&lhs, &rhs) {
+            (Value::Integer(lhs), Value::Integer(rhs)) => ordering_op(lhs.cmp(rhs)),
+            (Value::Float(lhs), Value::Float(rhs)) => float_op(*lhs, *rhs),
+            (lhs, rhs) => match Value::value_ptr_cmp(lhs, rhs) {
+                Ok(ordering) => ordering_op(ordering),
+                Err(error) => {
+                    if let VmErrorKind::UnsupportedBinaryOperation { .. } = error.kind() {
+                        return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                            op,
+                            lhs: lhs.type_info()?,
+                            rhs: rhs.type_info()?,
+                        }));
+                    }
+
+                    return Err(error);
+                }
+            },
         };
 
         self.stack.push(out);
@@ -365,22 +682,22 @@ impl Vm {
     }
 
     fn op_gt(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, ">")?;
+        self.internal_boolean_ops(|o| o == Ordering::Greater, |a, b| a > b, ">")?;
         Ok(())
     }
 
     fn op_gte(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a >= b, |a, b| a >= b, ">=")?;
+        self.internal_boolean_ops(|o| o != Ordering::Less, |a, b| a >= b, ">=")?;
         Ok(())
     }
 
     fn op_lt(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a < b, |a, b| a < b, "<")?;
+        self.internal_boolean_ops(|o| o == Ordering::Less, |a, b| a < b, "<")?;
         Ok(())
     }
 
     fn op_lte(&mut self) -> Result<(), VmError> {
-        self.internal_boolean_ops(|a, b| a <= b, |a, b| a <= b, "<=")?;
+        self.internal_boolean_ops(|o| o != Ordering::Greater, |a, b| a <= b, "<=")?;
         Ok(())
     }
 
@@ -481,6 +798,27 @@ impl Vm {
         Ok(())
     }
 
+    /// Concatenate a number of vectors into a new vector.
+    #[inline]
+    fn op_vec_concat(&mut self, count: usize) -> Result<(), VmError> {
+        let mut vec = Vec::new();
+
+        for value in self.stack.pop_sequence(count)? {
+            vec.extend(Self::spread_values(value)?);
+        }
+
+        self.stack.push(Shared::new(vec));
+        Ok(())
+    }
+
+    /// Get the values of a vector which is being spread.
+    fn spread_values(value: Value) -> Result<Vec<Value>, VmError> {
+        match value {
+            Value::Vec(vec) => Ok(vec.borrow_ref()?.clone()),
+            actual => Err(VmError::expected::<Vec<Value>>(actual.type_info()?)),
+        }
+    }
+
     /// Construct a new tuple.
     #[inline]
     fn op_tuple(&mut self, count: usize) -> Result<(), VmError> {
@@ -519,10 +857,12 @@ impl Vm {
 
     #[inline]
     fn op_add(&mut self) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num(
             crate::ADD,
             || VmError::from(VmErrorKind::Overflow),
-            i64::checked_add,
+            move |a, b| arithmetic.add(a, b),
             std::ops::Add::add,
             "+",
         )?;
@@ -531,10 +871,12 @@ impl Vm {
 
     #[inline]
     fn op_sub(&mut self) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num(
             crate::SUB,
             || VmError::from(VmErrorKind::Underflow),
-            i64::checked_sub,
+            move |a, b| arithmetic.sub(a, b),
             std::ops::Sub::sub,
             "-",
         )?;
@@ -543,10 +885,12 @@ impl Vm {
 
     #[inline]
     fn op_mul(&mut self) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num(
             crate::ADD,
             || VmError::from(VmErrorKind::Overflow),
-            i64::checked_mul,
+            move |a, b| arithmetic.mul(a, b),
             std::ops::Mul::mul,
             "*",
         )?;
@@ -555,10 +899,12 @@ impl Vm {
 
     #[inline]
     fn op_div(&mut self) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num(
             crate::ADD,
             || VmError::from(VmErrorKind::DivideByZero),
-            i64::checked_div,
+            move |a, b| arithmetic.div(a, b),
             std::ops::Div::div,
             "+",
         )?;
@@ -567,10 +913,12 @@ impl Vm {
 
     #[inline]
     fn op_rem(&mut self) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num(
             crate::REM,
             || VmError::from(VmErrorKind::DivideByZero),
-            i64::checked_rem,
+            move |a, b| arithmetic.rem(a, b),
             std::ops::Rem::rem,
             "%",
         )?;
@@ -676,11 +1024,13 @@ impl Vm {
 
     #[inline]
     fn op_add_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num_assign(
             offset,
             crate::ADD_ASSIGN,
             || VmError::from(VmErrorKind::Overflow),
-            i64::checked_add,
+            move |a, b| arithmetic.add(a, b),
             std::ops::Add::add,
             "+=",
         )?;
@@ -689,11 +1039,13 @@ impl Vm {
 
     #[inline]
     fn op_sub_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num_assign(
             offset,
             crate::SUB_ASSIGN,
             || VmError::from(VmErrorKind::Underflow),
-            i64::checked_sub,
+            move |a, b| arithmetic.sub(a, b),
             std::ops::Sub::sub,
             "-=",
         )?;
@@ -702,11 +1054,13 @@ impl Vm {
 
     #[inline]
     fn op_mul_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num_assign(
             offset,
             crate::MUL_ASSIGN,
             || VmError::from(VmErrorKind::Overflow),
-            i64::checked_mul,
+            move |a, b| arithmetic.mul(a, b),
             std::ops::Mul::mul,
             "*=",
         )?;
@@ -715,11 +1069,13 @@ impl Vm {
 
     #[inline]
     fn op_div_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num_assign(
             offset,
             crate::DIV_ASSIGN,
             || VmError::from(VmErrorKind::DivideByZero),
-            i64::checked_div,
+            move |a, b| arithmetic.div(a, b),
             std::ops::Div::div,
             "/=",
         )?;
@@ -728,11 +1084,13 @@ impl Vm {
 
     #[inline]
     fn op_rem_assign(&mut self, offset: usize) -> Result<(), VmError> {
+        let arithmetic = self.arithmetic;
+
         self.internal_num_assign(
             offset,
             crate::REM_ASSIGN,
             || VmError::from(VmErrorKind::DivideByZero),
-            i64::checked_rem,
+            move |a, b| arithmetic.rem(a, b),
             std::ops::Rem::rem,
             "%=",
         )?;
@@ -741,11 +1099,56 @@ impl Vm {
 
     /// Perform an index set operation.
     #[inline]
-    fn op_index_set(&mut self) -> Result<(), VmError> {
+    fn op_index_set(&mut self, negative: bool) -> Result<(), VmError> {
         let target = self.stack.pop()?;
         let index = self.stack.pop()?;
         let value = self.stack.pop()?;
 
+        // NB: tuples and bytes always support assigning to an integer index.
+        if let (Value::Tuple(_), Value::Integer(integer), false)
+        | (Value::Bytes(_), Value::Integer(integer), false) = (&target, &index, negative)
+        {
+            use std::convert::TryInto as _;
+
+            if let Ok(index) = (*integer).try_into() {
+                if Self::try_tuple_like_index_set(&target, index, value)? {
+                    return Ok(());
+                }
+            }
+
+            return Err(VmError::from(VmErrorKind::MissingIndex {
+                target: target.type_info()?,
+                index: Integer::I64(*integer),
+            }));
+        }
+
+        // NB: integer index assignments on tuple-like values are only
+        // supported with negative indexing enabled.
+        if negative {
+            if let Value::Integer(integer) = &index {
+                if let Some(len) = Self::try_tuple_like_len(&target)? {
+                    use std::convert::TryInto as _;
+
+                    let resolved = if *integer < 0 {
+                        *integer + len as i64
+                    } else {
+                        *integer
+                    };
+
+                    if let Ok(index) = resolved.try_into() {
+                        if Self::try_tuple_like_index_set(&target, index, value)? {
+                            return Ok(());
+                        }
+                    }
+
+                    return Err(VmError::from(VmErrorKind::MissingIndex {
+                        target: target.type_info()?,
+                        index: Integer::I64(*integer),
+                    }));
+                }
+            }
+        }
+
         // This is a useful pattern.
         #[allow(clippy::never_loop)]
         loop {
@@ -882,6 +1285,7 @@ impl Vm {
             Value::Unit => None,
             Value::Tuple(tuple) => tuple.borrow_ref()?.get(index).cloned(),
             Value::Vec(vec) => vec.borrow_ref()?.get(index).cloned(),
+            Value::Bytes(bytes) => bytes.borrow_ref()?.get(index).copied().map(Value::Byte),
             Value::Result(result) => {
                 let result = result.borrow_ref()?;
 
@@ -933,6 +1337,19 @@ impl Vm {
         Ok(Some(value))
     }
 
+    /// Get the length of a tuple-like value which supports being indexed by
+    /// integers.
+    fn try_tuple_like_len(target: &Value) -> Result<Option<usize>, VmError> {
+        Ok(match target {
+            Value::Tuple(tuple) => Some(tuple.borrow_ref()?.len()),
+            Value::Vec(vec) => Some(vec.borrow_ref()?.len()),
+            Value::Bytes(bytes) => Some(bytes.borrow_ref()?.len()),
+            Value::TypedTuple(typed_tuple) => Some(typed_tuple.borrow_ref()?.tuple.len()),
+            Value::TupleVariant(variant_tuple) => Some(variant_tuple.borrow_ref()?.tuple.len()),
+            _ => None,
+        })
+    }
+
     /// Implementation of getting a string index on an object-like type.
     fn try_tuple_like_index_set(
         target: &Value,
@@ -961,6 +1378,17 @@ impl Vm {
 
                 Ok(false)
             }
+            Value::Bytes(bytes) => {
+                let byte = crate::bytes::byte_from_value(value)?;
+                let mut bytes = bytes.borrow_mut()?;
+
+                if let Some(target) = bytes.get_mut(index) {
+                    *target = byte;
+                    return Ok(true);
+                }
+
+                Ok(false)
+            }
             Value::Result(result) => {
                 let mut result = result.borrow_mut()?;
 
@@ -1010,7 +1438,7 @@ impl Vm {
 
     /// Perform an index get operation.
     #[inline]
-    fn op_index_get(&mut self) -> Result<(), VmError> {
+    fn op_index_get(&mut self, negative: bool) -> Result<(), VmError> {
         let target = self.stack.pop()?;
         let index = self.stack.pop()?;
 
@@ -1033,7 +1461,16 @@ impl Vm {
                 Value::Integer(index) => {
                     use std::convert::TryInto as _;
 
-                    let index = match (*index).try_into() {
+                    let resolved = if negative && *index < 0 {
+                        match Self::try_tuple_like_len(&target)? {
+                            Some(len) => *index + len as i64,
+                            None => *index,
+                        }
+                    } else {
+                        *index
+                    };
+
+                    let index = match resolved.try_into() {
                         Ok(index) => index,
                         Err(..) => {
                             return Err(VmError::from(VmErrorKind::MissingIndex {
@@ -1062,6 +1499,95 @@ impl Vm {
         Ok(())
     }
 
+    /// Perform a slice operation.
+    #[inline]
+    fn op_slice(&mut self, from: bool, to: bool) -> Result<(), VmError> {
+        let target = self.stack.pop()?;
+
+        let to = if to {
+            Some(self.stack.pop()?.into_integer()?)
+        } else {
+            None
+        };
+
+        let from = if from {
+            Some(self.stack.pop()?.into_integer()?)
+        } else {
+            None
+        };
+
+        let value = match &target {
+            Value::String(string) => {
+                let string = string.borrow_ref()?;
+                let string = Self::slice_str(&target, &string, from, to)?;
+                Value::String(Shared::new(string.to_owned()))
+            }
+            Value::StaticString(string) => {
+                let string = Self::slice_str(&target, string.as_ref(), from, to)?;
+                Value::String(Shared::new(string.to_owned()))
+            }
+            Value::Vec(vec) => {
+                let vec = vec.borrow_ref()?;
+                let (start, end) = Self::slice_range(&target, vec.len(), from, to)?;
+                Value::Vec(Shared::new(vec[start..end].to_vec()))
+            }
+            Value::Bytes(bytes) => {
+                let bytes = bytes.borrow_ref()?;
+                let (start, end) = Self::slice_range(&target, bytes.len(), from, to)?;
+                Value::Bytes(Shared::new(Bytes::from_vec(bytes[start..end].to_vec())))
+            }
+            target => {
+                return Err(VmError::from(VmErrorKind::UnsupportedSlice {
+                    target: target.type_info()?,
+                }));
+            }
+        };
+
+        self.stack.push(value);
+        Ok(())
+    }
+
+    /// Slice a string, making sure the slice lies on character boundaries.
+    fn slice_str<'a>(
+        target: &Value,
+        string: &'a str,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<&'a str, VmError> {
+        let (start, end) = Self::slice_range(target, string.len(), from, to)?;
+
+        match string.get(start..end) {
+            Some(string) => Ok(string),
+            None => Err(VmError::from(VmErrorKind::SliceNotCharBoundary {
+                start,
+                end,
+            })),
+        }
+    }
+
+    /// Resolve the bounds of a slice over something of length `len`.
+    fn slice_range(
+        target: &Value,
+        len: usize,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> Result<(usize, usize), VmError> {
+        use std::convert::TryInto as _;
+
+        let start = from.unwrap_or(0);
+        let end = to.unwrap_or(len as i64);
+
+        match (start.try_into(), end.try_into()) {
+            (Ok(start), Ok(end)) if start <= end && end <= len => Ok((start, end)),
+            _ => Err(VmError::from(VmErrorKind::SliceOutOfRange {
+                target: target.type_info()?,
+                start,
+                end,
+                len,
+            })),
+        }
+    }
+
     /// Perform an index get operation specialized for tuples.
     #[inline]
     fn op_tuple_index_get(&mut self, index: usize) -> Result<(), VmError> {
@@ -1130,8 +1656,12 @@ impl Vm {
             }
             Value::TypedObject(typed_object) => {
                 let typed_object = typed_object.borrow_ref()?;
+                let hash = typed_object.type_hash();
 
-                match typed_object.get(&***index).cloned() {
+                match self
+                    .field_cache
+                    .get(self.ip, hash, &typed_object.object, index)
+                {
                     Some(value) => Some(value),
                     None => {
                         return Err(VmError::from(VmErrorKind::ObjectIndexMissing {
@@ -1142,8 +1672,12 @@ impl Vm {
             }
             Value::VariantObject(variant_object) => {
                 let variant_object = variant_object.borrow_ref()?;
+                let hash = variant_object.hash;
 
-                match variant_object.object.get(&***index).cloned() {
+                match self
+                    .field_cache
+                    .get(self.ip, hash, &variant_object.object, index)
+                {
                     Some(value) => Some(value),
                     None => {
                         return Err(VmError::from(VmErrorKind::ObjectIndexMissing {
@@ -1301,8 +1835,11 @@ impl Vm {
                     buf.push_str(buffer.format(integer));
                 }
                 Value::Float(float) => {
-                    let mut buffer = ryu::Buffer::new();
-                    buf.push_str(buffer.format(float));
+                    crate::value::write_float(&mut buf, float)
+                        .map_err(|fmt::Error| VmError::from(VmErrorKind::FormatError))?;
+                }
+                value @ Value::Bool(..) | value @ Value::Char(..) | value @ Value::Byte(..) => {
+                    value.string_display(&mut buf)?;
                 }
                 actual => {
                     let b = Shared::new(std::mem::take(&mut buf));
@@ -1364,6 +1901,9 @@ impl Vm {
     }
 
     /// Internal implementation of the instance check.
+    ///
+    /// Values are compared by the hash of their type, so external values are
+    /// instances of the type they are registered as in the context.
     fn is_instance(&mut self) -> Result<bool, VmError> {
         let b = self.stack.pop()?;
         let a = self.stack.pop()?;
@@ -1378,9 +1918,43 @@ impl Vm {
             }
         };
 
+        if let Some(capability) = Capability::from_hash(hash) {
+            return self.has_capability(&a, capability);
+        }
+
         Ok(*a.value_type()? == hash)
     }
 
+    /// Test if the given value supports the given capability.
+    fn has_capability(&self, value: &Value, capability: Capability) -> Result<bool, VmError> {
+        Ok(match capability {
+            Capability::Iterable => match value {
+                Value::Vec(..) | Value::Object(..) => true,
+                value => self.has_instance_fn(value, crate::INTO_ITER)?,
+            },
+            Capability::Callable => matches!(value, Value::Function(..)),
+            Capability::Indexable => match value {
+                Value::Vec(..)
+                | Value::Tuple(..)
+                | Value::Object(..)
+                | Value::Bytes(..)
+                | Value::TypedTuple(..)
+                | Value::TypedObject(..) => true,
+                value => self.has_instance_fn(value, crate::INDEX_GET)?,
+            },
+        })
+    }
+
+    /// Test if an instance function is available for the given value, either in
+    /// the unit or in the context.
+    fn has_instance_fn<H>(&self, target: &Value, hash: H) -> Result<bool, VmError>
+    where
+        H: IntoHash,
+    {
+        let hash = Hash::instance_function(target.value_type()?, hash.into_hash());
+        Ok(self.unit.lookup(hash).is_some() || self.context.lookup(hash).is_some())
+    }
+
     #[inline]
     fn op_is(&mut self) -> Result<(), VmError> {
         let is_instance = self.is_instance()?;
@@ -1395,6 +1969,64 @@ impl Vm {
         Ok(())
     }
 
+    /// Test if a value is contained in a collection.
+    ///
+    /// Vectors and tuples contain values which are equal to one of their
+    /// elements, objects contain the string keys they have, strings contain
+    /// substrings and characters, and bytes contain individual bytes.
+    #[inline]
+    fn op_in(&mut self) -> Result<(), VmError> {
+        let collection = self.stack.pop()?;
+        let value = self.stack.pop()?;
+
+        let contains = match (&collection, &value) {
+            (Value::Vec(vec), value) => Self::slice_contains(&vec.borrow_ref()?, value)?,
+            (Value::Tuple(tuple), value) => Self::slice_contains(&tuple.borrow_ref()?, value)?,
+            (Value::Object(object), Value::String(key)) => object
+                .borrow_ref()?
+                .contains_key(key.borrow_ref()?.as_str()),
+            (Value::Object(object), Value::StaticString(key)) => {
+                object.borrow_ref()?.contains_key(key.as_str())
+            }
+            (Value::String(string), Value::String(needle)) => {
+                string.borrow_ref()?.contains(needle.borrow_ref()?.as_str())
+            }
+            (Value::String(string), Value::StaticString(needle)) => {
+                string.borrow_ref()?.contains(needle.as_str())
+            }
+            (Value::String(string), Value::Char(c)) => string.borrow_ref()?.contains(*c),
+            (Value::StaticString(string), Value::String(needle)) => {
+                string.as_str().contains(needle.borrow_ref()?.as_str())
+            }
+            (Value::StaticString(string), Value::StaticString(needle)) => {
+                string.as_str().contains(needle.as_str())
+            }
+            (Value::StaticString(string), Value::Char(c)) => string.as_str().contains(*c),
+            (Value::Bytes(bytes), Value::Byte(b)) => bytes.borrow_ref()?.contains(b),
+            (collection, value) => {
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op: "in",
+                    lhs: value.type_info()?,
+                    rhs: collection.type_info()?,
+                }));
+            }
+        };
+
+        self.stack.push(contains);
+        Ok(())
+    }
+
+    /// Test if the given slice contains an element which is equal to `value`.
+    fn slice_contains(values: &[Value], value: &Value) -> Result<bool, VmError> {
+        for v in values {
+            if Value::value_ptr_eq(v, value)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     #[inline]
     fn op_is_unit(&mut self) -> Result<(), VmError> {
         let value = self.stack.pop()?;
@@ -1674,6 +2306,13 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.memory = self.memory.clone();
+        vm.gas = self.gas.clone();
+        vm.cancellation = self.cancellation.clone();
+        vm.arithmetic = self.arithmetic;
+        vm.allowed_functions = self.allowed_functions.clone();
+        vm.statics = self.statics.clone();
+        vm.output = self.output.clone();
         self.stack.push(Generator::new(vm));
         Ok(())
     }
@@ -1683,6 +2322,13 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.memory = self.memory.clone();
+        vm.gas = self.gas.clone();
+        vm.cancellation = self.cancellation.clone();
+        vm.arithmetic = self.arithmetic;
+        vm.allowed_functions = self.allowed_functions.clone();
+        vm.statics = self.statics.clone();
+        vm.output = self.output.clone();
         self.stack.push(Stream::new(vm));
         Ok(())
     }
@@ -1692,6 +2338,13 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.memory = self.memory.clone();
+        vm.gas = self.gas.clone();
+        vm.cancellation = self.cancellation.clone();
+        vm.arithmetic = self.arithmetic;
+        vm.allowed_functions = self.allowed_functions.clone();
+        vm.statics = self.statics.clone();
+        vm.output = self.output.clone();
         self.stack.push(Future::new(vm.async_complete()));
         Ok(())
     }
@@ -1738,6 +2391,7 @@ impl Vm {
                     .lookup(hash)
                     .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
 
+                self.check_allowed(hash)?;
                 Function::from_handler(handler.clone())
             }
         };
@@ -1812,6 +2466,7 @@ impl Vm {
                     .lookup(hash)
                     .ok_or_else(|| VmError::from(VmErrorKind::MissingFunction { hash }))?;
 
+                self.check_allowed(hash)?;
                 handler(&mut self.stack, args)?;
             }
         }
@@ -1858,7 +2513,17 @@ impl Vm {
                     }
                 };
 
-                handler(&mut self.stack, args)?;
+                self.check_allowed(hash)?;
+
+                // NB: native instance functions might grow the buffer of the
+                // instance, like `push`, so it's re-measured after the call.
+                if self.memory.is_some() {
+                    let instance = instance.clone();
+                    handler(&mut self.stack, args)?;
+                    instance.charge_heap();
+                } else {
+                    handler(&mut self.stack, args)?;
+                }
             }
         }
 
@@ -1886,21 +2551,144 @@ impl Vm {
         Ok(None)
     }
 
+    /// Load the value of a static, calling its initializer if it hasn't been
+    /// initialized yet.
+    #[inline]
+    fn op_static(&mut self, hash: Hash) -> Result<(), VmError> {
+        let unit = &self.unit;
+        let statics = self
+            .statics
+            .get_or_insert_with(|| Statics::new(unit.clone()));
+
+        match statics.get_or_begin(hash)? {
+            Some(value) => {
+                self.stack.push(value);
+                Ok(())
+            }
+            None => self.op_call(hash, 0),
+        }
+    }
+
+    /// Store the value on top of the stack as the value of a static.
+    #[inline]
+    fn op_static_store(&mut self, hash: Hash) -> Result<(), VmError> {
+        let value = self.stack.last()?.clone();
+
+        let unit = &self.unit;
+        self.statics
+            .get_or_insert_with(|| Statics::new(unit.clone()))
+            .store(hash, value);
+
+        Ok(())
+    }
+
+    /// Call a function pointer with the elements of a vector as arguments.
+    #[inline]
+    fn op_call_fn_spread(&mut self) -> Result<Option<VmHalt>, VmError> {
+        let function = self.stack.pop()?;
+        let args = Self::spread_values(self.stack.pop()?)?;
+        let count = args.len();
+
+        self.stack.extend(args);
+        self.stack.push(function);
+        self.op_call_fn(count)
+    }
+
+    /// Call an instance function with the elements of a vector as arguments,
+    /// where the first element is the instance.
+    #[inline]
+    fn op_call_instance_spread(&mut self, hash: Hash) -> Result<(), VmError> {
+        let args = Self::spread_values(self.stack.pop()?)?;
+
+        // NB: the instance itself is not counted as an argument.
+        let count = match args.len().checked_sub(1) {
+            Some(count) => count,
+            None => {
+                return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                    actual: 0,
+                    expected: 1,
+                }))
+            }
+        };
+
+        self.stack.extend(args);
+        self.op_call_instance(hash, count)
+    }
+
     /// Advance the instruction pointer.
     pub(crate) fn advance(&mut self) {
         self.ip = self.ip.overflowing_add(1).0;
     }
 
-    /// Evaluate a single instruction.
-    pub(crate) fn run_for(&mut self, mut limit: Option<usize>) -> Result<VmHalt, VmError> {
+    /// Evaluate instructions until the virtual machine halts, or the given
+    /// limit of instructions has been reached.
+    pub(crate) fn run_for(&mut self, limit: Option<usize>) -> Result<VmHalt, VmError> {
+        let gas = self.gas.clone();
+        let _gas_guard = gas.as_ref().map(Gas::install);
+        let cancellation = self.cancellation.clone();
+        let cancellation = cancellation.as_ref();
+        let _allowed_guard = self
+            .allowed_functions
+            .as_ref()
+            .map(AllowedFunctions::install);
+        let _host_access_guard = self.context.host_access().install();
+        let unit = &self.unit;
+        let statics = self
+            .statics
+            .get_or_insert_with(|| Statics::new(unit.clone()));
+        let _statics_guard = statics.install();
+        let _output_guard = self.output.as_ref().map(Output::install);
+
+        match self.memory.clone() {
+            Some(memory) => {
+                let _guard = memory.install();
+                self.run_for_inner(limit, Some(&memory), gas.as_ref(), cancellation)
+            }
+            None => self.run_for_inner(limit, None, gas.as_ref(), cancellation),
+        }
+    }
+
+    fn run_for_inner(
+        &mut self,
+        mut limit: Option<usize>,
+        memory: Option<&Memory>,
+        gas: Option<&Gas>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<VmHalt, VmError> {
         loop {
             let inst = *self
                 .unit
                 .instruction_at(self.ip)
                 .ok_or_else(|| VmError::from(VmErrorKind::IpOutOfBounds))?;
 
+            if let Some(breakpoints) = &mut self.breakpoints {
+                if breakpoints.check(self.call_frames.len(), self.ip) {
+                    return Ok(VmHalt::Breakpoint);
+                }
+            }
+
+            if let Some(cancellation) = cancellation {
+                if cancellation.is_cancelled() {
+                    return Err(VmError::from(VmErrorKind::Cancelled));
+                }
+            }
+
+            if let Some(gas) = gas {
+                gas.charge(&inst)?;
+            }
+
             log::trace!("{}: {}", self.ip, inst);
 
+            let watched = match &self.watchpoints {
+                Some(watchpoints) => {
+                    match watchpoints.watched_offset(self.call_frames.len(), &inst) {
+                        Some(offset) => Some((offset, self.stack.at_offset(offset)?.clone())),
+                        None => None,
+                    }
+                }
+                None => None,
+            };
+
             match inst {
                 Inst::Not => {
                     self.op_not()?;
@@ -1935,6 +2723,12 @@ impl Vm {
                 Inst::RemAssign { offset } => {
                     self.op_rem_assign(offset)?;
                 }
+                Inst::Static { hash } => {
+                    self.op_static(hash)?;
+                }
+                Inst::StaticStore { hash } => {
+                    self.op_static_store(hash)?;
+                }
                 Inst::Fn { hash } => {
                     self.op_fn(hash)?;
                 }
@@ -1952,11 +2746,22 @@ impl Vm {
                         return Ok(reason);
                     }
                 }
+                Inst::CallFnSpread => {
+                    if let Some(reason) = self.op_call_fn_spread()? {
+                        return Ok(reason);
+                    }
+                }
+                Inst::CallInstanceSpread { hash } => {
+                    self.op_call_instance_spread(hash)?;
+                }
                 Inst::LoadInstanceFn { hash } => {
                     self.op_load_instance_fn(hash)?;
                 }
-                Inst::IndexGet => {
-                    self.op_index_get()?;
+                Inst::IndexGet { negative } => {
+                    self.op_index_get(negative)?;
+                }
+                Inst::Slice { from, to } => {
+                    self.op_slice(from, to)?;
                 }
                 Inst::TupleIndexGet { index } => {
                     self.op_tuple_index_get(index)?;
@@ -1973,8 +2778,8 @@ impl Vm {
                 Inst::ObjectSlotIndexGetAt { offset, slot } => {
                     self.op_object_slot_index_get_at(offset, slot)?;
                 }
-                Inst::IndexSet => {
-                    self.op_index_set()?;
+                Inst::IndexSet { negative } => {
+                    self.op_index_set(negative)?;
                 }
                 Inst::Return => {
                     if self.op_return()? {
@@ -1993,8 +2798,8 @@ impl Vm {
                     // NB: the future itself will advance the virtual machine.
                     return Ok(VmHalt::Awaited(Awaited::Future(future)));
                 }
-                Inst::Select { len } => {
-                    if let Some(select) = self.op_select(len)? {
+                Inst::Select { len, default } => {
+                    if let Some(select) = self.op_select(len, default)? {
                         // NB: the future itself will advance the virtual machine.
                         return Ok(VmHalt::Awaited(Awaited::Select(select)));
                     }
@@ -2068,6 +2873,9 @@ impl Vm {
                 Inst::Vec { count } => {
                     self.op_vec(count)?;
                 }
+                Inst::VecConcat { count } => {
+                    self.op_vec_concat(count)?;
+                }
                 Inst::Tuple { count } => {
                     self.op_tuple(count)?;
                 }
@@ -2111,6 +2919,9 @@ impl Vm {
                 Inst::IsNot => {
                     self.op_is_not()?;
                 }
+                Inst::In => {
+                    self.op_in()?;
+                }
                 Inst::IsUnit => {
                     self.op_is_unit()?;
                 }
@@ -2200,6 +3011,20 @@ impl Vm {
 
             self.advance();
 
+            if let Some(memory) = memory {
+                memory.check()?;
+            }
+
+            if let Some((offset, before)) = watched {
+                if !Value::value_ptr_eq(&before, self.stack.at_offset(offset)?)? {
+                    if let Some(watchpoints) = &mut self.watchpoints {
+                        watchpoints.set_hit(self.call_frames.len(), offset);
+                    }
+
+                    return Ok(VmHalt::Watchpoint);
+                }
+            }
+
             if let Some(limit) = &mut limit {
                 if *limit <= 1 {
                     return Ok(VmHalt::Limited);
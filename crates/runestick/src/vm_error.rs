@@ -1,7 +1,7 @@
 use crate::panic::BoxedPanic;
 use crate::{
-    AccessError, Hash, Integer, Item, Panic, Protocol, StackError, TypeInfo, Unit, Value,
-    ValueType, VmHaltInfo,
+    AccessError, Hash, HostAccess, Inst, Integer, Item, Panic, Protocol, StackError, TypeInfo,
+    Unit, Value, ValueType, VmHaltInfo,
 };
 use std::sync::Arc;
 use thiserror::Error;
@@ -139,6 +139,10 @@ pub enum VmErrorKind {
     /// Raised when we try to access an empty execution.
     #[error("no running virtual machines")]
     NoRunningVm,
+    /// Tried to await a future while running without an async executor,
+    /// like through [VmExecution::complete][crate::VmExecution::complete].
+    #[error("cannot await outside of an async context, use `async_complete` or `async_resume` to run asynchronous code")]
+    AwaitOutsideAsync,
     /// The virtual machine stopped for an unexpected reason.
     #[error("halted for unexpected reason `{halt}`")]
     Halted {
@@ -155,6 +159,23 @@ pub enum VmErrorKind {
         #[from]
         error: StackError,
     },
+    /// The virtual machine allocated more memory than it's allowed to.
+    #[error("memory limit exceeded, {used} bytes used out of a limit of {limit}")]
+    MemoryLimitExceeded {
+        /// The configured memory limit in bytes.
+        limit: usize,
+        /// The number of bytes in use when the limit was exceeded.
+        used: usize,
+    },
+    /// The virtual machine ran out of gas.
+    #[error("out of gas, the limit of {limit} was exhausted")]
+    OutOfGas {
+        /// The amount of gas the pool started out with.
+        limit: u64,
+    },
+    /// The execution was cancelled through its cancellation token.
+    #[error("execution was cancelled")]
+    Cancelled,
     /// The virtual machine encountered a numerical overflow.
     #[error("numerical overflow")]
     Overflow,
@@ -186,9 +207,44 @@ pub enum VmErrorKind {
         /// The instance type we tried to look up function on.
         instance: TypeInfo,
     },
+    /// Tried to call a native function which the virtual machine isn't
+    /// allowed to call.
+    #[error("calling function `{hash}` is not allowed")]
+    DeniedFunction {
+        /// Hash of the denied function.
+        hash: Hash,
+    },
+    /// A native function tried to access the host system in a way which the
+    /// context doesn't grant.
+    #[error("{access} access is denied by the context")]
+    DeniedHostAccess {
+        /// The denied access.
+        access: HostAccess,
+    },
     /// Instruction pointer went out-of-bounds.
     #[error("instruction pointer is out-of-bounds")]
     IpOutOfBounds,
+    /// An instruction in a unit refers to something which doesn't exist, as
+    /// detected by [Unit::verify].
+    #[error("instruction `{inst}` at `{ip}` is invalid")]
+    BadInstruction {
+        /// The instruction pointer of the invalid instruction.
+        ip: usize,
+        /// The invalid instruction.
+        inst: Inst,
+        /// The error describing what is invalid.
+        #[source]
+        error: VmError,
+    },
+    /// A function in a unit has an offset which is out-of-bounds, as detected
+    /// by [Unit::verify].
+    #[error("function `{hash}` has an out-of-bounds offset `{offset}`")]
+    BadFunctionOffset {
+        /// The hash of the function.
+        hash: Hash,
+        /// The offset of the function.
+        offset: usize,
+    },
     /// Tried to await something on the stack which can't be await:ed.
     #[error("unsupported target for .await `{actual}`")]
     UnsupportedAwait {
@@ -289,6 +345,42 @@ pub enum VmErrorKind {
         /// The index to get.
         index: TypeInfo,
     },
+    /// A slice operation that is not supported.
+    #[error("the slice operation `{target}[..]` is not supported")]
+    UnsupportedSlice {
+        /// The target type to slice.
+        target: TypeInfo,
+    },
+    /// Tried to slice a range which is out of bounds.
+    #[error("slice `{start}..{end}` is out of range for `{target}` of length {len}")]
+    SliceOutOfRange {
+        /// The type being sliced.
+        target: TypeInfo,
+        /// The start of the slice.
+        start: i64,
+        /// The end of the slice.
+        end: i64,
+        /// The length of the value being sliced.
+        len: usize,
+    },
+    /// Tried to clamp a value with a lower bound which is greater than the
+    /// upper bound.
+    #[error("clamp called with a lower bound greater than the upper bound")]
+    BadClampBounds,
+    /// Tried to access a static while it was being initialized.
+    #[error("static `{hash}` was accessed while it was being initialized")]
+    StaticCycle {
+        /// The hash of the static.
+        hash: Hash,
+    },
+    /// Tried to slice a string which doesn't lie on a character boundary.
+    #[error("slice `{start}..{end}` does not lie on a character boundary")]
+    SliceNotCharBoundary {
+        /// The start of the slice.
+        start: usize,
+        /// The end of the slice.
+        end: usize,
+    },
     /// An tuple index get operation that is not supported.
     #[error("the tuple index get operation is not supported on `{target}`")]
     UnsupportedTupleIndexGet {
@@ -315,6 +407,26 @@ pub enum VmErrorKind {
         /// The type that is not supported.
         test_type: TypeInfo,
     },
+    /// Tried to hash a value which can't be hashed.
+    #[error("`{actual}` can't be hashed")]
+    Unhashable {
+        /// The type that couldn't be hashed.
+        actual: TypeInfo,
+    },
+    /// Tried to compare values which contain themselves.
+    #[error("`{lhs}` and `{rhs}` can't be compared since they contain themselves")]
+    CyclicComparison {
+        /// The left-hand side of the comparison.
+        lhs: TypeInfo,
+        /// The right-hand side of the comparison.
+        rhs: TypeInfo,
+    },
+    /// Tried to iterate over a value which isn't iterable.
+    #[error("`{actual}` is not iterable")]
+    UnsupportedIterable {
+        /// The type that couldn't be iterated over.
+        actual: TypeInfo,
+    },
     /// Encountered a value that could not be called as a function
     #[error("`{actual_type}` cannot be called since it's not a function")]
     UnsupportedCallFn {
@@ -421,6 +533,14 @@ pub enum VmErrorKind {
         /// Number type we tried to convert to.
         to: &'static str,
     },
+    /// Error raised when an element of a vector failed to convert.
+    #[error("failed to convert element at index `{index}`: {error}")]
+    BadVecElement {
+        /// The index of the element which failed to convert.
+        index: usize,
+        /// The error raised by the conversion.
+        error: VmError,
+    },
     /// Error raised when we expected an tuple of the given length.
     #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
     ExpectedTupleLength {
@@ -1,14 +1,43 @@
-use crate::{GeneratorState, Value, Vm, VmError, VmErrorKind, VmHalt, VmHaltInfo};
+use crate::{
+    CancellationToken, GeneratorState, Value, Vm, VmError, VmErrorKind, VmHalt, VmHaltInfo,
+};
 
 /// The execution environment for a virtual machine.
 pub struct VmExecution {
     vms: Vec<Vm>,
+    /// The root virtual machine, once the execution has completed.
+    completed: Option<Vm>,
+    cancellation: CancellationToken,
 }
 
 impl VmExecution {
     /// Construct an execution from a virtual machine.
-    pub(crate) fn new(vm: Vm) -> Self {
-        Self { vms: vec![vm] }
+    ///
+    /// If the virtual machine was spawned as part of another execution, the
+    /// cancellation token of that execution is shared.
+    pub(crate) fn new(mut vm: Vm) -> Self {
+        let cancellation = match vm.cancellation() {
+            Some(cancellation) => cancellation.clone(),
+            None => {
+                let cancellation = CancellationToken::new();
+                vm.set_cancellation(Some(cancellation.clone()));
+                cancellation
+            }
+        };
+
+        Self {
+            vms: vec![vm],
+            completed: None,
+            cancellation,
+        }
+    }
+
+    /// Get a token which can be used to cancel this execution.
+    ///
+    /// Once cancelled, the execution errors with [VmErrorKind::Cancelled]
+    /// before it executes its next instruction.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
     }
 
     /// Get the current virtual machine.
@@ -20,6 +49,11 @@ impl VmExecution {
     }
 
     /// Get the current virtual machine mutably.
+    ///
+    /// Since this borrows the execution mutably, it can only be called while
+    /// the execution is suspended between steps and never in the middle of an
+    /// instruction. This allows a host, like a debugger, to read and modify
+    /// the stack before the execution is continued.
     pub fn vm_mut(&mut self) -> Result<&mut Vm, VmError> {
         match self.vms.last_mut() {
             Some(vm) => Ok(vm),
@@ -27,6 +61,24 @@ impl VmExecution {
         }
     }
 
+    /// Convert the execution back into the virtual machine it was started
+    /// from, so that it can be reused for another call.
+    ///
+    /// The virtual machine is [reset][Vm::reset], which retains its allocated
+    /// capacity. If the execution hasn't completed, any in-progress state is
+    /// discarded.
+    pub fn into_vm(self) -> Result<Vm, VmError> {
+        let vm = match self.completed {
+            Some(vm) => Some(vm),
+            None => self.vms.into_iter().next(),
+        };
+
+        let mut vm = vm.ok_or_else(|| VmError::from(VmErrorKind::NoRunningVm))?;
+        vm.reset();
+        vm.set_cancellation(None);
+        Ok(vm)
+    }
+
     /// Complete the current execution without support for async instructions.
     ///
     /// This will error if the execution is suspended through yielding.
@@ -41,8 +93,9 @@ impl VmExecution {
 
     /// Complete the current execution without support for async instructions.
     ///
-    /// If any async instructions are encountered, this will error. This will
-    /// also error if the execution is suspended through yielding.
+    /// If any async instructions are encountered, this will error with
+    /// [VmErrorKind::AwaitOutsideAsync]. This will also error if the execution
+    /// is suspended through yielding.
     pub fn complete(&mut self) -> Result<Value, VmError> {
         match self.resume()? {
             GeneratorState::Complete(value) => Ok(value),
@@ -52,9 +105,31 @@ impl VmExecution {
         }
     }
 
+    /// Complete the current execution with support for async instructions,
+    /// blocking the current thread until it's done.
+    ///
+    /// Any futures awaited by the script are polled on a minimal built-in
+    /// executor, so this can be used to run asynchronous scripts without
+    /// bringing in an external runtime like tokio.
+    ///
+    /// # Limitations
+    ///
+    /// The built-in executor doesn't provide any I/O or timer facilities.
+    /// Futures which depend on a specific runtime, like `time::delay_for` or
+    /// the `http` module in `rune-modules`, will fail or panic when awaited
+    /// under this mode and must instead be run through
+    /// [async_complete][VmExecution::async_complete] inside of that runtime.
+    ///
+    /// This must not be called from within an asynchronous context, since it
+    /// blocks the current thread.
+    pub fn complete_blocking(&mut self) -> Result<Value, VmError> {
+        futures::executor::block_on(self.async_complete())
+    }
+
     /// Resume the current execution with support for async instructions.
     pub async fn async_resume(&mut self) -> Result<GeneratorState, VmError> {
         loop {
+            self.check_cancelled()?;
             let len = self.vms.len();
             let vm = self.vm_mut()?;
 
@@ -68,6 +143,9 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
+                // NB: breakpoints and watchpoints are ignored when running to
+                // completion.
+                VmHalt::Breakpoint | VmHalt::Watchpoint => continue,
                 VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
@@ -79,7 +157,7 @@ impl VmExecution {
             if len == 1 {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
-                self.vms.clear();
+                self.completed = self.vms.pop();
                 return Ok(GeneratorState::Complete(value));
             }
 
@@ -89,9 +167,11 @@ impl VmExecution {
 
     /// Resume the current execution without support for async instructions.
     ///
-    /// If any async instructions are encountered, this will error.
+    /// If any async instructions are encountered, this will error with
+    /// [VmErrorKind::AwaitOutsideAsync].
     pub fn resume(&mut self) -> Result<GeneratorState, VmError> {
         loop {
+            self.check_cancelled()?;
             let len = self.vms.len();
             let vm = self.vm_mut()?;
 
@@ -101,7 +181,11 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
+                // NB: breakpoints and watchpoints are ignored when running to
+                // completion.
+                VmHalt::Breakpoint | VmHalt::Watchpoint => continue,
                 VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
+                VmHalt::Awaited(..) => return Err(Self::await_outside_async(vm)),
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -112,7 +196,7 @@ impl VmExecution {
             if len == 1 {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
-                self.vms.clear();
+                self.completed = self.vms.pop();
                 return Ok(GeneratorState::Complete(value));
             }
 
@@ -120,11 +204,56 @@ impl VmExecution {
         }
     }
 
+    /// Resume the current execution without support for async instructions,
+    /// until it either completes or hits a [breakpoint][Vm::with_breakpoints]
+    /// or [watchpoint][Vm::with_watchpoints].
+    ///
+    /// Returns `None` if the execution was paused, in which case
+    /// the virtual machine can be inspected through [vm][VmExecution::vm]
+    /// before the execution is resumed again.
+    ///
+    /// If any async instructions are encountered, this will error with
+    /// [VmErrorKind::AwaitOutsideAsync]. This will also error if the execution
+    /// is suspended through yielding.
+    pub fn resume_until_breakpoint(&mut self) -> Result<Option<Value>, VmError> {
+        loop {
+            self.check_cancelled()?;
+            let len = self.vms.len();
+            let vm = self.vm_mut()?;
+
+            match Self::run_for(vm, None)? {
+                VmHalt::Exited => (),
+                VmHalt::VmCall(vm_call) => {
+                    vm_call.into_execution(self)?;
+                    continue;
+                }
+                VmHalt::Breakpoint | VmHalt::Watchpoint => return Ok(None),
+                VmHalt::Awaited(..) => return Err(Self::await_outside_async(vm)),
+                halt => {
+                    return Err(VmError::from(VmErrorKind::Halted {
+                        halt: halt.into_info(),
+                    }))
+                }
+            }
+
+            if len == 1 {
+                let value = vm.stack_mut().pop()?;
+                debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
+                self.completed = self.vms.pop();
+                return Ok(Some(value));
+            }
+
+            self.pop_vm()?;
+        }
+    }
+
     /// Step the single execution for one step without support for async
     /// instructions.
     ///
-    /// If any async instructions are encountered, this will error.
+    /// If any async instructions are encountered, this will error with
+    /// [VmErrorKind::AwaitOutsideAsync].
     pub fn step(&mut self) -> Result<Option<Value>, VmError> {
+        self.check_cancelled()?;
         let len = self.vms.len();
         let vm = self.vm_mut()?;
 
@@ -134,7 +263,8 @@ impl VmExecution {
                 vm_call.into_execution(self)?;
                 return Ok(None);
             }
-            VmHalt::Limited => return Ok(None),
+            VmHalt::Limited | VmHalt::Breakpoint | VmHalt::Watchpoint => return Ok(None),
+            VmHalt::Awaited(..) => return Err(Self::await_outside_async(vm)),
             halt => {
                 return Err(VmError::from(VmErrorKind::Halted {
                     halt: halt.into_info(),
@@ -155,6 +285,7 @@ impl VmExecution {
     /// Step the single execution for one step with support for async
     /// instructions.
     pub async fn async_step(&mut self) -> Result<Option<Value>, VmError> {
+        self.check_cancelled()?;
         let len = self.vms.len();
         let vm = self.vm_mut()?;
 
@@ -168,7 +299,7 @@ impl VmExecution {
                 vm_call.into_execution(self)?;
                 return Ok(None);
             }
-            VmHalt::Limited => return Ok(None),
+            VmHalt::Limited | VmHalt::Breakpoint | VmHalt::Watchpoint => return Ok(None),
             halt => {
                 return Err(VmError::from(VmErrorKind::Halted {
                     halt: halt.into_info(),
@@ -209,6 +340,24 @@ impl VmExecution {
         Ok(())
     }
 
+    /// Check if the execution has been cancelled, in which case all virtual
+    /// machines are torn down.
+    #[inline]
+    fn check_cancelled(&mut self) -> Result<(), VmError> {
+        if self.cancellation.is_cancelled() {
+            self.vms.clear();
+            return Err(VmError::from(VmErrorKind::Cancelled));
+        }
+
+        Ok(())
+    }
+
+    /// Construct the error raised when an await is encountered while running
+    /// without an async executor, pointing at the offending instruction.
+    fn await_outside_async(vm: &Vm) -> VmError {
+        VmError::from(VmErrorKind::AwaitOutsideAsync).into_unwinded(vm.unit(), vm.ip())
+    }
+
     #[inline]
     fn run_for(vm: &mut Vm, limit: Option<usize>) -> Result<VmHalt, VmError> {
         match vm.run_for(limit) {
@@ -14,6 +14,10 @@ pub enum VmHalt {
     Awaited(Awaited),
     /// Call into a new virtual machine.
     VmCall(VmCall),
+    /// The virtual machine is about to execute a line with a breakpoint.
+    Breakpoint,
+    /// A watched variable was changed by the last instruction executed.
+    Watchpoint,
 }
 
 impl VmHalt {
@@ -25,6 +29,8 @@ impl VmHalt {
             Self::Yielded => VmHaltInfo::Yielded,
             Self::Awaited(..) => VmHaltInfo::Awaited,
             Self::VmCall(..) => VmHaltInfo::VmCall,
+            Self::Breakpoint => VmHaltInfo::Breakpoint,
+            Self::Watchpoint => VmHaltInfo::Watchpoint,
         }
     }
 }
@@ -42,6 +48,10 @@ pub enum VmHaltInfo {
     Awaited,
     /// Received instruction to push the inner virtual machine.
     VmCall,
+    /// Hit a breakpoint.
+    Breakpoint,
+    /// Hit a watchpoint.
+    Watchpoint,
 }
 
 impl fmt::Display for VmHaltInfo {
@@ -52,6 +62,8 @@ impl fmt::Display for VmHaltInfo {
             Self::Yielded => write!(f, "yielded"),
             Self::Awaited => write!(f, "awaited"),
             Self::VmCall => write!(f, "calling into other vm"),
+            Self::Breakpoint => write!(f, "hit breakpoint"),
+            Self::Watchpoint => write!(f, "hit watchpoint"),
         }
     }
 }
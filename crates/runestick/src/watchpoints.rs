@@ -0,0 +1,88 @@
+//! Watchpoints which pause the execution of a virtual machine when a local
+//! variable changes.
+
+use crate::collections::HashSet;
+use crate::Inst;
+
+/// Watchpoints on local variables.
+///
+/// A local variable is identified by the depth of the call frame it belongs
+/// to, as in the number of [call frames][crate::Vm::call_frames] while that
+/// function is executing, and its offset relative to the bottom of the stack
+/// of that frame.
+///
+/// A watchpoint is hit after an instruction which assigns to the variable
+/// changed its value. Values are compared structurally, so modifying a value
+/// in place through another reference to it is not detected.
+#[derive(Debug, Clone, Default)]
+pub struct Watchpoints {
+    /// Watched variables by call frame depth and offset.
+    watched: HashSet<(usize, usize)>,
+    /// The call frame depth and offset of the watchpoint which was last hit.
+    hit: Option<(usize, usize)>,
+}
+
+impl Watchpoints {
+    /// Construct a new empty collection of watchpoints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch the variable at the given offset in the call frame with the given
+    /// depth. Returns `false` if it was already watched.
+    pub fn insert(&mut self, depth: usize, offset: usize) -> bool {
+        self.watched.insert((depth, offset))
+    }
+
+    /// Stop watching the given variable. Returns `false` if it wasn't
+    /// watched.
+    pub fn remove(&mut self, depth: usize, offset: usize) -> bool {
+        self.watched.remove(&(depth, offset))
+    }
+
+    /// Test if there are no watched variables.
+    pub fn is_empty(&self) -> bool {
+        self.watched.is_empty()
+    }
+
+    /// The call frame depth and offset of the watchpoint which was last hit,
+    /// if any.
+    pub fn hit(&self) -> Option<(usize, usize)> {
+        self.hit
+    }
+
+    /// Get the offset of the watched variable which the given instruction
+    /// assigns to, if any.
+    pub(crate) fn watched_offset(&self, depth: usize, inst: &Inst) -> Option<usize> {
+        let offset = match *inst {
+            Inst::Replace { offset }
+            | Inst::AddAssign { offset }
+            | Inst::SubAssign { offset }
+            | Inst::MulAssign { offset }
+            | Inst::DivAssign { offset }
+            | Inst::RemAssign { offset }
+            | Inst::BitAndAssign { offset }
+            | Inst::BitXorAssign { offset }
+            | Inst::BitOrAssign { offset }
+            | Inst::ShlAssign { offset }
+            | Inst::ShrAssign { offset } => offset,
+            _ => return None,
+        };
+
+        if self.watched.contains(&(depth, offset)) {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    /// Mark the given variable as changed.
+    pub(crate) fn set_hit(&mut self, depth: usize, offset: usize) {
+        self.hit = Some((depth, offset));
+    }
+
+    /// Forget which watchpoint was last hit.
+    pub(crate) fn reset(&mut self) {
+        self.hit = None;
+    }
+}
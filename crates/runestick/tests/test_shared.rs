@@ -1,4 +1,4 @@
-use runestick::{Any, Shared};
+use runestick::{Any, OwnedMut, OwnedRef, Shared};
 
 #[global_allocator]
 static ALLOCATOR: checkers::Allocator = checkers::Allocator::system();
@@ -19,3 +19,53 @@ fn test_clone_take() {
     assert_eq!(Foo(0), thing2.take_downcast::<Foo>().unwrap());
     assert!(thing.take().is_err());
 }
+
+#[checkers::test]
+fn test_access_error_names_type() {
+    let thing = Shared::new(Any::new(Foo(0)));
+    let _guard = thing.downcast_borrow_mut::<Foo>().unwrap();
+
+    let error = thing.downcast_borrow_ref::<Foo>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot read, value of type `Foo` is exclusively accessed"
+    );
+
+    let vec = Shared::new(vec![1u32, 2, 3]);
+    let _guard = vec.borrow_ref().unwrap();
+
+    let error = vec.borrow_mut().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot write, value of type `Vec<u32>` is shared by 1"
+    );
+}
+
+#[checkers::test]
+fn test_try_take() {
+    let thing = Shared::new(Any::new(Foo(0)));
+    let thing2 = thing.clone();
+
+    {
+        let _guard = thing2.downcast_borrow_ref::<Foo>().unwrap();
+        assert!(thing.try_take().is_err());
+    }
+
+    let _ = thing.try_take().unwrap();
+    assert!(thing.try_take().is_err());
+    assert!(thing2.take().is_err());
+}
+
+#[checkers::test]
+fn test_owned_map() {
+    let thing = Shared::new(Any::new(Foo(0)));
+    let thing2 = thing.clone();
+
+    let mut field = OwnedMut::map(thing.downcast_owned_mut::<Foo>().unwrap(), |foo| &mut foo.0);
+    *field += 1;
+    assert!(thing2.downcast_borrow_ref::<Foo>().is_err());
+    drop(field);
+
+    let field = OwnedRef::map(thing2.downcast_owned_ref::<Foo>().unwrap(), |foo| &foo.0);
+    assert_eq!(*field, 1);
+}